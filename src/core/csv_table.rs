@@ -0,0 +1,102 @@
+///
+/// Таблица чисел, прочитанная из файла csv.
+/// Первая строка - заголовок. Разделитель столбцов - запятая,
+/// или точка с запятой, если она встречается в заголовке (экспорт из электронных таблиц),
+/// в этом случае в числах допускается десятичная запятая.
+/// Пустые строки пропускаются, все строки должны содержать столько же столбцов, сколько заголовок.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvTable {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    line_numbers: Vec<usize>,
+    decimal_comma: bool,
+}
+
+impl CsvTable {
+    ///
+    /// Разбирает содержимое файла csv.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut lines = content
+            .lines()
+            .map(|line| line.trim())
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty());
+        let (_, header) = lines
+            .next()
+            .ok_or("Таблица csv не содержит заголовка.".to_string())?;
+        let decimal_comma = header.contains(';');
+        let delimiter = if decimal_comma { ';' } else { ',' };
+        let split = |line: &str| -> Vec<String> {
+            line.split(delimiter)
+                .map(|cell| cell.trim().trim_matches('"').to_string())
+                .collect()
+        };
+        let header = split(header);
+        let mut rows = vec![];
+        let mut line_numbers = vec![];
+        for (index, line) in lines {
+            let row = split(line);
+            if row.len() != header.len() {
+                return Err(format!(
+                    "Строка {}: количество столбцов {} не совпадает с заголовком ({}).",
+                    index + 1,
+                    row.len(),
+                    header.len()
+                ));
+            }
+            rows.push(row);
+            line_numbers.push(index + 1);
+        }
+        Ok(CsvTable {
+            header,
+            rows,
+            line_numbers,
+            decimal_comma,
+        })
+    }
+
+    ///
+    /// Заголовок таблицы.
+    pub fn header(&self) -> &Vec<String> {
+        &self.header
+    }
+
+    ///
+    /// Строки таблицы без заголовка.
+    pub fn rows(&self) -> &Vec<Vec<String>> {
+        &self.rows
+    }
+
+    ///
+    /// Индекс столбца с заданным заголовком.
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.header.iter().position(|column| column == name)
+    }
+
+    ///
+    /// Числовые значения столбца, начиная со строки `first_row` (нумерация строк без заголовка с нуля).
+    pub fn column(&self, index: usize, first_row: usize) -> Result<Vec<f64>, String> {
+        self.rows
+            .iter()
+            .enumerate()
+            .skip(first_row)
+            .map(|(row, cells)| self.number(&cells[index], row))
+            .collect()
+    }
+
+    ///
+    /// Разбирает число в ячейке строки `row` таблицы (нумерация строк без заголовка с нуля).
+    pub fn number(&self, cell: &str, row: usize) -> Result<f64, String> {
+        let value = if self.decimal_comma {
+            cell.replace(',', ".")
+        } else {
+            cell.to_string()
+        };
+        value.parse::<f64>().map_err(|_| {
+            format!(
+                "Строка {}: значение \"{cell}\" не является числом.",
+                self.line_numbers[row]
+            )
+        })
+    }
+}
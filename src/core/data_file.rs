@@ -0,0 +1,87 @@
+use std::{fs, path::Path};
+
+use log::warn;
+use serde::de::DeserializeOwned;
+
+use super::{csv_table::CsvTable, json_file::JsonFile};
+
+///
+/// Формат файла входных данных, определяется по расширению файла.
+/// Файлы без расширения считаются файлами json.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataFormat {
+    Json,
+    Toml,
+    Yaml,
+    Csv,
+}
+
+impl DataFormat {
+    ///
+    /// Определяет формат по расширению файла.
+    pub fn from_path(file_path: &str) -> Result<Self, String> {
+        let extension = Path::new(file_path)
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        match extension.as_deref() {
+            None | Some("json") => Ok(DataFormat::Json),
+            Some("toml") => Ok(DataFormat::Toml),
+            Some("yaml") | Some("yml") => Ok(DataFormat::Yaml),
+            Some("csv") => Ok(DataFormat::Csv),
+            Some(extension) => Err(format!(
+                "Неизвестный формат файла {file_path}: {extension}. Допустимые форматы: json, toml, yaml, csv."
+            )),
+        }
+    }
+}
+
+///
+/// Reads data file (json, toml, yaml или csv).
+/// Формат файла определяется по расширению.
+pub struct DataFile {
+    file_path: String,
+}
+
+impl DataFile {
+    pub fn new(file_path: String) -> Self {
+        DataFile { file_path }
+    }
+
+    ///
+    /// Формат файла.
+    pub fn format(&self) -> Result<DataFormat, String> {
+        DataFormat::from_path(&self.file_path)
+    }
+
+    ///
+    /// Return content file as string.
+    fn text(&self) -> Result<String, String> {
+        fs::read_to_string(&self.file_path).map_err(|err| {
+            warn!("DataFile.text() | error {:?}", err);
+            err.to_string()
+        })
+    }
+
+    ///
+    /// Десериализует содержимое файла json, toml или yaml.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, String> {
+        match self.format()? {
+            DataFormat::Json => {
+                let content = JsonFile::new(self.file_path.clone()).content()?;
+                serde_json::from_reader(content).map_err(|err| err.to_string())
+            }
+            DataFormat::Toml => toml::from_str(&self.text()?).map_err(|err| err.to_string()),
+            DataFormat::Yaml => serde_yaml::from_str(&self.text()?).map_err(|err| err.to_string()),
+            DataFormat::Csv => Err(format!(
+                "Файл {} в формате csv не может быть прочитан как структурированные данные.",
+                self.file_path
+            )),
+        }
+    }
+
+    ///
+    /// Читает таблицу из файла csv.
+    pub fn csv_table(&self) -> Result<CsvTable, String> {
+        CsvTable::parse(&self.text()?).map_err(|err| format!("Файл {}: {err}", self.file_path))
+    }
+}
@@ -1,9 +1,13 @@
 pub(crate) mod binary_search;
+pub(crate) mod csv_table;
+pub(crate) mod data_file;
 pub(crate) mod json_file;
 pub(crate) mod linear_interpolation;
 pub(crate) mod physical_constants;
 pub(crate) mod point;
 pub(crate) mod round;
+pub(crate) mod schema_version;
 pub(crate) mod system_of_units;
+pub(crate) mod unit_system;
 pub(crate) mod visualisation;
 pub(crate) mod water_density;
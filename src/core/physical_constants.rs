@@ -1 +1,3 @@
 pub const EART_GRAVITY: f64 = 9.81; // [м/с^2]
+pub const STEEL_YOUNG_MODULUS: f64 = 2.06e11; // [Па]
+pub const STEEL_YIELD_STRESS: f64 = 235.0; // [МПа]
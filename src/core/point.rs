@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug, Clone, Copy, Serialize)]
+#[derive(Deserialize, Debug, Clone, Copy, Serialize, PartialEq)]
 pub struct Point {
     pub x: f64,
     pub y: f64,
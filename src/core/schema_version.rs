@@ -0,0 +1,93 @@
+use log::warn;
+use serde_json::Value;
+
+///
+/// Миграция файла входных данных с версии формата `from` на версию `from + 1`.
+/// Parameters:
+///     from - исходная версия формата,
+///     description - описание изменений формата,
+///     migrate - преобразование содержимого файла.
+pub struct Migration {
+    pub from: u32,
+    pub description: &'static str,
+    pub migrate: fn(Value) -> Result<Value, String>,
+}
+
+///
+/// Версия формата файла входных данных.
+/// Версия задается полем `version` в корне файла, файл без поля `version` имеет версию 1.
+/// Файлы старых версий последовательно обновляются миграциями до текущей версии при загрузке,
+/// файлы более новых версий отклоняются до разбора содержимого.
+/// Parameters:
+///     name - название вида файла для сообщений,
+///     current - текущая версия формата,
+///     migrations - миграции на каждую следующую версию.
+pub struct SchemaVersion {
+    name: &'static str,
+    current: u32,
+    migrations: &'static [Migration],
+}
+
+impl SchemaVersion {
+    ///
+    /// Основной конструктор.
+    pub const fn new(name: &'static str, current: u32, migrations: &'static [Migration]) -> Self {
+        SchemaVersion {
+            name,
+            current,
+            migrations,
+        }
+    }
+
+    ///
+    /// Версия формата файла, если поле `version` не задано - 1.
+    pub fn version(&self, value: &Value) -> Result<u32, String> {
+        match value.get("version") {
+            None => Ok(1),
+            Some(version) => version
+                .as_u64()
+                .filter(|version| *version >= 1 && *version <= u32::MAX as u64)
+                .map(|version| version as u32)
+                .ok_or(format!(
+                    "Версия формата {} должна быть целым числом больше нуля: {version}.",
+                    self.name
+                )),
+        }
+    }
+
+    ///
+    /// Обновляет содержимое файла до текущей версии формата.
+    /// Каждая выполненная миграция сопровождается предупреждением.
+    pub fn upgrade(&self, value: Value) -> Result<Value, String> {
+        let mut version = self.version(&value)?;
+        if version > self.current {
+            return Err(format!(
+                "Версия формата {} {version} не поддерживается, поддерживаемая версия: {}. Обновите программу.",
+                self.name, self.current
+            ));
+        }
+        let mut value = value;
+        while version < self.current {
+            let migration = self
+                .migrations
+                .iter()
+                .find(|migration| migration.from == version)
+                .ok_or(format!(
+                    "Отсутствует миграция {} с версии {version}.",
+                    self.name
+                ))?;
+            warn!(
+                "SchemaVersion.upgrade() | {} версии {version} обновлен до версии {}: {}",
+                self.name,
+                version + 1,
+                migration.description
+            );
+            value = (migration.migrate)(value)?;
+            version += 1;
+        }
+        if let Value::Object(map) = &mut value {
+            map.insert("version".to_string(), Value::from(self.current));
+        }
+        Ok(value)
+    }
+}
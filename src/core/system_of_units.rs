@@ -1,28 +1,268 @@
-use serde::Deserialize;
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Neg, Sub},
+};
 
-#[derive(Deserialize, Debug)]
-#[serde(from = "f64")]
-pub struct Newton(pub f64);
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug)]
-pub struct Tons(pub f64);
+use super::physical_constants::EART_GRAVITY;
 
+///
+/// Объявляет физическую величину - обертку над f64 с единицей измерения.
+/// Величины одной размерности можно складывать, вычитать и умножать на число.
+macro_rules! physical_unit {
+    ($(#[$doc:meta])* $name:ident, $suffix:literal) => {
+        $(#[$doc])*
+        #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+        pub struct $name(pub f64);
+
+        impl $name {
+            ///
+            /// Значение величины без единицы измерения.
+            pub fn value(&self) -> f64 {
+                self.0
+            }
+
+            ///
+            /// Обозначение единицы измерения.
+            pub fn suffix() -> &'static str {
+                $suffix
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, rhs: $name) -> Self::Output {
+                $name(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, rhs: $name) -> Self::Output {
+                $name(self.0 - rhs.0)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> Self::Output {
+                $name(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = $name;
+
+            fn mul(self, rhs: f64) -> Self::Output {
+                $name(self.0 * rhs)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match f.precision() {
+                    Some(precision) => {
+                        write!(f, "{:.*} {}", precision, self.value(), Self::suffix())
+                    }
+                    None => write!(f, "{} {}", self.value(), Self::suffix()),
+                }
+            }
+        }
+    };
+}
+
+///
+/// Объявляет пересчет между единицами измерения одной величины: to = from * factor.
+macro_rules! unit_conversion {
+    ($from:ident, $to:ident, $factor:expr) => {
+        impl From<$from> for $to {
+            fn from(value: $from) -> Self {
+                $to(value.0 * $factor)
+            }
+        }
+
+        impl From<$to> for $from {
+            fn from(value: $to) -> Self {
+                $from(value.0 / $factor)
+            }
+        }
+    };
+}
+
+physical_unit!(
+    ///
+    /// Длина [м].
+    Meters,
+    "м"
+);
+physical_unit!(
+    ///
+    /// Площадь [м^2].
+    SquareMeters,
+    "м^2"
+);
+physical_unit!(
+    ///
+    /// Объем [м^3].
+    CubicMeters,
+    "м^3"
+);
+physical_unit!(
+    ///
+    /// Масса [т].
+    Tons,
+    "т"
+);
+physical_unit!(
+    ///
+    /// Сила [тс] - вес массы в 1 т.
+    TonsForce,
+    "тс"
+);
+physical_unit!(
+    ///
+    /// Интенсивность распределенной нагрузки [т/м].
+    TonsPerMeter,
+    "т/м"
+);
+physical_unit!(
+    ///
+    /// Момент силы [т*м].
+    TonMeters,
+    "т*м"
+);
+physical_unit!(
+    ///
+    /// Статический момент массы [т*м].
+    /// В отличие от TonMeters не является моментом силы, переводится в него через вес массы.
+    MassMoment,
+    "т*м"
+);
+physical_unit!(
+    ///
+    /// Число тонн на 1 см осадки [т/см].
+    TonsPerCentimeter,
+    "т/см"
+);
+physical_unit!(
+    ///
+    /// Момент, дифферентующий на 1 см [т*м/см].
+    TonMetersPerCentimeter,
+    "т*м/см"
+);
+physical_unit!(
+    ///
+    /// Плотность [т/м^3].
+    TonsPerCubicMeter,
+    "т/м^3"
+);
+physical_unit!(
+    ///
+    /// Напряжение [тс/м^2].
+    TonsPerSquareMeter,
+    "тс/м^2"
+);
+physical_unit!(
+    ///
+    /// Сила в системе СИ [Н].
+    Newton,
+    "Н"
+);
+physical_unit!(
+    ///
+    /// Сила в системе СИ [кН].
+    KiloNewtons,
+    "кН"
+);
+physical_unit!(
+    ///
+    /// Интенсивность распределенной нагрузки в системе СИ [кН/м].
+    KiloNewtonsPerMeter,
+    "кН/м"
+);
+physical_unit!(
+    ///
+    /// Момент силы в системе СИ [кН*м].
+    KiloNewtonMeters,
+    "кН*м"
+);
+physical_unit!(
+    ///
+    /// Плотность в системе СИ [кг/м^3].
+    KilogramsPerCubicMeter,
+    "кг/м^3"
+);
+physical_unit!(
+    ///
+    /// Напряжение в системе СИ [МПа].
+    MegaPascals,
+    "МПа"
+);
+
+unit_conversion!(Tons, TonsForce, 1.0);
+unit_conversion!(TonsForce, Newton, 1000.0 * EART_GRAVITY);
+unit_conversion!(Newton, KiloNewtons, 0.001);
+unit_conversion!(TonsPerMeter, KiloNewtonsPerMeter, EART_GRAVITY);
+unit_conversion!(TonMeters, KiloNewtonMeters, EART_GRAVITY);
+unit_conversion!(TonsPerCubicMeter, KilogramsPerCubicMeter, 1000.0);
+unit_conversion!(TonsPerSquareMeter, MegaPascals, EART_GRAVITY / 1000.0);
+
+impl From<TonsForce> for KiloNewtons {
+    fn from(value: TonsForce) -> Self {
+        KiloNewtons::from(Newton::from(value))
+    }
+}
+
+impl From<KiloNewtons> for TonsForce {
+    fn from(value: KiloNewtons) -> Self {
+        TonsForce::from(Newton::from(value))
+    }
+}
+
+///
+/// Вес массы value [т] в ньютонах.
 impl From<f64> for Newton {
     fn from(value: f64) -> Self {
-        let newton = value * 1000.0 * 9.81;
-        Newton(newton)
+        Newton::from(Tons(value))
     }
 }
 
 impl From<Tons> for Newton {
-    fn from(tonn: Tons) -> Newton {
-        let newton = tonn.0 * 1000.0 * 9.81;
-        Newton(newton)
+    fn from(value: Tons) -> Self {
+        Newton::from(TonsForce::from(value))
     }
 }
 
 impl From<Newton> for Tons {
     fn from(value: Newton) -> Self {
-        Tons((value.0 / 9.81) / 1000.0)
+        Tons::from(TonsForce::from(value))
+    }
+}
+
+impl Mul<Meters> for TonsPerMeter {
+    type Output = TonsForce;
+
+    fn mul(self, rhs: Meters) -> Self::Output {
+        TonsForce(self.0 * rhs.0)
+    }
+}
+
+impl Mul<Meters> for Tons {
+    type Output = MassMoment;
+
+    fn mul(self, rhs: Meters) -> Self::Output {
+        MassMoment(self.0 * rhs.0)
+    }
+}
+
+impl Mul<Meters> for TonsForce {
+    type Output = TonMeters;
+
+    fn mul(self, rhs: Meters) -> Self::Output {
+        TonMeters(self.0 * rhs.0)
     }
 }
@@ -0,0 +1,139 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use super::{
+    data_file::DataFile,
+    system_of_units::{
+        KiloNewtonMeters, KiloNewtons, KiloNewtonsPerMeter, MegaPascals, TonMeters, TonsForce,
+        TonsPerMeter, TonsPerSquareMeter,
+    },
+};
+
+///
+/// Система единиц для вывода результатов расчета.
+/// Расчет выполняется в тоннах-силы, пересчет выполняется только при выводе
+/// с использованием ускорения свободного падения EART_GRAVITY.
+///     TonnesForce - тс, т/м, т*м, тс/м^2,
+///     KiloNewtons - кН, кН/м, кН*м, кПа,
+///     MegaNewtons - МН, МН/м, МН*м, МПа.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitSystem {
+    #[default]
+    TonnesForce,
+    KiloNewtons,
+    MegaNewtons,
+}
+
+#[derive(Deserialize)]
+struct UnitSystemInput {
+    #[serde(default)]
+    unit_system: UnitSystem,
+}
+
+impl UnitSystem {
+    ///
+    /// Create the object from json, toml or yaml file (key `unit_system`, по умолчанию тонны-силы).
+    #[instrument(skip_all, err, target = "UnitSystem::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let input: UnitSystemInput = DataFile::new(file_path).deserialize()?;
+        Ok(input.unit_system)
+    }
+
+    ///
+    /// Пересчитывает силу в выбранную систему единиц.
+    pub fn force(&self, value: TonsForce) -> f64 {
+        match self {
+            UnitSystem::TonnesForce => value.value(),
+            UnitSystem::KiloNewtons => KiloNewtons::from(value).value(),
+            UnitSystem::MegaNewtons => KiloNewtons::from(value).value() / 1000.0,
+        }
+    }
+
+    ///
+    /// Пересчитывает интенсивность нагрузки в выбранную систему единиц.
+    pub fn intensity(&self, value: TonsPerMeter) -> f64 {
+        match self {
+            UnitSystem::TonnesForce => value.value(),
+            UnitSystem::KiloNewtons => KiloNewtonsPerMeter::from(value).value(),
+            UnitSystem::MegaNewtons => KiloNewtonsPerMeter::from(value).value() / 1000.0,
+        }
+    }
+
+    ///
+    /// Пересчитывает момент в выбранную систему единиц.
+    pub fn moment(&self, value: TonMeters) -> f64 {
+        match self {
+            UnitSystem::TonnesForce => value.value(),
+            UnitSystem::KiloNewtons => KiloNewtonMeters::from(value).value(),
+            UnitSystem::MegaNewtons => KiloNewtonMeters::from(value).value() / 1000.0,
+        }
+    }
+
+    ///
+    /// Пересчитывает напряжение в выбранную систему единиц.
+    pub fn stress(&self, value: MegaPascals) -> f64 {
+        match self {
+            UnitSystem::TonnesForce => TonsPerSquareMeter::from(value).value(),
+            UnitSystem::KiloNewtons => value.value() * 1000.0,
+            UnitSystem::MegaNewtons => value.value(),
+        }
+    }
+
+    ///
+    /// Обозначение единицы силы.
+    pub fn force_unit(&self) -> &'static str {
+        match self {
+            UnitSystem::TonnesForce => TonsForce::suffix(),
+            UnitSystem::KiloNewtons => KiloNewtons::suffix(),
+            UnitSystem::MegaNewtons => "МН",
+        }
+    }
+
+    ///
+    /// Обозначение единицы интенсивности нагрузки.
+    pub fn intensity_unit(&self) -> &'static str {
+        match self {
+            UnitSystem::TonnesForce => TonsPerMeter::suffix(),
+            UnitSystem::KiloNewtons => KiloNewtonsPerMeter::suffix(),
+            UnitSystem::MegaNewtons => "МН/м",
+        }
+    }
+
+    ///
+    /// Обозначение единицы момента.
+    pub fn moment_unit(&self) -> &'static str {
+        match self {
+            UnitSystem::TonnesForce => TonMeters::suffix(),
+            UnitSystem::KiloNewtons => KiloNewtonMeters::suffix(),
+            UnitSystem::MegaNewtons => "МН*м",
+        }
+    }
+
+    ///
+    /// Обозначение единицы напряжения.
+    pub fn stress_unit(&self) -> &'static str {
+        match self {
+            UnitSystem::TonnesForce => TonsPerSquareMeter::suffix(),
+            UnitSystem::KiloNewtons => "кПа",
+            UnitSystem::MegaNewtons => MegaPascals::suffix(),
+        }
+    }
+}
+
+impl FromStr for UnitSystem {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "t" | "tf" | "tonnes_force" => Ok(UnitSystem::TonnesForce),
+            "kn" | "si" | "kilo_newtons" => Ok(UnitSystem::KiloNewtons),
+            "mn" | "mega_newtons" => Ok(UnitSystem::MegaNewtons),
+            _ => Err(format!(
+                "Неизвестная система единиц: {s}. Допустимые значения: t, kn, mn."
+            )),
+        }
+    }
+}
@@ -4,8 +4,16 @@ use plotly::{
     Layout, Plot, Scatter,
 };
 
-use crate::strength::{ship::spatium_functions::SpatiumFunctions, strength::Strength};
+use super::{
+    system_of_units::{TonMeters, TonsForce, TonsPerMeter},
+    unit_system::UnitSystem,
+};
+use crate::strength::{
+    report::report_format::ReportFormat, ship::spatium_functions::SpatiumFunctions,
+    strength::Strength,
+};
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DiagrammType {
     LightweightIntensity,
     DeadweightIntensity,
@@ -18,27 +26,73 @@ pub enum DiagrammType {
     BendingMomentWithCorrection,
 }
 
-impl std::fmt::Display for DiagrammType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl DiagrammType {
+    ///
+    /// Все типы эпюр.
+    pub fn all() -> [DiagrammType; 9] {
+        [
+            DiagrammType::LightweightIntensity,
+            DiagrammType::DeadweightIntensity,
+            DiagrammType::DisplacementIntensity,
+            DiagrammType::BuoyancyIntensity,
+            DiagrammType::TotalShipload,
+            DiagrammType::ShareForce,
+            DiagrammType::ShareForceWithCorrection,
+            DiagrammType::BendingMoment,
+            DiagrammType::BendingMomentWithCorrection,
+        ]
+    }
+
+    ///
+    /// Подпись эпюры с единицами измерения выбранной системы единиц.
+    pub fn label(&self, unit_system: UnitSystem) -> String {
+        let intensity = unit_system.intensity_unit();
+        let force = unit_system.force_unit();
+        let moment = unit_system.moment_unit();
         match self {
-            DiagrammType::LightweightIntensity => write!(f, "Lightweight intensity, [т / м]"),
-            DiagrammType::DeadweightIntensity => write!(f, "Deadweight intensity, [ т/ м]"),
-            DiagrammType::DisplacementIntensity => write!(f, "Displacement intensity, [т / м]"),
-            DiagrammType::BuoyancyIntensity => write!(f, "Buoyancy intensity, [т / м]"),
-            DiagrammType::TotalShipload => write!(f, "Total shipload, [т / м]"),
-            DiagrammType::ShareForce => write!(f, "Share force, [т]"),
-            DiagrammType::ShareForceWithCorrection => write!(f, "Share force with correction, [т]"),
-            DiagrammType::BendingMoment => write!(f, "Bending moment, [т * м]"),
+            DiagrammType::LightweightIntensity => format!("Lightweight intensity, [{intensity}]"),
+            DiagrammType::DeadweightIntensity => format!("Deadweight intensity, [{intensity}]"),
+            DiagrammType::DisplacementIntensity => {
+                format!("Displacement intensity, [{intensity}]")
+            }
+            DiagrammType::BuoyancyIntensity => format!("Buoyancy intensity, [{intensity}]"),
+            DiagrammType::TotalShipload => format!("Total shipload, [{intensity}]"),
+            DiagrammType::ShareForce => format!("Share force, [{force}]"),
+            DiagrammType::ShareForceWithCorrection => {
+                format!("Share force with correction, [{force}]")
+            }
+            DiagrammType::BendingMoment => format!("Bending moment, [{moment}]"),
             DiagrammType::BendingMomentWithCorrection => {
-                write!(f, "Bending moment with correction, [т * м]")
+                format!("Bending moment with correction, [{moment}]")
             }
         }
     }
+
+    ///
+    /// Пересчитывает значение эпюры из тонн-силы в выбранную систему единиц.
+    fn convert(&self, unit_system: UnitSystem, value: f64) -> f64 {
+        match self {
+            DiagrammType::ShareForce | DiagrammType::ShareForceWithCorrection => {
+                unit_system.force(TonsForce(value))
+            }
+            DiagrammType::BendingMoment | DiagrammType::BendingMomentWithCorrection => {
+                unit_system.moment(TonMeters(value))
+            }
+            _ => unit_system.intensity(TonsPerMeter(value)),
+        }
+    }
+}
+
+impl std::fmt::Display for DiagrammType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label(UnitSystem::default()))
+    }
 }
 
 pub struct Visualisation<'a> {
     spatium_length: f64,
     strength: &'a Strength,
+    unit_system: UnitSystem,
 }
 
 impl<'a> Visualisation<'a> {
@@ -46,65 +100,60 @@ impl<'a> Visualisation<'a> {
         Visualisation {
             spatium_length,
             strength,
+            unit_system: UnitSystem::default(),
         }
     }
 
-    pub fn show(&self, diagramm_type: DiagrammType) {
-        match diagramm_type {
-            DiagrammType::LightweightIntensity => {
-                self.plot(
-                    self.strength.lightweight_intensity(),
-                    diagramm_type.to_string(),
-                )
-                .show();
-            }
-            DiagrammType::DeadweightIntensity => {
-                self.plot(
-                    self.strength.deadweight_intensity(),
-                    diagramm_type.to_string(),
-                )
-                .show();
-            }
-            DiagrammType::DisplacementIntensity => {
-                self.plot(
-                    self.strength.displacement_intensity(),
-                    diagramm_type.to_string(),
-                )
-                .show();
-            }
-            DiagrammType::TotalShipload => self
-                .plot(self.strength.total_shipload(), diagramm_type.to_string())
-                .show(),
-            DiagrammType::BuoyancyIntensity => self
-                .plot(
-                    self.strength.buoyancy_intensity(),
-                    diagramm_type.to_string(),
-                )
-                .show(),
-            DiagrammType::ShareForce => self
-                .plot(self.strength.share_force(), diagramm_type.to_string())
-                .show(),
-            DiagrammType::ShareForceWithCorrection => {
-                match self.strength.share_force_with_correction() {
-                    Some(share_force) => {
-                        self.plot(share_force, diagramm_type.to_string()).show();
-                    }
-                    _ => (),
-                }
-            }
-            DiagrammType::BendingMoment => {
-                self.plot(self.strength.bending_moment(), diagramm_type.to_string())
-                    .show();
-            }
+    ///
+    /// Задает систему единиц для вывода эпюр.
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        self.unit_system = unit_system;
+        self
+    }
+
+    ///
+    /// Возвращает эпюру в выбранной системе единиц.
+    /// Если эпюра не построена (эпюры с поправкой), возвращает None.
+    pub fn diagramm(&self, diagramm_type: DiagrammType) -> Option<SpatiumFunctions> {
+        let s_fs = match diagramm_type {
+            DiagrammType::LightweightIntensity => Some(self.strength.lightweight_intensity()),
+            DiagrammType::DeadweightIntensity => Some(self.strength.deadweight_intensity()),
+            DiagrammType::DisplacementIntensity => Some(self.strength.displacement_intensity()),
+            DiagrammType::BuoyancyIntensity => Some(self.strength.buoyancy_intensity()),
+            DiagrammType::TotalShipload => Some(self.strength.total_shipload()),
+            DiagrammType::ShareForce => Some(self.strength.share_force()),
+            DiagrammType::ShareForceWithCorrection => self.strength.share_force_with_correction(),
+            DiagrammType::BendingMoment => Some(self.strength.bending_moment()),
             DiagrammType::BendingMomentWithCorrection => {
-                match self.strength.bending_moment_with_correction() {
-                    Some(bending_moment) => {
-                        self.plot(bending_moment, diagramm_type.to_string()).show();
-                    }
-                    _ => (),
-                }
+                self.strength.bending_moment_with_correction()
             }
-        };
+        }?;
+        Some(s_fs.converted(|value| diagramm_type.convert(self.unit_system, value)))
+    }
+
+    pub fn show(&self, diagramm_type: DiagrammType) {
+        if let Some(s_fs) = self.diagramm(diagramm_type) {
+            self.plot(&s_fs, diagramm_type.label(self.unit_system))
+                .show();
+        }
+    }
+
+    ///
+    /// Эпюра в формате CSV: абсцисса и значение на границах теоретических шпаций.
+    /// Заголовок столбца значений содержит единицы измерения выбранной системы единиц.
+    pub fn csv(&self, diagramm_type: DiagrammType) -> Option<String> {
+        let s_fs = self.diagramm(diagramm_type)?;
+        let rows: Vec<Vec<String>> = s_fs
+            .as_ref()
+            .iter()
+            .flat_map(|spatium| {
+                [
+                    vec![spatium.x1().to_string(), spatium.f_x1().to_string()],
+                    vec![spatium.x2().to_string(), spatium.f_x2().to_string()],
+                ]
+            })
+            .collect();
+        Some(ReportFormat::Csv.table(&["x, [м]", &diagramm_type.label(self.unit_system)], &rows))
     }
 
     pub fn plot(&self, s_fs: &SpatiumFunctions, diagramm_type: String) -> Plot {
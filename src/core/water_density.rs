@@ -3,7 +3,7 @@ use std::ops::Div;
 use serde::Deserialize;
 use tracing::instrument;
 
-use super::json_file::JsonFile;
+use super::data_file::DataFile;
 
 ///
 /// Плотность воды.
@@ -23,12 +23,10 @@ impl WaterDensity {
         self.water_density
     }
 
-    /// Create the object from json file.
-    #[instrument(skip_all, err, target = "WaterDensity::from_json_file")]
-    pub fn from_json_file(file_path: String) -> Result<Self, String> {
-        let json = JsonFile::new(file_path);
-        let content = json.content().map_err(|err| err.to_string())?;
-        serde_json::from_reader(content).map_err(|err| err.to_string())
+    /// Create the object from json, toml or yaml file.
+    #[instrument(skip_all, err, target = "WaterDensity::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        DataFile::new(file_path).deserialize()
     }
 }
 
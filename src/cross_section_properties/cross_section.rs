@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use super::plate_element::PlateElement;
+
+///
+/// Поперечное сечение корпуса судна (эквивалентный брус), составленное из тонкостенных плоских элементов.
+/// Элементы задаются для всего сечения (оба борта).
+/// Parameters:
+///     abscissa - абсцисса сечения, отсчитывается от мидель шпангоута,
+///     положительна в нос судна [м],
+///     elements - элементы сечения.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CrossSection {
+    abscissa: f64,
+    elements: Vec<PlateElement>,
+}
+
+impl CrossSection {
+    ///
+    /// Валидация: сечение должно содержать элементы, каждый элемент должен иметь
+    /// положительные толщину и длину.
+    pub(crate) fn validate(self) -> Result<Self, String> {
+        if self.elements.is_empty() {
+            return Err(format!(
+                "Сечение с абсциссой {} м не содержит элементов.",
+                self.abscissa
+            ));
+        }
+        for (index, element) in self.elements.iter().enumerate() {
+            element.validate().map_err(|err| {
+                format!(
+                    "Сечение с абсциссой {} м, элемент {index}: {err}",
+                    self.abscissa
+                )
+            })?;
+        }
+        Ok(self)
+    }
+
+    ///
+    /// Абсцисса сечения [м].
+    pub fn abscissa(&self) -> f64 {
+        self.abscissa
+    }
+
+    ///
+    /// Элементы сечения.
+    pub fn elements(&self) -> &Vec<PlateElement> {
+        &self.elements
+    }
+
+    ///
+    /// Площадь сечения [м^2].
+    pub fn area(&self) -> f64 {
+        self.elements.iter().map(|element| element.area()).sum()
+    }
+
+    ///
+    /// Аппликата нейтральной оси (центра тяжести сечения) [м].
+    pub fn neutral_axis(&self) -> f64 {
+        let static_moment: f64 = self
+            .elements
+            .iter()
+            .map(|element| element.area() * element.z())
+            .sum();
+        static_moment / self.area()
+    }
+
+    ///
+    /// Момент инерции сечения относительно горизонтальной нейтральной оси [м^4].
+    pub fn moment_of_inertia(&self) -> f64 {
+        let neutral_axis = self.neutral_axis();
+        self.elements
+            .iter()
+            .map(|element| {
+                element.own_moment_of_inertia()
+                    + element.area() * (element.z() - neutral_axis).powi(2)
+            })
+            .sum()
+    }
+
+    ///
+    /// Статический момент части сечения, расположенной выше нейтральной оси,
+    /// относительно нейтральной оси [м^3].
+    pub fn first_moment_of_area(&self) -> f64 {
+        let neutral_axis = self.neutral_axis();
+        self.elements
+            .iter()
+            .map(|element| element.first_moment_above(neutral_axis))
+            .sum()
+    }
+
+    ///
+    /// Суммарная толщина элементов, пересекающих нейтральную ось
+    /// (обшивка бортов и продольные переборки), воспринимающих перерезывающую силу [мм].
+    pub fn shear_thickness(&self) -> f64 {
+        let neutral_axis = self.neutral_axis();
+        self.elements
+            .iter()
+            .filter(|element| element.crosses(neutral_axis))
+            .map(|element| element.thickness())
+            .sum()
+    }
+
+    ///
+    /// Отношение S / (I * t), связывающее перерезывающую силу с наибольшим касательным
+    /// напряжением на нейтральной оси: tau = N * S / (I * t) [1/м^2].
+    /// Толщина t принимается в метрах. Если нейтральную ось не пересекает ни один элемент,
+    /// отношение не определено.
+    pub fn shear_stress_factor(&self) -> Result<f64, String> {
+        let thickness = self.shear_thickness() / 1000.0;
+        if thickness <= 0.0 {
+            return Err(format!(
+                "Сечение с абсциссой {} м не содержит элементов, пересекающих нейтральную ось (обшивка борта, продольная переборка).",
+                self.abscissa
+            ));
+        }
+        Ok(self.first_moment_of_area() / (self.moment_of_inertia() * thickness))
+    }
+
+    ///
+    /// Наименьшая и наибольшая аппликаты элементов сечения (днище и палуба) [м].
+    pub fn z_range(&self) -> (f64, f64) {
+        self.elements
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), element| {
+                let (z_min, z_max) = element.z_range();
+                (min.min(z_min), max.max(z_max))
+            })
+    }
+}
@@ -0,0 +1,141 @@
+use serde::Deserialize;
+use tracing::instrument;
+
+use super::cross_section::CrossSection;
+use crate::core::{data_file::DataFile, linear_interpolation::LinearInterpolation};
+
+///
+/// Поперечные сечения корпуса судна по длине.
+/// Геометрические характеристики между сечениями интерполируются линейно,
+/// за пределами крайних сечений принимаются равными характеристикам крайних сечений.
+/// Parameters:
+///     sections - сечения, отсортированные по возрастанию абсциссы,
+///     shear_stress_factors - отношения S / (I * t) для каждого сечения или ошибка,
+///     если для какого-либо сечения отношение не определено.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossSections {
+    sections: Vec<CrossSection>,
+    shear_stress_factors: Result<Vec<f64>, String>,
+}
+
+///
+/// Поперечные сечения корпуса во входном файле.
+#[derive(Deserialize)]
+struct CrossSectionsData {
+    sections: Vec<CrossSection>,
+}
+
+impl CrossSections {
+    ///
+    /// Основной конструктор.
+    pub fn new(sections: Vec<CrossSection>) -> Result<Self, String> {
+        let sections = Self::validate(sections)?;
+        let shear_stress_factors = sections
+            .iter()
+            .map(CrossSection::shear_stress_factor)
+            .collect();
+        Ok(CrossSections {
+            sections,
+            shear_stress_factors,
+        })
+    }
+
+    ///
+    /// Вспомогательный конструктор.
+    /// Файл json, toml или yaml с полем `sections`.
+    #[instrument(skip_all, err, target = "CrossSections::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let data: CrossSectionsData = DataFile::new(file_path.clone()).deserialize()?;
+        CrossSections::new(data.sections).map_err(|err| format!("Файл {file_path}: {err}"))
+    }
+
+    ///
+    /// Валидация: должно быть задано хотя бы одно сечение, абсциссы сечений должны строго возрастать.
+    fn validate(input: Vec<CrossSection>) -> Result<Vec<CrossSection>, String> {
+        if input.is_empty() {
+            return Err("Поперечные сечения корпуса не заданы.".to_string());
+        }
+        let mut sections = Vec::with_capacity(input.len());
+        for section in input {
+            let section = section.validate()?;
+            if let Some(previous) = sections.last().map(CrossSection::abscissa) {
+                if section.abscissa() <= previous {
+                    return Err(format!(
+                        "Абсцисса сечения {} м не больше абсциссы предыдущего сечения {previous} м. Сечения должны быть отсортированы по абсциссе.",
+                        section.abscissa()
+                    ));
+                }
+            }
+            sections.push(section);
+        }
+        Ok(sections)
+    }
+
+    ///
+    /// Сечения корпуса.
+    pub fn sections(&self) -> &Vec<CrossSection> {
+        &self.sections
+    }
+
+    ///
+    /// Момент инерции сечения с абсциссой x относительно нейтральной оси [м^4].
+    pub fn moment_of_inertia(&self, x: f64) -> Result<f64, String> {
+        self.interpolated(x, |index| self.sections[index].moment_of_inertia())
+    }
+
+    ///
+    /// Аппликата нейтральной оси сечения с абсциссой x [м].
+    pub fn neutral_axis(&self, x: f64) -> Result<f64, String> {
+        self.interpolated(x, |index| self.sections[index].neutral_axis())
+    }
+
+    ///
+    /// Отношение S / (I * t) для сечения с абсциссой x [1/м^2].
+    /// Наибольшее касательное напряжение на нейтральной оси: tau = N * S / (I * t).
+    /// Отношение определяется для каждого сечения при создании, ошибка возвращается,
+    /// если хотя бы одно сечение не содержит элементов, пересекающих нейтральную ось.
+    pub fn shear_stress_factor(&self, x: f64) -> Result<f64, String> {
+        let factors = self.shear_stress_factors.as_ref().map_err(Clone::clone)?;
+        self.interpolated(x, |index| factors[index])
+    }
+
+    ///
+    /// Ближайшее к абсциссе x сечение.
+    pub fn nearest(&self, x: f64) -> &CrossSection {
+        self.sections
+            .iter()
+            .min_by(|a, b| {
+                (a.abscissa() - x)
+                    .abs()
+                    .total_cmp(&(b.abscissa() - x).abs())
+            })
+            .unwrap()
+    }
+
+    ///
+    /// Линейно интерполирует характеристику сечения по абсциссе.
+    /// Parameters:
+    ///     x - абсцисса,
+    ///     property - характеристика сечения с заданным индексом.
+    fn interpolated(&self, x: f64, property: impl Fn(usize) -> f64) -> Result<f64, String> {
+        let last = self.sections.len() - 1;
+        if x <= self.sections[0].abscissa() {
+            return Ok(property(0));
+        }
+        if x >= self.sections[last].abscissa() {
+            return Ok(property(last));
+        }
+        let right = self
+            .sections
+            .iter()
+            .position(|section| section.abscissa() >= x)
+            .unwrap();
+        LinearInterpolation::new(
+            property(right - 1),
+            property(right),
+            self.sections[right - 1].abscissa(),
+            self.sections[right].abscissa(),
+        )
+        .interpolated_value(x)
+    }
+}
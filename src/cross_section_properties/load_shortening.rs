@@ -0,0 +1,60 @@
+use super::plate_element::PlateElement;
+use crate::core::physical_constants::STEEL_YOUNG_MODULUS;
+
+///
+/// Кривая "нагрузка - укорочение" (зависимость напряжения от деформации) элемента сечения
+/// для расчета предельной прочности корпуса методом Смита. Деформация положительна при растяжении,
+/// напряжение [МПа] положительно при растяжении.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoadShortening {
+    ///
+    /// Жесткий (угловой) элемент и обшивка без подкрепленной панели:
+    /// упругопластический материал при растяжении и сжатии.
+    HardCorner { yield_stress: f64 },
+    ///
+    /// Подкрепленная панель: при растяжении - упругопластический материал, при сжатии - упругая работа
+    /// до критического напряжения (поправка Джонсона-Остенфельда) и снижение несущей способности
+    /// после потери устойчивости: sigma = sigma_cr * sqrt(eps_cr / |eps|).
+    StiffenedPanel {
+        yield_stress: f64,
+        critical_stress: f64,
+    },
+}
+
+impl LoadShortening {
+    ///
+    /// Вспомогательный конструктор.
+    /// Тип кривой определяется наличием у элемента подкрепленной панели.
+    pub fn from_element(element: &PlateElement) -> Self {
+        let yield_stress = element.yield_stress();
+        match element.panel() {
+            Some(panel) => LoadShortening::StiffenedPanel {
+                yield_stress,
+                critical_stress: panel.critical_stress(element.thickness(), yield_stress),
+            },
+            None => LoadShortening::HardCorner { yield_stress },
+        }
+    }
+
+    ///
+    /// Напряжение в элементе при заданной деформации [МПа].
+    pub fn stress(&self, strain: f64) -> f64 {
+        let young_modulus = STEEL_YOUNG_MODULUS / 1.0e6;
+        let elastic_plastic =
+            |yield_stress: f64| (young_modulus * strain).clamp(-yield_stress, yield_stress);
+        match *self {
+            LoadShortening::HardCorner { yield_stress } => elastic_plastic(yield_stress),
+            LoadShortening::StiffenedPanel {
+                yield_stress,
+                critical_stress,
+            } => {
+                let critical_strain = critical_stress / young_modulus;
+                if strain >= -critical_strain {
+                    elastic_plastic(yield_stress)
+                } else {
+                    -critical_stress * (critical_strain / -strain).sqrt()
+                }
+            }
+        }
+    }
+}
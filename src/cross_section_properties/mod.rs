@@ -0,0 +1,6 @@
+pub mod cross_section;
+pub mod cross_sections;
+pub mod load_shortening;
+pub mod plate_element;
+pub mod stiffened_panel;
+pub mod ultimate_strength;
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use super::stiffened_panel::StiffenedPanel;
+use crate::core::physical_constants::STEEL_YIELD_STRESS;
+
+///
+/// Тонкостенный плоский элемент поперечного сечения корпуса (участок обшивки, настила, переборки).
+/// Координаты отсчитываются в плоскости шпангоута: ордината y - от диаметральной плоскости,
+/// положительна в сторону правого борта, аппликата z - от основной плоскости.
+/// Parameters:
+///     y1, z1 - координаты начала элемента [м],
+///     y2, z2 - координаты конца элемента [м],
+///     thickness - толщина элемента [мм],
+///     yield_stress - предел текучести материала [МПа], по умолчанию STEEL_YIELD_STRESS,
+///     panel - подкрепленная панель палубы или днища, к которой относится элемент (необязательная).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct PlateElement {
+    y1: f64,
+    z1: f64,
+    y2: f64,
+    z2: f64,
+    thickness: f64,
+    #[serde(default)]
+    yield_stress: Option<f64>,
+    #[serde(default)]
+    panel: Option<StiffenedPanel>,
+}
+
+impl PlateElement {
+    ///
+    /// Валидация: толщина, длина и предел текучести элемента должны быть больше нуля,
+    /// параметры панели должны быть корректны.
+    pub(crate) fn validate(self) -> Result<Self, String> {
+        if self.thickness <= 0.0 {
+            return Err(format!(
+                "Толщина элемента сечения должна быть больше нуля: {} мм.",
+                self.thickness
+            ));
+        }
+        if self.length() <= 0.0 {
+            return Err(format!(
+                "Элемент сечения ({}, {}) - ({}, {}) имеет нулевую длину.",
+                self.y1, self.z1, self.y2, self.z2
+            ));
+        }
+        if let Some(yield_stress) = self.yield_stress {
+            if yield_stress <= 0.0 {
+                return Err(format!(
+                    "Предел текучести материала элемента должен быть больше нуля: {yield_stress} МПа."
+                ));
+            }
+        }
+        if let Some(panel) = self.panel {
+            panel.validate()?;
+        }
+        Ok(self)
+    }
+
+    ///
+    /// Длина элемента [м].
+    pub fn length(&self) -> f64 {
+        (self.y2 - self.y1).hypot(self.z2 - self.z1)
+    }
+
+    ///
+    /// Толщина элемента [мм].
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    ///
+    /// Предел текучести материала элемента [МПа].
+    pub fn yield_stress(&self) -> f64 {
+        self.yield_stress.unwrap_or(STEEL_YIELD_STRESS)
+    }
+
+    ///
+    /// Подкрепленная панель, к которой относится элемент.
+    pub fn panel(&self) -> Option<&StiffenedPanel> {
+        self.panel.as_ref()
+    }
+
+    ///
+    /// Площадь элемента [м^2].
+    pub fn area(&self) -> f64 {
+        self.length() * self.thickness / 1000.0
+    }
+
+    ///
+    /// Аппликата центра тяжести элемента [м].
+    pub fn z(&self) -> f64 {
+        (self.z1 + self.z2) / 2.0
+    }
+
+    ///
+    /// Наименьшая и наибольшая аппликаты элемента [м].
+    pub fn z_range(&self) -> (f64, f64) {
+        (self.z1.min(self.z2), self.z1.max(self.z2))
+    }
+
+    ///
+    /// Собственный момент инерции элемента относительно горизонтальной оси,
+    /// проходящей через его центр тяжести [м^4]: i = A * (z2 - z1)^2 / 12.
+    /// Момент инерции толщины горизонтального элемента не учитывается (тонкостенное сечение).
+    pub fn own_moment_of_inertia(&self) -> f64 {
+        self.area() * (self.z2 - self.z1).powi(2) / 12.0
+    }
+
+    ///
+    /// Пересекает ли элемент горизонтальную ось с аппликатой z (борт, продольная переборка).
+    pub fn crosses(&self, z: f64) -> bool {
+        let (z_min, z_max) = self.z_range();
+        z_min < z && z < z_max
+    }
+
+    ///
+    /// Статический момент части элемента, расположенной выше горизонтальной оси с аппликатой z,
+    /// относительно этой оси [м^3].
+    pub fn first_moment_above(&self, z: f64) -> f64 {
+        let (z_min, z_max) = self.z_range();
+        if z_max <= z {
+            return 0.0;
+        }
+        if z_min == z_max {
+            return self.area() * (z_max - z);
+        }
+        let z_low = z_min.max(z);
+        let area = self.area() * (z_max - z_low) / (z_max - z_min);
+        area * ((z_max + z_low) / 2.0 - z)
+    }
+}
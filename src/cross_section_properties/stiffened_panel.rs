@@ -0,0 +1,118 @@
+use std::{f64::consts::PI, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::physical_constants::STEEL_YOUNG_MODULUS;
+
+///
+/// Расположение панели в поперечном сечении.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PanelLocation {
+    Deck,
+    Bottom,
+}
+
+impl Display for PanelLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            PanelLocation::Deck => "палуба",
+            PanelLocation::Bottom => "днище",
+        };
+        write!(f, "{name}")
+    }
+}
+
+///
+/// Панель, подкрепленная продольными ребрами жесткости (продольная система набора).
+/// Толщина обшивки панели равна толщине элемента сечения, к которому относится панель.
+/// Parameters:
+///     location - расположение панели (палуба, днище),
+///     stiffener_spacing - расстояние между продольными ребрами жесткости [мм],
+///     span - пролет ребра жесткости (расстояние между рамными связями) [м],
+///     stiffener_area - площадь поперечного сечения ребра без присоединенного пояска [см^2],
+///     stiffener_inertia - момент инерции ребра с присоединенным пояском [см^4].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct StiffenedPanel {
+    location: PanelLocation,
+    stiffener_spacing: f64,
+    span: f64,
+    stiffener_area: f64,
+    stiffener_inertia: f64,
+}
+
+impl StiffenedPanel {
+    ///
+    /// Валидация: шпация ребер, пролет и момент инерции ребра должны быть больше нуля,
+    /// площадь ребра не может быть отрицательной.
+    pub(crate) fn validate(self) -> Result<Self, String> {
+        if self.stiffener_spacing <= 0.0 || self.span <= 0.0 || self.stiffener_inertia <= 0.0 {
+            return Err(format!(
+                "Панель ({}): расстояние между ребрами, пролет и момент инерции ребра должны быть больше нуля.",
+                self.location
+            ));
+        }
+        if self.stiffener_area < 0.0 {
+            return Err(format!(
+                "Панель ({}): площадь ребра не может быть отрицательной: {} см^2.",
+                self.location, self.stiffener_area
+            ));
+        }
+        Ok(self)
+    }
+
+    ///
+    /// Расположение панели.
+    pub fn location(&self) -> PanelLocation {
+        self.location
+    }
+
+    ///
+    /// Эйлерово напряжение пластины между ребрами при сжатии вдоль ребер [МПа]:
+    /// sigma_e = 3.6 * E * (t / s)^2.
+    /// Parameters:
+    ///     thickness - толщина обшивки [мм].
+    pub fn plate_euler_stress(&self, thickness: f64) -> f64 {
+        3.6 * STEEL_YOUNG_MODULUS * (thickness / self.stiffener_spacing).powi(2) / 1.0e6
+    }
+
+    ///
+    /// Эйлерово напряжение ребра с присоединенным пояском как стержня на пролете между рамными связями [МПа]:
+    /// sigma_e = pi^2 * E * I / (A * l^2), A - площадь ребра с присоединенным пояском.
+    /// Parameters:
+    ///     thickness - толщина обшивки [мм].
+    pub fn column_euler_stress(&self, thickness: f64) -> f64 {
+        let area = self.stiffener_area * 1.0e-4 + self.stiffener_spacing * thickness * 1.0e-6;
+        PI.powi(2) * STEEL_YOUNG_MODULUS * self.stiffener_inertia * 1.0e-8
+            / (area * self.span.powi(2))
+            / 1.0e6
+    }
+
+    ///
+    /// Критическое напряжение панели - меньшее из критических напряжений пластины и ребра
+    /// с поправкой Джонсона-Остенфельда [МПа].
+    /// Parameters:
+    ///     thickness - толщина обшивки [мм],
+    ///     yield_stress - предел текучести [МПа].
+    pub fn critical_stress(&self, thickness: f64, yield_stress: f64) -> f64 {
+        johnson_ostenfeld(self.plate_euler_stress(thickness), yield_stress).min(johnson_ostenfeld(
+            self.column_euler_stress(thickness),
+            yield_stress,
+        ))
+    }
+}
+
+///
+/// Поправка Джонсона-Остенфельда на пластичность [МПа]:
+/// sigma_cr = sigma_e при sigma_e <= sigma_y / 2,
+/// sigma_cr = sigma_y * (1 - sigma_y / (4 * sigma_e)) при sigma_e > sigma_y / 2.
+/// Parameters:
+///     euler_stress - эйлерово напряжение [МПа],
+///     yield_stress - предел текучести [МПа].
+pub fn johnson_ostenfeld(euler_stress: f64, yield_stress: f64) -> f64 {
+    if euler_stress <= yield_stress / 2.0 {
+        euler_stress
+    } else {
+        yield_stress * (1.0 - yield_stress / (4.0 * euler_stress))
+    }
+}
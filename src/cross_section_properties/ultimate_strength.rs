@@ -0,0 +1,160 @@
+use serde::Serialize;
+
+use super::{cross_section::CrossSection, load_shortening::LoadShortening};
+use crate::core::{
+    physical_constants::STEEL_YOUNG_MODULUS,
+    system_of_units::{KiloNewtonMeters, TonMeters},
+};
+
+///
+/// Количество частей, на которые делится наклонный или вертикальный элемент сечения по высоте.
+const SUBDIVISIONS: usize = 10;
+///
+/// Количество шагов по кривизне в каждом направлении изгиба.
+const CURVATURE_STEPS: usize = 200;
+///
+/// Наибольшая кривизна в долях кривизны начала текучести.
+const MAX_CURVATURE_RATIO: f64 = 4.0;
+///
+/// Количество итераций поиска нейтральной оси методом деления отрезка пополам.
+const NEUTRAL_AXIS_ITERATIONS: usize = 60;
+
+///
+/// Точка кривой "изгибающий момент - кривизна".
+/// Parameters:
+///     curvature - кривизна, положительная при перегибе [1/м],
+///     neutral_axis - аппликата нейтральной оси [м],
+///     moment - изгибающий момент, положительный при перегибе [т*м].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct CurvaturePoint {
+    pub curvature: f64,
+    pub neutral_axis: f64,
+    pub moment: f64,
+}
+
+///
+/// Предельная прочность поперечного сечения корпуса при общем изгибе, метод Смита.
+/// Сечение разбивается на элементы с собственными кривыми "нагрузка - укорочение" (LoadShortening).
+/// Кривизна увеличивается по шагам, на каждом шаге итерационно определяется положение нейтральной оси,
+/// при котором сумма продольных сил в элементах равна нулю, и вычисляется изгибающий момент.
+/// Предельный момент - наибольший момент на кривой "момент - кривизна".
+/// Parameters:
+///     curve - кривая "момент - кривизна" для прогиба и перегиба.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UltimateStrength {
+    curve: Vec<CurvaturePoint>,
+}
+
+///
+/// Часть элемента сечения: площадь [м^2], аппликата центра тяжести [м] и кривая "нагрузка - укорочение".
+struct SmithElement {
+    area: f64,
+    z: f64,
+    load_shortening: LoadShortening,
+}
+
+impl UltimateStrength {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     section - поперечное сечение корпуса.
+    pub fn new(section: &CrossSection) -> Self {
+        let mut elements = vec![];
+        for element in section.elements() {
+            let load_shortening = LoadShortening::from_element(element);
+            let (z_min, z_max) = element.z_range();
+            let parts = if z_min == z_max { 1 } else { SUBDIVISIONS };
+            for part in 0..parts {
+                elements.push(SmithElement {
+                    area: element.area() / parts as f64,
+                    z: z_min + (z_max - z_min) * (part as f64 + 0.5) / parts as f64,
+                    load_shortening,
+                });
+            }
+        }
+        let (z_min, z_max) = section.z_range();
+        let neutral_axis = section.neutral_axis();
+        let min_yield_stress = section
+            .elements()
+            .iter()
+            .map(|element| element.yield_stress())
+            .fold(f64::INFINITY, f64::min);
+        let yield_curvature = min_yield_stress * 1.0e6
+            / (STEEL_YOUNG_MODULUS * (z_max - neutral_axis).max(neutral_axis - z_min));
+        let step = MAX_CURVATURE_RATIO * yield_curvature / CURVATURE_STEPS as f64;
+        let mut curve = vec![];
+        for direction in [-1.0, 1.0] {
+            for index in 1..=CURVATURE_STEPS {
+                let curvature = direction * step * index as f64;
+                curve.push(UltimateStrength::point(&elements, curvature, z_min, z_max));
+            }
+        }
+        UltimateStrength { curve }
+    }
+
+    ///
+    /// Определяет положение нейтральной оси и изгибающий момент при заданной кривизне.
+    fn point(elements: &[SmithElement], curvature: f64, z_min: f64, z_max: f64) -> CurvaturePoint {
+        // Продольная сила [МН] при положении нейтральной оси z_na.
+        let force = |neutral_axis: f64| -> f64 {
+            elements
+                .iter()
+                .map(|element| {
+                    element
+                        .load_shortening
+                        .stress(curvature * (element.z - neutral_axis))
+                        * element.area
+                })
+                .sum()
+        };
+        // При перегибе с подъемом нейтральной оси сила убывает, при прогибе - возрастает.
+        let (mut low, mut high) = (z_min, z_max);
+        for _ in 0..NEUTRAL_AXIS_ITERATIONS {
+            let middle = (low + high) / 2.0;
+            if force(middle) * curvature.signum() > 0.0 {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        let neutral_axis = (low + high) / 2.0;
+        // Изгибающий момент [МН*м].
+        let moment: f64 = elements
+            .iter()
+            .map(|element| {
+                element
+                    .load_shortening
+                    .stress(curvature * (element.z - neutral_axis))
+                    * element.area
+                    * (element.z - neutral_axis)
+            })
+            .sum();
+        CurvaturePoint {
+            curvature,
+            neutral_axis,
+            moment: TonMeters::from(KiloNewtonMeters(moment * 1000.0)).value(),
+        }
+    }
+
+    ///
+    /// Предельный момент на перегиб [т*м] - наибольший момент на кривой "момент - кривизна".
+    pub fn hogging_capacity(&self) -> f64 {
+        self.curve()
+            .iter()
+            .fold(0.0_f64, |max, point| max.max(point.moment))
+    }
+
+    ///
+    /// Предельный момент на прогиб, по модулю [т*м].
+    pub fn sagging_capacity(&self) -> f64 {
+        self.curve()
+            .iter()
+            .fold(0.0_f64, |max, point| max.max(-point.moment))
+    }
+
+    ///
+    /// Кривая "момент - кривизна": сначала прогиб, затем перегиб, по возрастанию модуля кривизны.
+    pub fn curve(&self) -> &Vec<CurvaturePoint> {
+        &self.curve
+    }
+}
@@ -1,24 +1,736 @@
 mod core;
+mod cross_section_properties;
+mod server;
 mod strength;
 mod tests;
-use core::visualisation::{DiagrammType, Visualisation};
+use core::{
+    system_of_units::{MegaPascals, Meters, TonMeters},
+    unit_system::UnitSystem,
+    visualisation::{DiagrammType, Visualisation},
+};
+use cross_section_properties::cross_sections::CrossSections;
+use server::strength_server::Server;
 use std::env;
-use strength::strength::Strength;
+use strength::{
+    ballast::ballast_optimizer::BallastOptimizer,
+    buoyancy_intensity::quick_draft::QuickDraft,
+    hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
+    lightweight::inclining_experiment::IncliningExperiment,
+    load::{load_category::LoadCategory, shiploads::Shiploads},
+    loading_sequence::{sequence::LoadingSequence, sequence_report::SequenceReport},
+    project::loading_condition::LoadingCondition,
+    report::{loading_condition_table::LoadingConditionTable, report_format::ReportFormat},
+    ship::ship_model::Ship,
+    stability::heel::Heel,
+    stowage::{stowage_candidate::StowageCandidate, stowage_planner::StowagePlanner},
+    strength::Strength,
+    validation::{
+        bonjean_validation::BonjeanValidation, hydrostatic_validation::HydrostaticValidation,
+        loading_validation::LoadingValidation,
+    },
+    voyage::voyage_plan::Voyage,
+};
+
+const INPUT_PATH: &str = "./input_data/input_data.json";
+const SHIPLOADS_FILE: &str = "input_data/full_ship.json";
+const FRAMES_FILE: &str = "./input_data/frames.json";
+const HYDROSTATIC_CURVES_FILE: &str = "./input_data/hydrostatic_curves.json";
+const EMPTY_SHIPLOADS_FILE: &str = "input_data/empty_ship.json";
+const LOADING_SEQUENCE_FILE: &str = "input_data/loading_sequence.json";
+const BALLAST_FILE: &str = "input_data/ballast.json";
+const STOWAGE_FILE: &str = "input_data/stowage.json";
+const VOYAGE_FILE: &str = "input_data/voyage.json";
+const DEPARTURE_CONDITION_FILE: &str = "input_data/departure_condition.json";
+const PROJECT_CONDITION_FILE: &str = "input_data/project_condition.json";
+const INCLINING_EXPERIMENT_FILE: &str = "input_data/inclining_experiment.json";
+const CROSS_SECTIONS_FILE: &str = "input_data/cross_sections.json";
+const SERVER_ADDRESS: &str = "127.0.0.1:8080";
 
 fn main() {
     env::set_var("RUST_LOG", "debug");
     env::set_var("RUST_BACKTRACE", "full");
 
     env_logger::init();
-    tracing_subscriber::fmt().compact().try_init();
-    let input_path = "./input_data/input_data.json".to_string();
-    let shiploads_file = "input_data/full_ship.json".to_string();
-    let frames_file = "./input_data/frames.json".to_string();
-    let hydrostatic_curves = "./input_data/hydrostatic_curves.json".to_string();
-    let strength =
-        Strength::new_project(input_path, shiploads_file, frames_file, hydrostatic_curves).unwrap();
-    strength.bending_moment();
-    let vis = Visualisation::new(11.75, &strength);
+    let _ = tracing_subscriber::fmt().compact().try_init();
+    let args: Vec<String> = env::args().collect();
+    match args.get(1).map(|arg| arg.as_str()) {
+        Some("serve") => serve(args.get(2).map_or(SERVER_ADDRESS, |arg| arg.as_str())),
+        Some("sequence") => loading_sequence(
+            args.get(2).map_or(EMPTY_SHIPLOADS_FILE, |arg| arg.as_str()),
+            args.get(3)
+                .map_or(LOADING_SEQUENCE_FILE, |arg| arg.as_str()),
+        ),
+        Some("ballast") => ballast(
+            args.get(2).map_or(EMPTY_SHIPLOADS_FILE, |arg| arg.as_str()),
+            args.get(3).map_or(BALLAST_FILE, |arg| arg.as_str()),
+        ),
+        Some("stowage") => stowage(
+            args.get(2).map_or(EMPTY_SHIPLOADS_FILE, |arg| arg.as_str()),
+            args.get(3).map_or(STOWAGE_FILE, |arg| arg.as_str()),
+        ),
+        Some("voyage") => voyage(
+            args.get(2).map_or(EMPTY_SHIPLOADS_FILE, |arg| arg.as_str()),
+            args.get(3).map_or(VOYAGE_FILE, |arg| arg.as_str()),
+        ),
+        Some("inclining") => inclining(
+            args.get(2)
+                .map_or(INCLINING_EXPERIMENT_FILE, |arg| arg.as_str()),
+        ),
+        Some("validate") => validate(args.get(2).map_or(SHIPLOADS_FILE, |arg| arg.as_str())),
+        Some("categories") => categories(
+            args.get(2)
+                .map_or(DEPARTURE_CONDITION_FILE, |arg| arg.as_str()),
+            args.get(3).map(|arg| arg.as_str()),
+        ),
+        Some("table") => table(
+            args.get(2)
+                .map_or(DEPARTURE_CONDITION_FILE, |arg| arg.as_str()),
+            args.get(3).map_or("markdown", |arg| arg.as_str()),
+            args.get(4).map(|arg| arg.as_str()),
+        ),
+        Some("csv") => diagramms_csv(
+            args.get(2).map_or(SHIPLOADS_FILE, |arg| arg.as_str()),
+            args.get(3).map(|arg| arg.as_str()),
+        ),
+        Some("project") => project(
+            args.get(2)
+                .map_or(PROJECT_CONDITION_FILE, |arg| arg.as_str()),
+            args.get(3).map_or("markdown", |arg| arg.as_str()),
+        ),
+        Some("import") => import(
+            args.get(2).map_or(INPUT_PATH, |arg| arg.as_str()),
+            args.get(3).map_or(FRAMES_FILE, |arg| arg.as_str()),
+            args.get(4)
+                .map_or(HYDROSTATIC_CURVES_FILE, |arg| arg.as_str()),
+            args.get(5)
+                .map_or(DEPARTURE_CONDITION_FILE, |arg| arg.as_str()),
+        ),
+        Some("heel") => heel(
+            args.get(2)
+                .map_or(DEPARTURE_CONDITION_FILE, |arg| arg.as_str()),
+            args.get(3).map(|arg| arg.as_str()),
+        ),
+        Some("deflection") => deflection(
+            args.get(2).map_or(SHIPLOADS_FILE, |arg| arg.as_str()),
+            args.get(3).map_or(CROSS_SECTIONS_FILE, |arg| arg.as_str()),
+        ),
+        Some("shear") => shear(
+            args.get(2).map_or(SHIPLOADS_FILE, |arg| arg.as_str()),
+            args.get(3).map_or(CROSS_SECTIONS_FILE, |arg| arg.as_str()),
+        ),
+        Some("buckling") => buckling(
+            args.get(2).map_or(SHIPLOADS_FILE, |arg| arg.as_str()),
+            args.get(3).map_or(CROSS_SECTIONS_FILE, |arg| arg.as_str()),
+        ),
+        Some("ultimate") => ultimate(
+            args.get(2).map_or(SHIPLOADS_FILE, |arg| arg.as_str()),
+            args.get(3).map_or(CROSS_SECTIONS_FILE, |arg| arg.as_str()),
+        ),
+        Some("draft") => draft(
+            args.get(2)
+                .map_or(DEPARTURE_CONDITION_FILE, |arg| arg.as_str()),
+            args.get(3).is_some_and(|arg| arg == "quick"),
+        ),
+        Some("hydrostatics") => hydrostatics(
+            args.get(2)
+                .map_or(HYDROSTATIC_CURVES_FILE, |arg| arg.as_str()),
+            args.get(3)
+                .map_or(Ok(5.0), |arg| arg.parse::<f64>())
+                .unwrap(),
+        ),
+        _ => show_diagramms(),
+    }
+}
+
+///
+/// Запускает HTTP сервер расчета прочности.
+/// Контролируемой схемой загрузки при запуске является SHIPLOADS_FILE.
+fn serve(address: &str) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let shiploads = Shiploads::from_file(SHIPLOADS_FILE.to_string()).unwrap();
+    Server::new(ship, &shiploads)
+        .with_unit_system(unit_system(None))
+        .run(address)
+        .unwrap();
+}
+
+///
+/// Расчет последовательности грузовых операций.
+/// Parameters:
+///     initial_file - исходная схема загрузки,
+///     sequence_file - последовательность грузовых операций.
+fn loading_sequence(initial_file: &str, sequence_file: &str) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let initial = Shiploads::from_file(initial_file.to_string()).unwrap();
+    let sequence = LoadingSequence::from_file(sequence_file.to_string()).unwrap();
+    let report = sequence
+        .run(&ship, &initial)
+        .unwrap()
+        .with_unit_system(unit_system(None));
+    println!("{report}");
+}
+
+///
+/// Подбор заполнения балластных цистерн.
+/// Parameters:
+///     cargo_file - схема загрузки судна без балласта,
+///     ballast_file - балластные цистерны и требования к посадке судна.
+fn ballast(cargo_file: &str, ballast_file: &str) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let cargo = Shiploads::from_file(cargo_file.to_string()).unwrap();
+    let optimizer = BallastOptimizer::from_file(ballast_file.to_string()).unwrap();
+    let solution = optimizer
+        .optimize(&ship, &cargo)
+        .unwrap()
+        .with_unit_system(unit_system(None));
+    println!("{}", serde_json::to_string_pretty(&solution).unwrap());
+}
+
+///
+/// Планирование размещения навалочного груза по трюмам.
+/// Parameters:
+///     base_file - схема загрузки судна без навалочного груза,
+///     stowage_file - трюмы, масса и плотность груза.
+fn stowage(base_file: &str, stowage_file: &str) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let base = Shiploads::from_file(base_file.to_string()).unwrap();
+    let planner = StowagePlanner::from_file(stowage_file.to_string()).unwrap();
+    let units = unit_system(None);
+    let candidates: Vec<StowageCandidate> = planner
+        .plan(&ship, &base)
+        .into_iter()
+        .map(|candidate| candidate.with_unit_system(units))
+        .collect();
+    println!("{}", serde_json::to_string_pretty(&candidates).unwrap());
+}
+
+///
+/// Расчет прочности для состояний загрузки при отходе, в рейсе и при приходе.
+/// Parameters:
+///     base_file - схема загрузки судна без судовых запасов,
+///     voyage_file - цистерны судовых запасов, их расход и продолжительность рейса.
+fn voyage(base_file: &str, voyage_file: &str) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let base = Shiploads::from_file(base_file.to_string()).unwrap();
+    let voyage = Voyage::from_file(voyage_file.to_string()).unwrap();
+    println!(
+        "{}",
+        voyage.run(&ship, &base).with_unit_system(unit_system(None))
+    );
+}
+
+///
+/// Определение массы и аппликаты центра тяжести судна порожнем по результатам опыта кренования.
+/// Parameters:
+///     experiment_file - данные опыта кренования.
+fn inclining(experiment_file: &str) {
+    let experiment = IncliningExperiment::from_file(experiment_file.to_string()).unwrap();
+    let lightweight = experiment.lightweight();
+    println!("GM = {:.3} м", experiment.metacentric_height());
+    println!("KG = {:.3} м", experiment.vcg());
+    println!("Масса судна порожнем = {:.2}", lightweight.lightweight());
+    println!(
+        "Аппликата ЦТ судна порожнем = {:.3} м",
+        lightweight.vcg().unwrap_or_default()
+    );
+}
+
+///
+/// Проверка масштаба Бонжана, гидростатических кривых и схемы загрузки судна.
+/// Parameters:
+///     shiploads_file - схема загрузки судна.
+fn validate(shiploads_file: &str) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+    let bonjean_validation = BonjeanValidation::new(ship.frames(), ship.ship_dimensions());
+    let hydrostatic_validation = HydrostaticValidation::new(ship.hydrostatic_curves());
+    let validation = LoadingValidation::new(&ship, &shiploads);
+    let findings: Vec<_> = bonjean_validation
+        .findings()
+        .as_ref()
+        .iter()
+        .chain(hydrostatic_validation.findings().as_ref())
+        .chain(validation.findings().as_ref())
+        .collect();
+    for finding in findings.iter() {
+        println!("{finding}");
+    }
+    if findings.is_empty() {
+        println!("Замечаний нет.");
+    }
+}
+
+///
+/// Сводка нагрузок по категориям и расчет "что если" для нагрузок заданных категорий.
+/// Parameters:
+///     shiploads_file - схема загрузки судна,
+///     categories - категории нагрузок через запятую (например, "cargo,ballast").
+fn categories(shiploads_file: &str, categories: Option<&str>) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+    println!(
+        "{:<22} | {:>10} | {:>8} | {:>8}",
+        "Категория", "Масса, т", "LCG, м", "VCG, м"
+    );
+    for summary in shiploads.category_summary() {
+        println!("{summary}");
+    }
+    let mut conditions = vec![("Все нагрузки".to_string(), shiploads.clone())];
+    if let Some(categories) = categories {
+        let categories = categories
+            .split(',')
+            .map(|category| category.trim().parse::<LoadCategory>())
+            .collect::<Result<Vec<LoadCategory>, String>>()
+            .unwrap();
+        conditions.push((
+            categories
+                .iter()
+                .map(|category| category.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            shiploads.with_categories(&categories),
+        ));
+    }
+    let report = SequenceReport::new(
+        &ship,
+        conditions
+            .iter()
+            .map(|(name, shiploads)| (name.as_str(), shiploads)),
+    );
+    println!("{report}");
+}
+
+///
+/// Таблица нагрузок судна (статьи дедвейта, итоги, посадка и прочность).
+/// Parameters:
+///     shiploads_file - схема загрузки судна,
+///     format - формат отчета: markdown, html или csv,
+///     units - система единиц: t, kn или mn, если не задана - из файла с основными данными о судне.
+fn table(shiploads_file: &str, format: &str, units: Option<&str>) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+    let format = format.parse::<ReportFormat>().unwrap();
+    let table = LoadingConditionTable::new(&ship, &shiploads)
+        .unwrap()
+        .with_unit_system(unit_system(units));
+    println!("{}", table.render(format));
+}
+
+///
+/// Таблица нагрузок для схемы загрузки, ссылающейся на проект судна.
+/// Parameters:
+///     condition_file - схема загрузки судна со ссылкой на файл проекта,
+///     format - формат отчета: markdown, html или csv.
+fn project(condition_file: &str, format: &str) {
+    let condition = LoadingCondition::from_file(condition_file.to_string()).unwrap();
+    let project = condition.project();
+    let format = format.parse::<ReportFormat>().unwrap();
+    let table = LoadingConditionTable::new(project.ship(), condition.shiploads())
+        .unwrap()
+        .with_unit_system(project.unit_system());
+    println!("{}", project.ship_name());
+    println!("{}", table.render(format));
+}
+
+///
+/// Таблица нагрузок по данным судна, импортированным из файлов json, toml, yaml или csv.
+/// Parameters:
+///     input_path - основные данные о судне (json, toml или yaml),
+///     frames_file - масштаб Бонжана (json или csv),
+///     hydrostatic_curves - гидростатические кривые (json или csv),
+///     shiploads_file - схема загрузки судна.
+fn import(input_path: &str, frames_file: &str, hydrostatic_curves: &str, shiploads_file: &str) {
+    let ship = Ship::from_files(
+        input_path.to_string(),
+        frames_file.to_string(),
+        hydrostatic_curves.to_string(),
+    )
+    .unwrap();
+    let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+    let table = LoadingConditionTable::new(&ship, &shiploads).unwrap();
+    println!("{}", table.render(ReportFormat::Markdown));
+}
+
+///
+/// Эпюры в формате CSV.
+/// Parameters:
+///     shiploads_file - схема загрузки судна,
+///     units - система единиц: t, kn или mn, если не задана - из файла с основными данными о судне.
+fn diagramms_csv(shiploads_file: &str, units: Option<&str>) {
+    let strength = Strength::new_project(
+        INPUT_PATH.to_string(),
+        shiploads_file.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let vis = Visualisation::new(11.75, &strength).with_unit_system(unit_system(units));
+    for diagramm_type in DiagrammType::all() {
+        if let Some(csv) = vis.csv(diagramm_type) {
+            println!("{csv}");
+        }
+    }
+}
+
+///
+/// Система единиц, заданная в командной строке или в файле с основными данными о судне.
+fn unit_system(units: Option<&str>) -> UnitSystem {
+    match units {
+        Some(units) => units.parse().unwrap(),
+        None => UnitSystem::from_file(INPUT_PATH.to_string()).unwrap(),
+    }
+}
+
+///
+/// Прогиб корпуса от изгибающего момента и осадки по маркам углубления с учетом прогиба.
+/// Parameters:
+///     shiploads_file - схема загрузки судна,
+///     cross_sections_file - поперечные сечения корпуса.
+fn deflection(shiploads_file: &str, cross_sections_file: &str) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let cross_sections = CrossSections::from_file(cross_sections_file.to_string()).unwrap();
+    let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+    let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+    let deflection = strength.deflection(&cross_sections).unwrap();
+    let (Meters(aft_draft), Meters(nose_draft)) = strength.draft().unwrap();
+    println!(
+        "Момент инерции сечения на миделе = {:.3} м^4",
+        cross_sections.moment_of_inertia(0.0).unwrap()
+    );
+    println!(
+        "Прогиб на миделе (+ перегиб) = {:.1} мм",
+        deflection.midship_deflection().unwrap()
+    );
+    println!("Наибольший перегиб = {:.1} мм", deflection.max_hogging());
+    println!("Наибольший прогиб = {:.1} мм", deflection.max_sagging());
+    let marks = deflection.draft_marks(aft_draft, nose_draft).unwrap();
+    println!(
+        "Осадки по маркам: корма = {:.3} м, мидель = {:.3} м, нос = {:.3} м",
+        marks.aft, marks.mid, marks.nose
+    );
+    println!(
+        "Средняя осадка с учетом прогиба = {:.3} м",
+        deflection
+            .quarter_mean_draft(aft_draft, nose_draft)
+            .unwrap()
+    );
+}
+
+///
+/// Касательные напряжения в обшивке борта и продольных переборках и их проверка во всех расчетных сечениях.
+/// Parameters:
+///     shiploads_file - схема загрузки судна,
+///     cross_sections_file - поперечные сечения корпуса.
+fn shear(shiploads_file: &str, cross_sections_file: &str) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let cross_sections = CrossSections::from_file(cross_sections_file.to_string()).unwrap();
+    let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+    let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+    let check = strength
+        .shear_stress_check(&cross_sections, &ship.permissible_limits().unwrap())
+        .unwrap();
+    let unit_system = unit_system(None);
+    let stress = |value: f64| unit_system.stress(MegaPascals(value));
+    let unit = unit_system.stress_unit();
+    println!("| x, м | tau, {unit} | tau / [tau] |");
+    println!("|---|---|---|");
+    for station in check.stations() {
+        println!(
+            "| {:.2} | {:.2} | {:.3} |",
+            station.abscissa,
+            stress(station.shear_stress),
+            station.utilisation
+        );
+    }
+    println!(
+        "Наибольшее касательное напряжение = {:.2} {unit}, допускаемое = {:.2} {unit}",
+        stress(check.max_shear_stress()),
+        stress(check.permissible_shear_stress())
+    );
+    for station in check.failed_stations() {
+        println!(
+            "Превышение допускаемого касательного напряжения в сечении x = {:.2} м: {:.2} {unit}",
+            station.abscissa,
+            stress(station.shear_stress)
+        );
+    }
+    println!(
+        "Прочность по касательным напряжениям {}",
+        if check.passed() {
+            "обеспечена"
+        } else {
+            "не обеспечена"
+        }
+    );
+}
+
+///
+/// Проверка устойчивости и текучести панелей палубы и днища от общего изгиба по длине судна.
+/// Parameters:
+///     shiploads_file - схема загрузки судна,
+///     cross_sections_file - поперечные сечения корпуса с заданными панелями.
+fn buckling(shiploads_file: &str, cross_sections_file: &str) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let cross_sections = CrossSections::from_file(cross_sections_file.to_string()).unwrap();
+    let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+    let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+    let check = strength.buckling_check(&cross_sections).unwrap();
+    let unit_system = unit_system(None);
+    println!(
+        "| x, м | M, {} | устойчивость | текучесть |",
+        unit_system.moment_unit()
+    );
+    println!("|---|---|---|---|");
+    for station in check.stations() {
+        println!(
+            "| {:.2} | {:.2} | {:.3} | {:.3} |",
+            station.abscissa,
+            unit_system.moment(TonMeters(station.bending_moment)),
+            station.buckling_utilisation(),
+            station.yield_utilisation()
+        );
+    }
+    println!(
+        "Наибольший коэффициент использования: по устойчивости = {:.3}, по текучести = {:.3}",
+        check.max_buckling_utilisation(),
+        check.max_yield_utilisation()
+    );
+    println!(
+        "Устойчивость и прочность панелей палубы и днища {}",
+        if check.passed() {
+            "обеспечены"
+        } else {
+            "не обеспечены"
+        }
+    );
+}
+
+///
+/// Предельная прочность корпуса методом Смита и запасы относительно суммарного
+/// (на тихой воде и волнового) изгибающего момента.
+/// Parameters:
+///     shiploads_file - схема загрузки судна,
+///     cross_sections_file - поперечные сечения корпуса.
+fn ultimate(shiploads_file: &str, cross_sections_file: &str) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let cross_sections = CrossSections::from_file(cross_sections_file.to_string()).unwrap();
+    let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+    let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+    let check = strength.ultimate_strength_check(&cross_sections).unwrap();
+    let safety_factor =
+        |value: Option<f64>| value.map_or("-".to_string(), |value| format!("{value:.3}"));
+    let unit_system = unit_system(None);
+    let moment = unit_system.moment_unit();
+    println!("| x, м | Mu перегиб, {moment} | Mu прогиб, {moment} | Msw, {moment} | M перегиб, {moment} | M прогиб, {moment} | запас перегиб | запас прогиб |");
+    println!("|---|---|---|---|---|---|---|---|");
+    for section in check.sections() {
+        println!(
+            "| {:.2} | {:.0} | {:.0} | {:.2} | {:.2} | {:.2} | {} | {} |",
+            section.abscissa,
+            unit_system.moment(TonMeters(section.hogging_capacity)),
+            unit_system.moment(TonMeters(section.sagging_capacity)),
+            unit_system.moment(TonMeters(section.still_water_moment)),
+            unit_system.moment(TonMeters(section.total_hogging_moment)),
+            unit_system.moment(TonMeters(section.total_sagging_moment)),
+            safety_factor(section.hogging_safety_factor),
+            safety_factor(section.sagging_safety_factor)
+        );
+    }
+    println!(
+        "Наименьший запас предельной прочности = {}",
+        safety_factor(check.min_safety_factor())
+    );
+    println!(
+        "Предельная прочность корпуса {}",
+        if check.passed() {
+            "обеспечена"
+        } else {
+            "не обеспечена"
+        }
+    );
+}
+
+///
+/// Быстрая оценка посадки по гидростатическим кривым и ее сравнение с удифферентовкой по масштабу Бонжана.
+/// Parameters:
+///     shiploads_file - схема загрузки судна,
+///     quick_only - только быстрая оценка, без удифферентовки.
+fn draft(shiploads_file: &str, quick_only: bool) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+    let quick = QuickDraft::from_ship(&ship, &shiploads).unwrap();
+    let (quick_aft, quick_nose) = quick.drafts();
+    println!("LCB = {:.3} м", quick.lcb());
+    println!("MCT1cm = {:.1} т*м/см", quick.mct1cm());
+    println!("Средняя осадка = {:.2} м", quick.mean_draft());
+    println!("Дифферент (+ на нос) = {:.2} м", quick.trim());
+    if quick_only {
+        println!(
+            "Осадка кормой = {:.2} м, носом = {:.2} м",
+            quick_aft, quick_nose
+        );
+        return;
+    }
+    let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+    println!(
+        "Объемное водоизмещение после удифферентовки = {:.1}",
+        strength.displacement().unwrap()
+    );
+    let (Meters(aft), Meters(nose)) = strength.draft().unwrap();
+    println!(
+        "{:<16} | {:>8} | {:>8} | {:>10}",
+        "Осадка", "Корма", "Нос", "Дифферент"
+    );
+    for (name, aft, nose) in [
+        ("Быстрая оценка", quick_aft, quick_nose),
+        ("Удифферентовка", aft, nose),
+        ("Разность", quick_aft - aft, quick_nose - nose),
+    ] {
+        println!(
+            "{:<16} | {:>8.2} | {:>8.2} | {:>10.2}",
+            name,
+            aft,
+            nose,
+            nose - aft
+        );
+    }
+}
+
+///
+/// Крен судна на тихой воде и осадки по бортам.
+/// Parameters:
+///     shiploads_file - схема загрузки судна,
+///     arm - ордината центра тяжести груза для спрямления судна [м],
+///     если не задана - масса груза для спрямления не рассчитывается.
+fn heel(shiploads_file: &str, arm: Option<&str>) {
+    let ship = Ship::from_files(
+        INPUT_PATH.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+    let heel = Heel::new(&ship, &shiploads).unwrap();
+    println!("KG = {:.3} м", heel.kg());
+    println!("TCG = {:.3} м", heel.tcg());
+    println!("GM = {:.3} м", heel.metacentric_height());
+    println!("Кренящий момент = {:.2} т*м", heel.heeling_moment());
+    println!(
+        "Угол крена (+ на правый борт) = {:.2} град",
+        heel.angle().unwrap()
+    );
+    let port = heel.port_drafts().unwrap();
+    let starboard = heel.starboard_drafts().unwrap();
+    if let (Some(port), Some(starboard)) = (port, starboard) {
+        println!(
+            "{:<10} | {:>8} | {:>8} | {:>8}",
+            "Борт", "Корма", "Мидель", "Нос"
+        );
+        for (side, drafts) in [("Левый", port), ("Правый", starboard)] {
+            println!(
+                "{:<10} | {:>8.2} | {:>8.2} | {:>8.2}",
+                side, drafts.aft, drafts.mid, drafts.nose
+            );
+        }
+    }
+    let Some(arm) = arm else {
+        return;
+    };
+    let correction_mass = arm
+        .parse::<f64>()
+        .map_err(|err| format!("Некорректная ордината груза для спрямления {arm}: {err}"))
+        .and_then(|arm| heel.correction_mass(arm));
+    match correction_mass {
+        Ok(mass) => println!("Масса груза для спрямления на ординате {arm} м = {mass:.2} т"),
+        Err(err) => println!("Масса груза для спрямления не определена: {err}"),
+    }
+}
+
+fn show_diagramms() {
+    let strength = Strength::new_project(
+        INPUT_PATH.to_string(),
+        SHIPLOADS_FILE.to_string(),
+        FRAMES_FILE.to_string(),
+        HYDROSTATIC_CURVES_FILE.to_string(),
+    )
+    .unwrap();
+    let unit_system = unit_system(None);
+    println!(
+        "Наибольшая перерезывающая сила = {:.2} {}",
+        unit_system.force(strength.max_share_force().unwrap_or_default()),
+        unit_system.force_unit()
+    );
+    println!(
+        "Наибольший изгибающий момент = {:.2} {}",
+        unit_system.moment(strength.max_bending_moment().unwrap_or_default()),
+        unit_system.moment_unit()
+    );
+    let vis = Visualisation::new(11.75, &strength).with_unit_system(unit_system);
     vis.show(DiagrammType::LightweightIntensity);
     vis.show(DiagrammType::DeadweightIntensity);
     vis.show(DiagrammType::DisplacementIntensity);
@@ -29,3 +741,77 @@ fn main() {
     vis.show(DiagrammType::BendingMoment);
     vis.show(DiagrammType::BendingMomentWithCorrection);
 }
+
+///
+/// Выводит элементы теоретического чертежа при заданной осадке.
+/// Parameters:
+///     hydrostatic_curves_file - гидростатические кривые судна,
+///     draft - осадка судна [м].
+fn hydrostatics(hydrostatic_curves_file: &str, draft: f64) {
+    let curves = HydrostaticCurves::from_file(hydrostatic_curves_file.to_string()).unwrap();
+    let show = |name: &str, value: Option<String>| {
+        println!("{name} = {}", value.unwrap_or("не задано".to_string()));
+    };
+    println!("Осадка = {draft} м");
+    show(
+        "Весовое водоизмещение",
+        curves
+            .displacement_tonnage(draft)
+            .unwrap()
+            .map(|value| format!("{value:.1}")),
+    );
+    show(
+        "Абсцисса центра величины (LCB)",
+        curves
+            .lcb(draft)
+            .unwrap()
+            .map(|value| format!("{value:.3}")),
+    );
+    show(
+        "Абсцисса центра тяжести ватерлинии (LCF)",
+        curves
+            .lcf(draft)
+            .unwrap()
+            .map(|value| format!("{value:.3}")),
+    );
+    show(
+        "Площадь ватерлинии",
+        curves
+            .waterline_area(draft)
+            .unwrap()
+            .map(|value| format!("{value:.1}")),
+    );
+    show(
+        "Продольный метацентрический радиус (LMR)",
+        curves
+            .lmr(draft)
+            .unwrap()
+            .map(|value| format!("{value:.1}")),
+    );
+    show(
+        "Аппликата центра величины (KB)",
+        curves.kb(draft).unwrap().map(|value| format!("{value:.3}")),
+    );
+    show(
+        "Аппликата поперечного метацентра (KM)",
+        curves.km(draft).unwrap().map(|value| format!("{value:.3}")),
+    );
+    show(
+        "Момент, дифферентующий на 1 см (MCT1cm)",
+        curves
+            .mct1cm(draft)
+            .unwrap()
+            .map(|value| format!("{value:.1}")),
+    );
+    show(
+        "Число тонн на 1 см осадки (TPC)",
+        curves
+            .tpc(draft)
+            .unwrap()
+            .map(|value| format!("{value:.2}")),
+    );
+    show(
+        "Коэффициент общей полноты (CB)",
+        curves.cb(draft).unwrap().map(|value| format!("{value:.3}")),
+    );
+}
@@ -0,0 +1,183 @@
+use std::io::{BufRead, ErrorKind, Write};
+
+use serde::Serialize;
+use tracing::instrument;
+
+///
+/// Максимальный размер тела запроса [байт].
+const MAX_BODY_LENGTH: usize = 16 * 1024 * 1024;
+
+///
+/// HTTP запрос.
+/// Parameters:
+///     method - метод запроса (GET, POST и т.д.),
+///     path - путь запроса без строки параметров,
+///     body - тело запроса.
+#[derive(Debug, PartialEq)]
+pub struct HttpRequest {
+    method: String,
+    path: String,
+    body: String,
+}
+
+impl HttpRequest {
+    ///
+    /// Основной конструктор.
+    pub fn new(method: String, path: String, body: String) -> Self {
+        HttpRequest { method, path, body }
+    }
+
+    ///
+    /// Читает HTTP запрос из потока.
+    /// Возвращает ответ с ошибкой 408, если истекло время ожидания данных,
+    /// и 400, если запрос некорректен или тело запроса короче Content-Length.
+    #[instrument(skip_all, target = "HttpRequest::from_reader")]
+    pub fn from_reader<R: BufRead>(reader: &mut R) -> Result<Self, HttpResponse> {
+        let bad_request = |message: String| HttpResponse::error(400, message);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).map_err(read_error)?;
+        let mut parts = request_line.split_whitespace();
+        let (method, target) = match (parts.next(), parts.next()) {
+            (Some(method), Some(target)) => (method.to_uppercase(), target),
+            _ => {
+                return Err(bad_request(format!(
+                    "Некорректная строка запроса: {request_line:?}"
+                )))
+            }
+        };
+        let path = target.split('?').next().unwrap_or(target).to_string();
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            let read = reader.read_line(&mut header).map_err(read_error)?;
+            let header = header.trim_end();
+            if read == 0 || header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse::<usize>().map_err(|err| {
+                        bad_request(format!("Некорректный заголовок Content-Length: {err}"))
+                    })?;
+                }
+            }
+        }
+        if content_length > MAX_BODY_LENGTH {
+            return Err(bad_request(format!(
+                "Размер тела запроса {content_length} байт превышает допустимый {MAX_BODY_LENGTH} байт."
+            )));
+        }
+        let mut body = vec![0; content_length];
+        reader
+            .read_exact(&mut body)
+            .map_err(|err| match err.kind() {
+                ErrorKind::UnexpectedEof => bad_request(format!(
+                    "Тело запроса короче заголовка Content-Length: {content_length} байт."
+                )),
+                _ => read_error(err),
+            })?;
+        let body = String::from_utf8(body).map_err(|err| bad_request(err.to_string()))?;
+        Ok(HttpRequest::new(method, path, body))
+    }
+
+    ///
+    /// Метод запроса.
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    ///
+    /// Путь запроса.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    ///
+    /// Тело запроса.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+///
+/// HTTP ответ с телом в формате json.
+#[derive(Debug, PartialEq)]
+pub struct HttpResponse {
+    status: u16,
+    body: String,
+}
+
+impl HttpResponse {
+    ///
+    /// Основной конструктор.
+    pub fn new(status: u16, body: String) -> Self {
+        HttpResponse { status, body }
+    }
+
+    ///
+    /// Ответ 200 OK, содержащий сериализованный в json объект.
+    pub fn ok<T: Serialize>(value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(body) => HttpResponse::new(200, body),
+            Err(err) => HttpResponse::error(500, err.to_string()),
+        }
+    }
+
+    ///
+    /// Ответ с ошибкой. Тело ответа: {"error": "<сообщение>"}.
+    pub fn error(status: u16, message: String) -> Self {
+        let body = serde_json::json!({ "error": message }).to_string();
+        HttpResponse::new(status, body)
+    }
+
+    ///
+    /// Код состояния.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    ///
+    /// Тело ответа.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    ///
+    /// Записывает ответ в поток.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason_phrase(self.status),
+            self.body.len(),
+            self.body
+        )?;
+        writer.flush()
+    }
+}
+
+///
+/// Ответ с ошибкой чтения запроса из потока: 408 при истечении времени ожидания, иначе 400.
+fn read_error(err: std::io::Error) -> HttpResponse {
+    match err.kind() {
+        ErrorKind::WouldBlock | ErrorKind::TimedOut => {
+            HttpResponse::error(408, "Истекло время ожидания запроса.".to_string())
+        }
+        _ => HttpResponse::error(400, err.to_string()),
+    }
+}
+
+///
+/// Текстовое описание кода состояния.
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error",
+    }
+}
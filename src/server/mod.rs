@@ -0,0 +1,3 @@
+pub mod http;
+pub mod strength_result;
+pub mod strength_server;
@@ -0,0 +1,217 @@
+use serde::Serialize;
+use tracing::instrument;
+
+use crate::{
+    core::{
+        round::Round,
+        system_of_units::{Meters, TonMeters, Tons, TonsForce, TonsPerMeter},
+        unit_system::UnitSystem,
+    },
+    strength::{
+        buoyancy_intensity::quick_draft::DraftComparison,
+        ship::spatium_functions::SpatiumFunctions,
+        strength::Strength,
+        strength_check::{permissible_limits::PermissibleLimits, still_water_check::StrengthCheck},
+    },
+};
+
+///
+/// Отношение внутреннего силового фактора к допускаемому, начиная с которого выдается предупреждение.
+const WARNING_UTILISATION: f64 = 0.9;
+
+///
+/// Эпюры внешних и внутренних силовых факторов.
+#[derive(Debug, Serialize)]
+pub struct Diagrams {
+    lightweight_intensity: SpatiumFunctions,
+    deadweight_intensity: SpatiumFunctions,
+    displacement_intensity: SpatiumFunctions,
+    buoyancy_intensity: SpatiumFunctions,
+    total_shipload: SpatiumFunctions,
+    share_force: SpatiumFunctions,
+    share_force_with_correction: Option<SpatiumFunctions>,
+    bending_moment: SpatiumFunctions,
+    bending_moment_with_correction: Option<SpatiumFunctions>,
+}
+
+impl Diagrams {
+    ///
+    /// Пересчитывает эпюры с коэффициентами пересчета интенсивности, силы и момента.
+    fn converted(&self, intensity: f64, force: f64, moment: f64) -> Self {
+        let convert =
+            |diagram: &SpatiumFunctions, factor: f64| diagram.converted(|value| value * factor);
+        Diagrams {
+            lightweight_intensity: convert(&self.lightweight_intensity, intensity),
+            deadweight_intensity: convert(&self.deadweight_intensity, intensity),
+            displacement_intensity: convert(&self.displacement_intensity, intensity),
+            buoyancy_intensity: convert(&self.buoyancy_intensity, intensity),
+            total_shipload: convert(&self.total_shipload, intensity),
+            share_force: convert(&self.share_force, force),
+            share_force_with_correction: self
+                .share_force_with_correction
+                .as_ref()
+                .map(|diagram| convert(diagram, force)),
+            bending_moment: convert(&self.bending_moment, moment),
+            bending_moment_with_correction: self
+                .bending_moment_with_correction
+                .as_ref()
+                .map(|diagram| convert(diagram, moment)),
+        }
+    }
+}
+
+///
+/// Результаты расчета прочности судна для одной схемы загрузки.
+/// Parameters:
+///     aft_draft, nose_draft, mean_draft - осадки кормой, носом и средняя [м],
+///     trim - дифферент, положительный на нос [м],
+///     lightweight, deadweight, displacement_tonnage - массы [т],
+///     lcg, lcb - абсциссы центра тяжести и центра величины [м],
+///     draft_comparison - сравнение быстрой оценки посадки с результатом удифферентовки,
+///     diagrams - эпюры,
+///     strength_check - результаты проверки прочности, в том числе система единиц эпюр, сил и моментов.
+#[derive(Debug, Serialize)]
+pub struct StrengthResult {
+    aft_draft: f64,
+    nose_draft: f64,
+    mean_draft: f64,
+    trim: f64,
+    lightweight: Tons,
+    deadweight: Tons,
+    displacement_tonnage: Tons,
+    lcg: f64,
+    lcb: f64,
+    draft_comparison: DraftComparison,
+    diagrams: Diagrams,
+    strength_check: StrengthCheck,
+}
+
+impl StrengthResult {
+    ///
+    /// Основной конструктор.
+    #[instrument(skip_all, err, target = "StrengthResult::new")]
+    pub fn new(strength: &Strength, limits: &PermissibleLimits) -> Result<Self, String> {
+        let (Meters(aft_draft), Meters(nose_draft)) = strength.draft()?;
+        let Meters(lcb) = strength.lcb()?;
+        Ok(StrengthResult {
+            aft_draft,
+            nose_draft,
+            mean_draft: ((aft_draft + nose_draft) / 2.0).my_round(2),
+            trim: (nose_draft - aft_draft).my_round(2),
+            lightweight: strength.lightweight(),
+            deadweight: strength.deadweight(),
+            displacement_tonnage: strength.displacemnt_tonnage(),
+            lcg: strength.lcg().value().my_round(3),
+            lcb: lcb.my_round(3),
+            draft_comparison: DraftComparison::new(
+                &strength.quick_draft()?,
+                (aft_draft, nose_draft),
+            ),
+            diagrams: Diagrams {
+                lightweight_intensity: strength.lightweight_intensity().clone(),
+                deadweight_intensity: strength.deadweight_intensity().clone(),
+                displacement_intensity: strength.displacement_intensity().clone(),
+                buoyancy_intensity: strength.buoyancy_intensity().clone(),
+                total_shipload: strength.total_shipload().clone(),
+                share_force: strength.share_force().clone(),
+                share_force_with_correction: strength.share_force_with_correction().cloned(),
+                bending_moment: strength.bending_moment().clone(),
+                bending_moment_with_correction: strength.bending_moment_with_correction().cloned(),
+            },
+            strength_check: strength.strength_check(limits)?,
+        })
+    }
+
+    ///
+    /// Пересчитывает эпюры, силы и моменты в заданную систему единиц.
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        let (from, to) = (self.strength_check.unit_system(), unit_system);
+        self.diagrams = self.diagrams.converted(
+            to.intensity(TonsPerMeter(1.0)) / from.intensity(TonsPerMeter(1.0)),
+            to.force(TonsForce(1.0)) / from.force(TonsForce(1.0)),
+            to.moment(TonMeters(1.0)) / from.moment(TonMeters(1.0)),
+        );
+        self.strength_check = self.strength_check.with_unit_system(unit_system);
+        self
+    }
+
+    ///
+    /// Результаты проверки прочности.
+    pub fn strength_check(&self) -> &StrengthCheck {
+        &self.strength_check
+    }
+}
+
+///
+/// Уровень аварийно-предупредительной сигнализации.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum AlarmLevel {
+    Warning,
+    Alarm,
+}
+
+///
+/// Сигнал о превышении (или приближении к) допускаемых значений
+/// либо о невозможности выполнить расчет для текущей схемы загрузки.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Alarm {
+    level: AlarmLevel,
+    message: String,
+}
+
+impl Alarm {
+    ///
+    /// Основной конструктор.
+    pub fn new(level: AlarmLevel, message: String) -> Self {
+        Alarm { level, message }
+    }
+
+    ///
+    /// Сигналы по результатам проверки прочности.
+    pub fn from_strength_check(check: &StrengthCheck) -> Vec<Alarm> {
+        let mut alarms = vec![];
+        let factors = [
+            ("Перерезывающая сила", check.share_force_utilisation()),
+            ("Изгибающий момент на перегиб", check.hogging_utilisation()),
+            ("Изгибающий момент на прогиб", check.sagging_utilisation()),
+        ]
+        .into_iter()
+        .chain(
+            check
+                .shear_stress_utilisation()
+                .map(|utilisation| ("Касательное напряжение", utilisation)),
+        );
+        for (name, utilisation) in factors {
+            if utilisation > 1.0 {
+                alarms.push(Alarm::new(
+                    AlarmLevel::Alarm,
+                    format!(
+                        "{name}: {:.1}% от допускаемого значения, допускаемое значение превышено.",
+                        utilisation * 100.0
+                    ),
+                ));
+            } else if utilisation >= WARNING_UTILISATION {
+                alarms.push(Alarm::new(
+                    AlarmLevel::Warning,
+                    format!(
+                        "{name}: {:.1}% от допускаемого значения.",
+                        utilisation * 100.0
+                    ),
+                ));
+            }
+        }
+        alarms
+    }
+
+    ///
+    /// Уровень сигнала.
+    pub fn level(&self) -> AlarmLevel {
+        self.level
+    }
+
+    ///
+    /// Текст сигнала.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    io::BufReader,
+    net::{TcpListener, TcpStream},
+    time::Duration,
+};
+
+use log::{error, info, warn};
+use serde::Serialize;
+use tracing::instrument;
+
+use super::{
+    http::{HttpRequest, HttpResponse},
+    strength_result::{Alarm, AlarmLevel, StrengthResult},
+};
+use crate::{
+    core::unit_system::UnitSystem,
+    strength::{
+        buoyancy_intensity::quick_draft::QuickDraft, load::shiploads::Shiploads,
+        ship::ship_model::Ship, strength::Strength,
+    },
+};
+
+///
+/// Время ожидания данных запроса от клиента. Запросы обрабатываются последовательно,
+/// поэтому клиент, не передавший запрос целиком, не должен блокировать остальных.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+///
+/// Текущее контролируемое состояние судна.
+/// Parameters:
+///     result - результаты расчета прочности для текущей схемы загрузки,
+///     alarms - сигналы по результатам расчета.
+#[derive(Debug, Serialize)]
+pub struct MonitoredState {
+    result: Option<StrengthResult>,
+    alarms: Vec<Alarm>,
+}
+
+impl MonitoredState {
+    ///
+    /// Выполняет расчет прочности для схемы загрузки и формирует сигналы.
+    pub fn new(ship: &Ship, shiploads: &Shiploads) -> Self {
+        let state = match calculate(ship, shiploads) {
+            Ok(result) => {
+                let alarms = Alarm::from_strength_check(result.strength_check());
+                MonitoredState {
+                    result: Some(result),
+                    alarms,
+                }
+            }
+            Err(err) => MonitoredState {
+                result: None,
+                alarms: vec![Alarm::new(
+                    AlarmLevel::Alarm,
+                    format!("Расчет прочности для текущей схемы загрузки не выполнен: {err}"),
+                )],
+            },
+        };
+        for alarm in state.alarms() {
+            match alarm.level() {
+                AlarmLevel::Warning => warn!("{}", alarm.message()),
+                AlarmLevel::Alarm => error!("{}", alarm.message()),
+            }
+        }
+        state
+    }
+
+    ///
+    /// Пересчитывает результаты расчета в заданную систему единиц.
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        self.result = self
+            .result
+            .map(|result| result.with_unit_system(unit_system));
+        self
+    }
+
+    ///
+    /// Сигналы по результатам расчета.
+    pub fn alarms(&self) -> &Vec<Alarm> {
+        &self.alarms
+    }
+}
+
+///
+/// HTTP сервер для расчета прочности судна.
+/// Маршруты:
+///     POST /strength - расчет прочности для переданной схемы загрузки (Shiploads в формате json),
+///         контролируемое состояние не изменяется;
+///     POST /draft - быстрая оценка посадки по гидростатическим кривым для переданной схемы загрузки
+///         без удифферентовки и расчета прочности (QuickDraft);
+///     GET /state - текущее контролируемое состояние судна;
+///     POST /state - замена контролируемой схемы загрузки, возвращает новое состояние;
+///     GET /alarms - сигналы для текущего контролируемого состояния.
+/// Эпюры, силы и моменты в ответах приводятся в системе единиц unit_system (по умолчанию тонны-силы).
+pub struct Server {
+    ship: Ship,
+    state: MonitoredState,
+    unit_system: UnitSystem,
+}
+
+impl Server {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     ship - данные судна,
+    ///     shiploads - контролируемая схема загрузки судна.
+    pub fn new(ship: Ship, shiploads: &Shiploads) -> Self {
+        let state = MonitoredState::new(&ship, shiploads);
+        Server {
+            ship,
+            state,
+            unit_system: UnitSystem::default(),
+        }
+    }
+
+    ///
+    /// Задает систему единиц для вывода результатов расчета.
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        self.state = self.state.with_unit_system(unit_system);
+        self.unit_system = unit_system;
+        self
+    }
+
+    ///
+    /// Запускает сервер и обрабатывает запросы последовательно.
+    #[instrument(skip(self), err, target = "Server::run")]
+    pub fn run(&mut self, address: &str) -> Result<(), String> {
+        let listener = TcpListener::bind(address).map_err(|err| err.to_string())?;
+        info!("Сервер расчета прочности запущен: http://{address}");
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => self.handle_connection(stream),
+                Err(err) => warn!("Server.run | error {:?}", err),
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_connection(&mut self, stream: TcpStream) {
+        if let Err(err) = stream.set_read_timeout(Some(READ_TIMEOUT)) {
+            warn!("Server.handle_connection | error {:?}", err);
+            return;
+        }
+        let mut reader = BufReader::new(&stream);
+        let response = match HttpRequest::from_reader(&mut reader) {
+            Ok(request) => self.handle(&request),
+            Err(response) => response,
+        };
+        if response.status() >= 400 {
+            warn!("{} {}", response.status(), response.body());
+        }
+        let mut writer = &stream;
+        if let Err(err) = response.write_to(&mut writer) {
+            warn!("Server.handle_connection | error {:?}", err);
+        }
+    }
+
+    ///
+    /// Обрабатывает запрос и возвращает ответ.
+    pub fn handle(&mut self, request: &HttpRequest) -> HttpResponse {
+        info!("{} {}", request.method(), request.path());
+        match (request.method(), request.path()) {
+            ("POST", "/strength") => match parse_shiploads(request.body()) {
+                Ok(shiploads) => match calculate(&self.ship, &shiploads) {
+                    Ok(result) => HttpResponse::ok(&result.with_unit_system(self.unit_system)),
+                    Err(err) => HttpResponse::error(422, err),
+                },
+                Err(err) => HttpResponse::error(400, err),
+            },
+            ("POST", "/draft") => match parse_shiploads(request.body()) {
+                Ok(shiploads) => match QuickDraft::from_ship(&self.ship, &shiploads) {
+                    Ok(quick_draft) => HttpResponse::ok(&quick_draft),
+                    Err(err) => HttpResponse::error(422, err),
+                },
+                Err(err) => HttpResponse::error(400, err),
+            },
+            ("GET", "/state") => HttpResponse::ok(&self.state),
+            ("POST", "/state") => match parse_shiploads(request.body()) {
+                Ok(shiploads) => {
+                    self.state = MonitoredState::new(&self.ship, &shiploads)
+                        .with_unit_system(self.unit_system);
+                    HttpResponse::ok(&self.state)
+                }
+                Err(err) => HttpResponse::error(400, err),
+            },
+            ("GET", "/alarms") => HttpResponse::ok(self.state.alarms()),
+            (_, "/strength") | (_, "/draft") | (_, "/state") | (_, "/alarms") => {
+                HttpResponse::error(
+                    405,
+                    format!("Метод {} не поддерживается.", request.method()),
+                )
+            }
+            _ => HttpResponse::error(404, format!("Маршрут {} не найден.", request.path())),
+        }
+    }
+}
+
+fn parse_shiploads(body: &str) -> Result<Shiploads, String> {
+    Shiploads::from_value(serde_json::from_str(body).map_err(|err| err.to_string())?)
+}
+
+fn calculate(ship: &Ship, shiploads: &Shiploads) -> Result<StrengthResult, String> {
+    let strength = Strength::from_ship(ship, shiploads)?;
+    StrengthResult::new(&strength, &ship.permissible_limits()?)
+}
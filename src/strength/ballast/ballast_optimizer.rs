@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use log::info;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+
+use super::ballast_solution::{BallastSolution, TankFilling};
+use crate::{
+    core::{data_file::DataFile, schema_version::SchemaVersion, system_of_units::Meters},
+    strength::{
+        compartment::ship_compartment::Compartment,
+        load::{load_category::LoadCategory, shiploads::Shiploads},
+        ship::ship_model::Ship,
+        strength::Strength,
+        strength_check::still_water_check::StrengthCheck,
+    },
+};
+
+///
+/// Версии формата файла оптимизации балласта:
+///     1 - поля tanks, targets и необязательные поля initial_step, min_step.
+pub const BALLAST_SCHEMA: SchemaVersion = SchemaVersion::new("файла оптимизации балласта", 1, &[]);
+
+///
+/// Вес штрафа за невыполнение требований по осадке, дифференту и прочности
+/// относительно вклада массы балласта в целевую функцию.
+const PENALTY: f64 = 1000.0;
+
+///
+/// Требования к посадке судна после приема балласта.
+/// Parameters:
+///     trim - требуемый дифферент, положительный на нос [м],
+///     mean_draft - требуемая средняя осадка [м], не задается, если осадка не регламентируется,
+///     tolerance - допускаемое отклонение осадки и дифферента от требуемых значений [м].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct BallastTargets {
+    trim: f64,
+    #[serde(default)]
+    mean_draft: Option<f64>,
+    tolerance: f64,
+}
+
+impl BallastTargets {
+    ///
+    /// Отклонение посадки судна от требуемой за пределами допуска [м].
+    fn deviation(&self, aft_draft: f64, nose_draft: f64) -> f64 {
+        let trim_deviation = ((nose_draft - aft_draft - self.trim).abs() - self.tolerance).max(0.0);
+        let draft_deviation = self.mean_draft.map_or(0.0, |mean_draft| {
+            (((aft_draft + nose_draft) / 2.0 - mean_draft).abs() - self.tolerance).max(0.0)
+        });
+        trim_deviation + draft_deviation
+    }
+}
+
+///
+/// Результат расчета для одного варианта заполнения балластных цистерн.
+#[derive(Debug, Clone)]
+struct Evaluation {
+    objective: f64,
+    drafts: (f64, f64),
+    strength_check: StrengthCheck,
+    targets_met: bool,
+}
+
+///
+/// Подбор заполнения балластных цистерн для заданной схемы загрузки судна грузом.
+/// Минимизирует общую массу балласта при условии достижения требуемой посадки судна
+/// и обеспечения прочности (внутренние силовые факторы не превышают допускаемых).
+/// Поиск выполняется методом покоординатного спуска по степеням заполнения цистерн
+/// с последовательным уменьшением шага от initial_step до min_step.
+/// Parameters:
+///     tanks - балластные цистерны,
+///     targets - требования к посадке судна,
+///     initial_step - начальный шаг изменения степени заполнения цистерн,
+///     min_step - минимальный шаг изменения степени заполнения цистерн.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BallastOptimizer {
+    tanks: Vec<Compartment>,
+    targets: BallastTargets,
+    #[serde(default = "default_initial_step")]
+    initial_step: f64,
+    #[serde(default = "default_min_step")]
+    min_step: f64,
+}
+
+fn default_initial_step() -> f64 {
+    0.5
+}
+
+fn default_min_step() -> f64 {
+    0.125
+}
+
+impl BallastOptimizer {
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Версия формата проверяется до разбора содержимого (BALLAST_SCHEMA).
+    #[instrument(skip_all, err, target = "BallastOptimizer::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let value: Value = DataFile::new(file_path.clone()).deserialize()?;
+        let value = BALLAST_SCHEMA
+            .upgrade(value)
+            .map_err(|err| format!("Файл {file_path}: {err}"))?;
+        let optimizer: BallastOptimizer =
+            serde_json::from_value(value).map_err(|err| err.to_string())?;
+        optimizer.validate_input_data()
+    }
+
+    ///
+    /// Валидация входных данных.
+    fn validate_input_data(self) -> Result<BallastOptimizer, String> {
+        if self.tanks.is_empty() {
+            return Err("Балластные цистерны не заданы.".to_string());
+        }
+        if self.min_step <= 0.0 || self.initial_step < self.min_step || self.initial_step > 1.0 {
+            return Err(
+                "Шаг изменения степени заполнения цистерн должен удовлетворять условию 0 < min_step <= initial_step <= 1."
+                    .to_string(),
+            );
+        }
+        if self.targets.tolerance < 0.0 {
+            return Err("Допускаемое отклонение посадки не может быть отрицательным.".to_string());
+        }
+        Ok(self)
+    }
+
+    ///
+    /// Подбирает заполнение балластных цистерн.
+    /// Parameters:
+    ///     ship - данные судна,
+    ///     cargo - схема загрузки судна без балласта.
+    #[instrument(skip_all, err, target = "BallastOptimizer::optimize")]
+    pub fn optimize(&self, ship: &Ship, cargo: &Shiploads) -> Result<BallastSolution, String> {
+        let mut evaluations = HashMap::new();
+        let mut fillings = vec![0.0; self.tanks.len()];
+        let mut best = self.evaluate(ship, cargo, &fillings, &mut evaluations);
+        let mut step = self.initial_step;
+        while step >= self.min_step {
+            loop {
+                let mut improvement: Option<(Vec<f64>, Evaluation)> = None;
+                for i in 0..self.tanks.len() {
+                    for direction in [1.0, -1.0] {
+                        let filling = (fillings[i] + direction * step).clamp(0.0, 1.0);
+                        if filling == fillings[i] {
+                            continue;
+                        }
+                        let mut candidate = fillings.clone();
+                        candidate[i] = filling;
+                        let evaluation = self.evaluate(ship, cargo, &candidate, &mut evaluations);
+                        let current_best = improvement
+                            .as_ref()
+                            .map(|(_, evaluation)| evaluation)
+                            .or(best.as_ref())
+                            .map_or(f64::INFINITY, |evaluation| evaluation.objective);
+                        if let Some(evaluation) = evaluation {
+                            if evaluation.objective < current_best {
+                                improvement = Some((candidate, evaluation));
+                            }
+                        }
+                    }
+                }
+                match improvement {
+                    Some((candidate, evaluation)) => {
+                        info!(
+                            "BallastOptimizer.optimize | step = {step}, fillings = {:?}, objective = {}",
+                            candidate, evaluation.objective
+                        );
+                        fillings = candidate;
+                        best = Some(evaluation);
+                    }
+                    None => break,
+                }
+            }
+            step /= 2.0;
+        }
+        let best = best.ok_or(
+            "Не найдено ни одного варианта заполнения балластных цистерн, для которого выполнен расчет посадки судна."
+                .to_string(),
+        )?;
+        let density = cargo
+            .water_density()
+            .unwrap_or(ship.water_density().water_density());
+        let fillings = self
+            .tanks
+            .iter()
+            .zip(fillings.iter())
+            .map(|(tank, filling)| {
+                TankFilling::new(
+                    tank.name().to_string(),
+                    *filling,
+                    tank.shipload(*filling, density, LoadCategory::Ballast)
+                        .value()
+                        .value(),
+                )
+            })
+            .collect();
+        Ok(BallastSolution::new(
+            fillings,
+            best.drafts,
+            best.strength_check,
+            best.targets_met,
+        ))
+    }
+
+    ///
+    /// Схема загрузки судна с балластом.
+    fn shiploads(&self, ship: &Ship, cargo: &Shiploads, fillings: &[f64]) -> Shiploads {
+        let density = cargo
+            .water_density()
+            .unwrap_or(ship.water_density().water_density());
+        let mut shiploads = cargo.clone();
+        for (tank, filling) in self.tanks.iter().zip(fillings.iter()) {
+            if *filling > 0.0 {
+                shiploads.add(tank.shipload(*filling, density, LoadCategory::Ballast));
+            }
+        }
+        shiploads
+    }
+
+    ///
+    /// Расчет целевой функции для варианта заполнения балластных цистерн.
+    /// Возвращает None, если расчет посадки судна не выполнен.
+    fn evaluate(
+        &self,
+        ship: &Ship,
+        cargo: &Shiploads,
+        fillings: &[f64],
+        evaluations: &mut HashMap<Vec<i64>, Option<Evaluation>>,
+    ) -> Option<Evaluation> {
+        let key = fillings
+            .iter()
+            .map(|filling| (filling * 1.0e6).round() as i64)
+            .collect::<Vec<i64>>();
+        if let Some(evaluation) = evaluations.get(&key) {
+            return evaluation.clone();
+        }
+        let shiploads = self.shiploads(ship, cargo, fillings);
+        let evaluation = Strength::from_ship(ship, &shiploads)
+            .and_then(|strength| {
+                let (Meters(aft_draft), Meters(nose_draft)) = strength.draft()?;
+                Ok((
+                    (aft_draft, nose_draft),
+                    strength.strength_check(&ship.permissible_limits()?)?,
+                ))
+            })
+            .ok()
+            .map(|(drafts, strength_check)| {
+                let deviation = self.targets.deviation(drafts.0, drafts.1);
+                let overload = (strength_check.max_utilisation() - 1.0).max(0.0);
+                let density = cargo
+                    .water_density()
+                    .unwrap_or(ship.water_density().water_density());
+                let total_capacity: f64 =
+                    self.tanks.iter().map(|tank| tank.capacity(density)).sum();
+                let ballast = shiploads.sum() - cargo.sum();
+                Evaluation {
+                    objective: PENALTY * (deviation + overload) + ballast / total_capacity,
+                    drafts,
+                    strength_check,
+                    targets_met: deviation == 0.0 && overload == 0.0,
+                }
+            });
+        evaluations.insert(key, evaluation.clone());
+        evaluation
+    }
+}
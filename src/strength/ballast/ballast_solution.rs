@@ -0,0 +1,84 @@
+use serde::Serialize;
+
+use crate::core::{round::Round, unit_system::UnitSystem};
+use crate::strength::strength_check::still_water_check::StrengthCheck;
+
+///
+/// Степень заполнения балластной цистерны.
+/// Parameters:
+///     name - наименование цистерны,
+///     filling - степень заполнения (от 0 до 1),
+///     ballast - масса балласта в цистерне [т].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TankFilling {
+    name: String,
+    filling: f64,
+    ballast: f64,
+}
+
+impl TankFilling {
+    ///
+    /// Основной конструктор.
+    pub fn new(name: String, filling: f64, ballast: f64) -> Self {
+        TankFilling {
+            name,
+            filling,
+            ballast,
+        }
+    }
+
+    ///
+    /// Масса балласта в цистерне [т].
+    pub fn ballast(&self) -> f64 {
+        self.ballast
+    }
+}
+
+///
+/// Результат подбора балласта.
+/// Parameters:
+///     fillings - степени заполнения балластных цистерн,
+///     total_ballast - общая масса балласта [т],
+///     aft_draft, nose_draft - осадки кормой и носом [м],
+///     trim - дифферент, положительный на нос [м],
+///     strength_check - результаты проверки прочности,
+///     targets_met - достигнуты ли заданные осадка и дифферент и обеспечена ли прочность.
+#[derive(Debug, Clone, Serialize)]
+pub struct BallastSolution {
+    fillings: Vec<TankFilling>,
+    total_ballast: f64,
+    aft_draft: f64,
+    nose_draft: f64,
+    trim: f64,
+    strength_check: StrengthCheck,
+    targets_met: bool,
+}
+
+impl BallastSolution {
+    ///
+    /// Основной конструктор.
+    pub fn new(
+        fillings: Vec<TankFilling>,
+        (aft_draft, nose_draft): (f64, f64),
+        strength_check: StrengthCheck,
+        targets_met: bool,
+    ) -> Self {
+        let total_ballast: f64 = fillings.iter().map(|filling| filling.ballast()).sum();
+        BallastSolution {
+            fillings,
+            total_ballast: total_ballast.my_round(2),
+            aft_draft,
+            nose_draft,
+            trim: (nose_draft - aft_draft).my_round(2),
+            strength_check,
+            targets_met,
+        }
+    }
+
+    ///
+    /// Пересчитывает силы и моменты в результатах проверки прочности в заданную систему единиц.
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        self.strength_check = self.strength_check.with_unit_system(unit_system);
+        self
+    }
+}
@@ -0,0 +1,2 @@
+pub mod ballast_optimizer;
+pub mod ballast_solution;
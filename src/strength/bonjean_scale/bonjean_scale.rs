@@ -78,6 +78,7 @@ impl BonjeanScale {
     ///     draft - осадка судна [м].
     #[instrument(err, skip(self), target = "BonjeanScale::frame_underwater_volume")]
     pub fn frame_underwater_volume(&self, abscissa: f64, draft: f64) -> Result<f64, String> {
-        Ok(self.frame_underwater_area(abscissa, draft)? * self.ship_dimensions.length_spatium())
+        Ok(self.frame_underwater_area(abscissa, draft)?
+            * self.ship_dimensions.length_spatium().value())
     }
 }
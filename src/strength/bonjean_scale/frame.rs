@@ -8,18 +8,34 @@ use tracing::instrument;
 ///     id - номер шпангоута (нумерация шпангоутов c кормы в нос),
 ///     drafts - вектор, содержащий осадки шпангоута, вектор должен быть отсортирован по возрастанию,
 ///     areas - вектор, содержащий площади погруженной части шпангоута от осадки,
-///     volumes - вектор, содержащий объемы погруженной части шпангоута от осадки,
-///     masses - вектор, содержащий массы погруженной части шпангоута от осадки,
+///     volumes - вектор, содержащий объемы погруженной части шпангоута от осадки (необязательный),
+///     masses - вектор, содержащий массы погруженной части шпангоута от осадки (необязательный, ключ `massa`),
 ///     abscissa - абсцисса шпангоута относительно центра корабля.
-/// Длина всех векторов должна быть одинакова и не равна нулю, в проивном случая будет возвращена ошибка.
-#[derive(Deserialize, Debug, PartialEq)]
+/// Длина векторов осадок и площадей должна быть одинакова и не равна нулю, осадки должны строго возрастать,
+/// площади не должны уменьшаться с осадкой, в проивном случая будет возвращена ошибка.
+/// Объемы и массы в расчете не используются и не участвуют в сравнении шпангоутов,
+/// их согласованность с площадями проверяет BonjeanValidation.
+#[derive(Deserialize, Debug, Clone)]
 pub struct Frame {
     id: u64,
     drafts: Vec<f64>,
     areas: Vec<f64>,
+    #[serde(default)]
+    volumes: Vec<f64>,
+    #[serde(default, rename = "massa")]
+    masses: Vec<f64>,
     abscissa: f64,
 }
 
+impl PartialEq for Frame {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.drafts == other.drafts
+            && self.areas == other.areas
+            && self.abscissa == other.abscissa
+    }
+}
+
 impl Frame {
     ///
     /// Основной конструктор.
@@ -29,11 +45,43 @@ impl Frame {
             id,
             drafts,
             areas,
+            volumes: vec![],
+            masses: vec![],
             abscissa,
         })
         .validate_input_data()
     }
 
+    ///
+    /// Номер шпангоута.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    ///
+    /// Осадки шпангоута.
+    pub fn drafts(&self) -> &Vec<f64> {
+        &self.drafts
+    }
+
+    ///
+    /// Площади погруженной части шпангоута от осадки.
+    pub fn areas(&self) -> &Vec<f64> {
+        &self.areas
+    }
+
+    ///
+    /// Объемы погруженной части шпангоута от осадки, если заданы.
+    pub fn volumes(&self) -> &Vec<f64> {
+        &self.volumes
+    }
+
+    ///
+    /// Массы погруженной части шпангоута от осадки, если заданы.
+    pub fn masses(&self) -> &Vec<f64> {
+        &self.masses
+    }
+
     ///
     /// Абсцисса шпангоута относительно центра корабля.
     pub fn abscissa(&self) -> f64 {
@@ -44,13 +92,46 @@ impl Frame {
     // Валидация входных данных.
     #[instrument(skip(self), err, target = "Frame::validate_input_data")]
     fn validate_input_data(self) -> Result<Frame, String> {
+        self.validate()?;
+        Ok(self)
+    }
+
+    //
+    // Валидация входных данных без изменения шпангоута,
+    // используется также для шпангоутов, прочитанных из файла.
+    pub(crate) fn validate(&self) -> Result<(), String> {
         if let Err(err) = self.empty_data_validate() {
             return Err(err);
         }
         if let Err(err) = self.same_length_data_validate() {
             return Err(err);
         }
-        Ok(self)
+        self.monotonic_data_validate()
+    }
+
+    //
+    // Валидация входных данных.
+    // Осадки должны строго возрастать, площади погруженной части шпангоута не должны уменьшаться с осадкой.
+    fn monotonic_data_validate(&self) -> Result<(), String> {
+        for index in 1..self.drafts.len() {
+            if self.drafts[index] <= self.drafts[index - 1] {
+                return Err(format!(
+                    "Шпангоут {}, индекс {index}: осадка {} м не больше предыдущей осадки {} м. Осадки должны строго возрастать.",
+                    self.id,
+                    self.drafts[index],
+                    self.drafts[index - 1]
+                ));
+            }
+            if self.areas[index] < self.areas[index - 1] {
+                return Err(format!(
+                    "Шпангоут {}, индекс {index}: площадь {} м^2 меньше площади при предыдущей осадке {} м^2.",
+                    self.id,
+                    self.areas[index],
+                    self.areas[index - 1]
+                ));
+            }
+        }
+        Ok(())
     }
 
     //
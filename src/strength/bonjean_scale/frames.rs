@@ -1,12 +1,43 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
 use tracing::instrument;
 
-use crate::core::json_file::JsonFile;
+use crate::core::{
+    data_file::{DataFile, DataFormat},
+    schema_version::{Migration, SchemaVersion},
+};
 
 use super::frame::Frame;
 
+///
+/// Версии формата файла масштаба Бонжана:
+///     1 - массив шпангоутов без поля `version`,
+///     2 - объект с полями `version` и `frames`.
+pub const FRAMES_SCHEMA: SchemaVersion = SchemaVersion::new(
+    "файла масштаба Бонжана",
+    2,
+    &[Migration {
+        from: 1,
+        description: "массив шпангоутов перенесен в поле `frames`",
+        migrate: |value| match value {
+            Value::Array(_) => Ok(json!({ "frames": value })),
+            _ => Err(
+                "Файл масштаба Бонжана версии 1 должен содержать массив шпангоутов.".to_string(),
+            ),
+        },
+    }],
+);
+
+///
+/// Содержимое файла масштаба Бонжана текущей версии.
+#[derive(Deserialize)]
+struct FramesInput {
+    frames: Vec<Frame>,
+}
+
 ///
 /// Шпангоуты судна.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Frames {
     frames: Vec<Frame>,
 }
@@ -20,15 +51,70 @@ impl Frames {
     }
 
     ///
-    /// Create the object from json file.
+    /// Create the object from json, toml or yaml file.
+    /// Файлы старых версий формата обновляются до текущей версии (FRAMES_SCHEMA).
     #[instrument(target = "Frames::from_json_file", err)]
     pub fn from_json_file(file_path: String) -> Result<Frames, String> {
-        let json = JsonFile::new(file_path);
-        let contenst = json.content()?;
-        let frames = serde_json::from_reader(contenst).map_err(|e| e.to_string())?;
+        let value: Value = DataFile::new(file_path.clone()).deserialize()?;
+        let value = FRAMES_SCHEMA
+            .upgrade(value)
+            .map_err(|err| format!("Файл {file_path}: {err}"))?;
+        let input: FramesInput = serde_json::from_value(value).map_err(|e| e.to_string())?;
+        Frames::new(input.frames)
+    }
+
+    ///
+    /// Create the object from csv file.
+    /// Первая строка - заголовок: `draft` и номера шпангоутов, вторая строка - `abscissa` и абсциссы шпангоутов,
+    /// остальные строки - осадка и площади погруженной части шпангоутов при этой осадке.
+    /// Пустая ячейка означает, что для шпангоута площадь при этой осадке не задана.
+    #[instrument(target = "Frames::from_csv_file", err)]
+    pub fn from_csv_file(file_path: String) -> Result<Frames, String> {
+        let table = DataFile::new(file_path.clone()).csv_table()?;
+        let error = |err: String| format!("Файл {file_path}: {err}");
+        if table.header().len() < 2 {
+            return Err(error("Шпангоуты не заданы.".to_string()));
+        }
+        match table.rows().first() {
+            Some(row) if row[0] == "abscissa" => {}
+            _ => {
+                return Err(error(
+                    "Вторая строка таблицы должна содержать абсциссы шпангоутов `abscissa`."
+                        .to_string(),
+                ))
+            }
+        }
+        let drafts = table.column(0, 1).map_err(error)?;
+        let mut frames = vec![];
+        for (index, id) in table.header().iter().enumerate().skip(1) {
+            let id = id.parse::<u64>().map_err(|_| {
+                error(format!(
+                    "Заголовок столбца \"{id}\" должен быть номером шпангоута."
+                ))
+            })?;
+            let abscissa = table.number(&table.rows()[0][index], 0).map_err(error)?;
+            let mut frame_drafts = vec![];
+            let mut areas = vec![];
+            for (row, cells) in table.rows().iter().enumerate().skip(1) {
+                if !cells[index].is_empty() {
+                    frame_drafts.push(drafts[row - 1]);
+                    areas.push(table.number(&cells[index], row).map_err(error)?);
+                }
+            }
+            frames.push(Frame::new(id, frame_drafts, areas, abscissa).map_err(error)?);
+        }
         Frames::new(frames)
     }
 
+    ///
+    /// Create the object from json or csv file, формат определяется по расширению файла.
+    pub fn from_file(file_path: String) -> Result<Frames, String> {
+        match DataFormat::from_path(&file_path)? {
+            DataFormat::Csv => Frames::from_csv_file(file_path),
+            _ => Frames::from_json_file(file_path),
+        }
+    }
+
     ///
     /// Валидация входных данных.
     #[instrument(skip(self), err, target = "Frames::frames_validate")]
@@ -36,6 +122,28 @@ impl Frames {
         if self.frames.len() == 0 {
             return Err("Шпангоуты не заданы.".to_string());
         }
+        for (index, frame) in self.frames.iter().enumerate() {
+            frame.validate()?;
+            if let Some(previous) = index.checked_sub(1).map(|i| &self.frames[i]) {
+                if frame.abscissa() <= previous.abscissa() {
+                    return Err(format!(
+                        "Шпангоут {}, индекс {index}: абсцисса {} м не больше абсциссы предыдущего шпангоута {} м. Шпангоуты должны быть отсортированы по абсциссе.",
+                        frame.id(),
+                        frame.abscissa(),
+                        previous.abscissa()
+                    ));
+                }
+            }
+            if let Some(other) = self.frames[..index]
+                .iter()
+                .position(|other| other.id() == frame.id())
+            {
+                return Err(format!(
+                    "Шпангоут {}, индекс {index}: номер шпангоута совпадает с номером шпангоута с индексом {other}.",
+                    frame.id()
+                ));
+            }
+        }
         Ok(self)
     }
 
@@ -32,7 +32,7 @@ impl LCB {
     ///     node_draft - осадка носа [м].
     #[instrument(skip(self), err, target = "LCB::lcb")]
     pub fn lcb(&self, aft_draft: f64, nose_draft: f64) -> Result<f64, String> {
-        let length_spatium = self.ship_dimensions.length_spatium();
+        let length_spatium = self.ship_dimensions.length_spatium().value();
         let coordinate_aft = self.ship_dimensions.coordinate_aft();
         let mut abscissa = coordinate_aft + length_spatium / 2.0;
         let coordinate_bow = self.ship_dimensions.coordinate_nose();
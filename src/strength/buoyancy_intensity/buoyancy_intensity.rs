@@ -34,17 +34,17 @@ impl BuoyancyIntensity {
         bonjean_scale: &BonjeanScale,
         water_density: WaterDensity,
     ) -> Result<BuoyancyIntensity, String> {
-        let length_spatium = ship_dimensions.length_spatium();
+        let length_spatium = ship_dimensions.length_spatium().value();
         let half_spatium_len = length_spatium / 2.0;
         let coordinate_aft = ship_dimensions.coordinate_aft();
         let coordinate_nose = ship_dimensions.coordinate_nose();
         let number_spatiums = ship_dimensions.number_spatiums();
         let mut start_coord = coordinate_aft;
-        let mut end_coord = start_coord + ship_dimensions.length_spatium();
+        let mut end_coord = start_coord + ship_dimensions.length_spatium().value();
         let (aft_draft, nose_draft) = draft.draft(ship_dimensions)?;
         let li = LinearInterpolation::new(aft_draft, nose_draft, coordinate_aft, coordinate_nose);
         let mut buoyancy_intensity =
-            SpatiumFunctions::filled_zeros(number_spatiums, ship_dimensions.lbp());
+            SpatiumFunctions::filled_zeros(number_spatiums, ship_dimensions.lbp().value());
         for i in 0..number_spatiums {
             let abscissa = start_coord + half_spatium_len;
             let draft = li.interpolated_value(abscissa)?;
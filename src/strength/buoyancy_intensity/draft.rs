@@ -1,15 +1,13 @@
-use std::rc::Rc;
+use std::{cell::OnceCell, rc::Rc};
 use tracing::{info, instrument};
 
-use super::lcg::LCG;
+use super::{lcg::LCG, quick_draft::QuickDraft};
 use crate::{
-    core::round::Round,
+    core::{round::Round, water_density::WaterDensity},
     strength::{
         bonjean_scale::lcb::LCB,
         displacement::{displacement::Displacement, displacement_tonnage::DisplacementTonnage},
-        hydrostatic_curves::{
-            hydrostatic_curves::HydrostaticCurves, hydrostatic_typedata::HydrostaticTypeData,
-        },
+        hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
         ship::ship_dimensions::ShipDimensions,
     },
 };
@@ -22,13 +20,17 @@ use crate::{
 ///    lcg - центр тяжести судна,
 ///    displacement_tonnage - весовое водоизмещение судна,
 ///    hydrostatic_curves - гидростатические кривые,
-///    water_density - плотность воды.
+///    sea_water_density - плотность воды, для которой построены гидростатические кривые,
+///    если не задана, принимается равной плотности воды, в которой находится судно,
+///    drafts - осадки кормы и носа судна после удифферентовки, вычисляются при первом запросе.
 pub struct Draft {
     lcb: Rc<LCB>,
     displacement: Rc<Displacement>,
     lcg: LCG,
     d_t: DisplacementTonnage,
     hydrostatic_curves: HydrostaticCurves,
+    sea_water_density: Option<WaterDensity>,
+    drafts: OnceCell<(f64, f64)>,
 }
 
 impl Draft {
@@ -47,9 +49,26 @@ impl Draft {
             lcg,
             d_t,
             hydrostatic_curves,
+            sea_water_density: None,
+            drafts: OnceCell::new(),
         }
     }
 
+    ///
+    /// Задает плотность воды, для которой построены гидростатические кривые.
+    pub fn with_sea_water_density(mut self, sea_water_density: WaterDensity) -> Self {
+        self.sea_water_density = Some(sea_water_density);
+        self
+    }
+
+    ///
+    /// Плотность воды, для которой построены гидростатические кривые [т/м^3].
+    fn sea_water_density(&self) -> f64 {
+        self.sea_water_density
+            .unwrap_or(self.displacement.water_density())
+            .water_density()
+    }
+
     ///
     /// Выводит информацию о удифферентовки судна.
     fn solution_information(
@@ -107,13 +126,33 @@ impl Draft {
 
     ///
     /// Удифферентовка судна методом последовательных приближений.
+    /// Начальное приближение - посадка с дифферентом trim, полученным быстрой оценкой (QuickDraft),
+    /// если она не выходит за пределы гидростатических кривых, иначе - посадка на ровный киль.
     /// Возвращает осадки кормы и носа судна (aft_draft, nose_draft).
     #[instrument(skip(self), err, target = "Draft::trimming")]
-    fn trimming(&self, lbp: f64, mean_draft: f64, lcf: f64) -> Result<(f64, f64), String> {
+    pub(crate) fn trimming(
+        &self,
+        lbp: f64,
+        mean_draft: f64,
+        lcf: f64,
+        trim: f64,
+    ) -> Result<(f64, f64), String> {
         let mut max_draft = self.hydrostatic_curves.max_draft();
         let mut min_draft = self.hydrostatic_curves.min_draft();
-        let mut nose_draft = mean_draft;
-        let mut aft_draft = mean_draft;
+        let mut nose_draft = mean_draft + trim * (lbp / 2.0 - lcf) / lbp;
+        let mut aft_draft = mean_draft - trim * (lbp / 2.0 + lcf) / lbp;
+        if !(min_draft..=max_draft).contains(&aft_draft)
+            || !(min_draft..=max_draft).contains(&nose_draft)
+        {
+            nose_draft = mean_draft;
+            aft_draft = mean_draft;
+        } else if nose_draft > mean_draft {
+            // Дифферент на нос - осадка носом ищется выше средней осадки.
+            min_draft = mean_draft;
+        } else if nose_draft < mean_draft {
+            // Дифферент на корму - осадка носом ищется ниже средней осадки.
+            max_draft = mean_draft;
+        }
         let mut lcb = self.lcb.lcb(aft_draft, nose_draft)?;
         let lcg = self.lcg.lcg();
         let mut j = 0;
@@ -153,31 +192,48 @@ impl Draft {
             lcb = self.lcb.lcb(aft_draft, nose_draft)?;
             j += 1;
         }
+        info!("Число приближений удифферентовки: {j}.");
         Ok((aft_draft.my_round(2), nose_draft.my_round(2)))
     }
 
     ///
     /// Возвращает осадку кормы и носа судна (aft_draft, nose_draft).
-    #[instrument(skip_all, err, target = "Draft::draft")]
+    /// Удифферентовка выполняется при первом вызове, далее возвращается ее результат.
     pub fn draft(&self, ship_dimensions: ShipDimensions) -> Result<(f64, f64), String> {
+        if let Some(drafts) = self.drafts.get() {
+            return Ok(*drafts);
+        }
+        let drafts = self.trimmed_draft(ship_dimensions)?;
+        Ok(*self.drafts.get_or_init(|| drafts))
+    }
+
+    ///
+    /// Удифферентовка судна по водоизмещению и абсциссе центра величины.
+    #[instrument(skip_all, err, target = "Draft::draft")]
+    fn trimmed_draft(&self, ship_dimensions: ShipDimensions) -> Result<(f64, f64), String> {
         let displacement_tonnage = self.d_t.displacement_tonnage();
-        if displacement_tonnage > self.hydrostatic_curves.max_displacement_tonnage() {
+        // Гидростатические кривые построены для морской воды - водоизмещение приводится к ее плотности.
+        let density_ratio =
+            self.displacement.water_density().water_density() / self.sea_water_density();
+        if displacement_tonnage > self.hydrostatic_curves.max_displacement_tonnage() * density_ratio
+        {
             return Err(format!("Весовое водоизмещение {displacement_tonnage} тонн превысило весовое водоизмещение судна в грузу."));
         }
         let mut mean_draft = self
             .hydrostatic_curves
-            .mean_draft(displacement_tonnage)?
+            .mean_draft(displacement_tonnage / density_ratio)?
             .unwrap();
         let displacement = self.displacement.displacement_by_mass(displacement_tonnage);
-        let lcf = self
-            .hydrostatic_curves
-            .get_data_by_draft(mean_draft, HydrostaticTypeData::LCF)?
-            .unwrap();
+        let lcf = self.hydrostatic_curves.lcf(mean_draft)?.unwrap().value();
         let mut max_draft_d = self.hydrostatic_curves.max_draft();
         let mut min_draft_d = self.hydrostatic_curves.min_draft();
-        let lbp = ship_dimensions.lbp();
+        let lbp = ship_dimensions.lbp().value();
+        let trim = self
+            .quick_draft(ship_dimensions)
+            .map(|quick_draft| quick_draft.trim())
+            .unwrap_or(0.0);
         for i in 0..50 {
-            let (aft_draft, nose_draft) = self.trimming(lbp, mean_draft, lcf)?;
+            let (aft_draft, nose_draft) = self.trimming(lbp, mean_draft, lcf, trim)?;
             let calc_disp = self
                 .displacement
                 .displacement_by_drafts(aft_draft, nose_draft)?;
@@ -199,4 +255,17 @@ impl Draft {
                 .to_string(),
         )
     }
+
+    ///
+    /// Быстрая оценка посадки судна по гидростатическим кривым без удифферентовки.
+    pub fn quick_draft(&self, ship_dimensions: ShipDimensions) -> Result<QuickDraft, String> {
+        QuickDraft::new(
+            self.lcg.lcg(),
+            self.d_t.displacement_tonnage(),
+            self.displacement.water_density().water_density(),
+            self.sea_water_density(),
+            &self.hydrostatic_curves,
+            ship_dimensions.lbp().value(),
+        )
+    }
 }
@@ -1,3 +1,5 @@
 pub mod buoyancy_intensity;
 pub mod draft;
 pub mod lcg;
+pub mod quick_draft;
+pub mod side_drafts;
@@ -0,0 +1,197 @@
+use serde::Serialize;
+use tracing::instrument;
+
+use super::lcg::LCG;
+use crate::strength::{
+    hydrostatic_curves::{
+        hydrostatic_curves::HydrostaticCurves, hydrostatic_typedata::HydrostaticTypeData,
+    },
+    load::shiploads::Shiploads,
+    ship::ship_model::Ship,
+    strength::LoadingMasses,
+};
+
+///
+/// Быстрая оценка посадки судна только по гидростатическим кривым, без удифферентовки по масштабу Бонжана.
+/// Средняя осадка определяется по весовому водоизмещению, дифферент - по моменту, дифферентующему на 1 см:
+/// trim = displacement_tonnage * (lcg - lcb) / (100 * mct1cm). Ватерлиния поворачивается
+/// относительно центра тяжести площади ватерлинии (lcf).
+/// Если кривая mct1cm не задана, момент определяется по продольному метацентрическому радиусу:
+/// mct1cm = displacement_tonnage * lmr / (100 * lbp).
+/// Гидростатические кривые построены для морской воды, поэтому средняя осадка определяется
+/// по весовому водоизмещению, приведенному к плотности морской воды, а момент mct1cm
+/// пересчитывается пропорционально плотности воды, в которой находится судно.
+/// Parameters:
+///     displacement_tonnage - весовое водоизмещение [т],
+///     lcg - абсцисса центра тяжести судна [м],
+///     lcb - абсцисса центра величины при посадке на ровный киль [м],
+///     lcf - абсцисса центра тяжести площади ватерлинии [м],
+///     mct1cm - момент, дифферентующий на 1 см [т*м/см],
+///     mean_draft - средняя осадка [м],
+///     trim - дифферент, положительный на нос [м],
+///     aft_draft, nose_draft - осадки на кормовом и носовом перпендикулярах [м].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct QuickDraft {
+    displacement_tonnage: f64,
+    lcg: f64,
+    lcb: f64,
+    lcf: f64,
+    mct1cm: f64,
+    mean_draft: f64,
+    trim: f64,
+    aft_draft: f64,
+    nose_draft: f64,
+}
+
+impl QuickDraft {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     lcg - абсцисса центра тяжести судна [м],
+    ///     displacement_tonnage - весовое водоизмещение судна [т],
+    ///     water_density - плотность воды, в которой находится судно [т/м^3],
+    ///     sea_water_density - плотность воды, для которой построены гидростатические кривые [т/м^3],
+    ///     hydrostatic_curves - гидростатические кривые,
+    ///     lbp - длина судна между перпендикулярами [м].
+    #[instrument(skip(hydrostatic_curves), err, target = "QuickDraft::new")]
+    pub fn new(
+        lcg: f64,
+        displacement_tonnage: f64,
+        water_density: f64,
+        sea_water_density: f64,
+        hydrostatic_curves: &HydrostaticCurves,
+        lbp: f64,
+    ) -> Result<Self, String> {
+        let density_ratio = water_density / sea_water_density;
+        let Some(mean_draft) =
+            hydrostatic_curves.mean_draft(displacement_tonnage / density_ratio)?
+        else {
+            return Err(format!("Весовое водоизмещение {displacement_tonnage} тонн вне диапазона гидростатических кривых."));
+        };
+        let missing = |type_data: HydrostaticTypeData| {
+            format!(
+                "Кривая `{}` не задана для осадки {mean_draft} м.",
+                type_data.name()
+            )
+        };
+        let lcb = hydrostatic_curves
+            .lcb(mean_draft)?
+            .ok_or_else(|| missing(HydrostaticTypeData::LCB))?
+            .value();
+        let lcf = hydrostatic_curves
+            .lcf(mean_draft)?
+            .ok_or_else(|| missing(HydrostaticTypeData::LCF))?
+            .value();
+        let mct1cm = match hydrostatic_curves.mct1cm(mean_draft)? {
+            Some(mct1cm) => mct1cm.value() * density_ratio,
+            None => {
+                let lmr = hydrostatic_curves
+                    .lmr(mean_draft)?
+                    .ok_or_else(|| missing(HydrostaticTypeData::LMR))?;
+                displacement_tonnage * lmr.value() / (100.0 * lbp)
+            }
+        };
+        let trim = displacement_tonnage * (lcg - lcb) / (100.0 * mct1cm);
+        Ok(QuickDraft {
+            displacement_tonnage,
+            lcg,
+            lcb,
+            lcf,
+            mct1cm,
+            mean_draft,
+            trim,
+            aft_draft: mean_draft - trim * (lbp / 2.0 + lcf) / lbp,
+            nose_draft: mean_draft + trim * (lbp / 2.0 - lcf) / lbp,
+        })
+    }
+
+    ///
+    /// Вспомогательный конструктор.
+    /// Выполняет быструю оценку посадки для заданной схемы загрузки. Перед расчетом выполняется
+    /// проверка схемы загрузки (LoadingValidation).
+    /// Parameters:
+    ///     ship - данные судна,
+    ///     shiploads - схема загрузки судна.
+    #[instrument(skip_all, err, target = "QuickDraft::from_ship")]
+    pub fn from_ship(ship: &Ship, shiploads: &Shiploads) -> Result<Self, String> {
+        let masses = LoadingMasses::new(ship, shiploads)?;
+        QuickDraft::new(
+            LCG::from_disp_i(&masses.disp_i).lcg(),
+            masses.d_t.displacement_tonnage(),
+            masses.water_density.water_density(),
+            ship.water_density().water_density(),
+            ship.hydrostatic_curves(),
+            ship.ship_dimensions().lbp().value(),
+        )
+    }
+
+    ///
+    /// Абсцисса центра величины при посадке на ровный киль [м].
+    pub fn lcb(&self) -> f64 {
+        self.lcb
+    }
+
+    ///
+    /// Момент, дифферентующий на 1 см [т*м/см].
+    pub fn mct1cm(&self) -> f64 {
+        self.mct1cm
+    }
+
+    ///
+    /// Средняя осадка [м].
+    pub fn mean_draft(&self) -> f64 {
+        self.mean_draft
+    }
+
+    ///
+    /// Дифферент, положительный на нос [м].
+    pub fn trim(&self) -> f64 {
+        self.trim
+    }
+
+    ///
+    /// Осадки кормы и носа судна (aft_draft [м], nose_draft [м]).
+    pub fn drafts(&self) -> (f64, f64) {
+        (self.aft_draft, self.nose_draft)
+    }
+}
+
+///
+/// Сравнение быстрой оценки посадки с результатом удифферентовки по масштабу Бонжана.
+/// Parameters:
+///     quick_aft_draft, quick_nose_draft - осадки кормы и носа по быстрой оценке [м],
+///     aft_draft, nose_draft - осадки кормы и носа после удифферентовки [м],
+///     aft_difference, nose_difference, trim_difference - разность быстрой оценки и удифферентовки [м].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct DraftComparison {
+    quick_aft_draft: f64,
+    quick_nose_draft: f64,
+    aft_draft: f64,
+    nose_draft: f64,
+    aft_difference: f64,
+    nose_difference: f64,
+    trim_difference: f64,
+}
+
+impl DraftComparison {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     quick - быстрая оценка посадки,
+    ///     drafts - осадки кормы и носа после удифферентовки (aft_draft, nose_draft).
+    pub fn new(quick: &QuickDraft, drafts: (f64, f64)) -> Self {
+        let (quick_aft_draft, quick_nose_draft) = quick.drafts();
+        let (aft_draft, nose_draft) = drafts;
+        let aft_difference = quick_aft_draft - aft_draft;
+        let nose_difference = quick_nose_draft - nose_draft;
+        DraftComparison {
+            quick_aft_draft,
+            quick_nose_draft,
+            aft_draft,
+            nose_draft,
+            aft_difference,
+            nose_difference,
+            trim_difference: nose_difference - aft_difference,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use serde::Serialize;
+
+///
+/// Осадки судна у одного борта [м].
+/// Parameters:
+///     aft - на кормовом перпендикуляре,
+///     mid - на миделе,
+///     nose - на носовом перпендикуляре.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SideDrafts {
+    pub aft: f64,
+    pub mid: f64,
+    pub nose: f64,
+}
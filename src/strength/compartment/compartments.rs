@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use super::ship_compartment::Compartment;
+use crate::core::data_file::DataFile;
+
+///
+/// Грузовые помещения и цистерны судна.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub struct Compartments {
+    #[serde(default)]
+    compartments: Vec<Compartment>,
+}
+
+impl Compartments {
+    ///
+    /// Основной конструктор.
+    pub fn new(compartments: Vec<Compartment>) -> Self {
+        Compartments { compartments }
+    }
+
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Если помещения в файле не заданы, возвращает пустой список.
+    #[instrument(skip_all, err, target = "Compartments::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        DataFile::new(file_path).deserialize()
+    }
+}
+
+impl AsRef<Vec<Compartment>> for Compartments {
+    fn as_ref(&self) -> &Vec<Compartment> {
+        &self.compartments
+    }
+}
@@ -0,0 +1,2 @@
+pub mod compartments;
+pub mod ship_compartment;
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{point::Point, round::Round},
+    strength::load::{load_category::LoadCategory, shipload::Shipload},
+};
+
+///
+/// Грузовое помещение или цистерна судна.
+/// Parameters:
+///     name - наименование помещения,
+///     center_gravity - центр тяжести объема помещения относительно мидель шпангоута [м],
+///     length - длина помещения [м],
+///     volume - объем помещения [м^3],
+///     tank_top_area - площадь настила второго дна (для трюмов) [м^2],
+///     max_tank_top_load - допускаемая нагрузка на настил второго дна (для трюмов) [т/м^2],
+///     density - плотность груза, на который рассчитано помещение (для цистерн) [т/м^3].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Compartment {
+    name: String,
+    center_gravity: Point,
+    length: f64,
+    volume: f64,
+    #[serde(default)]
+    tank_top_area: Option<f64>,
+    #[serde(default)]
+    max_tank_top_load: Option<f64>,
+    #[serde(default)]
+    density: Option<f64>,
+}
+
+impl Compartment {
+    ///
+    /// Наименование помещения.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    ///
+    /// Центр тяжести объема помещения.
+    pub fn center_gravity(&self) -> Point {
+        self.center_gravity
+    }
+
+    ///
+    /// Объем помещения [м^3].
+    pub fn volume(&self) -> f64 {
+        self.volume
+    }
+
+    ///
+    /// Координата кормовой переборки помещения относительно мидель шпангоута [м].
+    pub fn start_coordinate(&self) -> f64 {
+        self.center_gravity.x - self.length / 2.0
+    }
+
+    ///
+    /// Координата носовой переборки помещения относительно мидель шпангоута [м].
+    pub fn end_coordinate(&self) -> f64 {
+        self.center_gravity.x + self.length / 2.0
+    }
+
+    ///
+    /// Масса груза плотностью density, полностью заполняющего помещение [т].
+    pub fn capacity(&self, density: f64) -> f64 {
+        self.volume * density
+    }
+
+    ///
+    /// Площадь настила второго дна [м^2].
+    pub fn tank_top_area(&self) -> Option<f64> {
+        self.tank_top_area
+    }
+
+    ///
+    /// Допускаемая масса груза по прочности настила второго дна [т].
+    /// Возвращает None, если площадь настила или допускаемая нагрузка на него не заданы.
+    pub fn max_tank_top_mass(&self) -> Option<f64> {
+        self.tank_top_area
+            .zip(self.max_tank_top_load)
+            .map(|(area, load)| area * load)
+    }
+
+    ///
+    /// Наибольшая масса груза плотностью density, которую можно принять в помещение [т].
+    /// Учитывает объем помещения и допускаемую нагрузку на настил второго дна.
+    pub fn max_cargo_mass(&self, density: f64) -> f64 {
+        let capacity = self.capacity(density);
+        self.max_tank_top_mass()
+            .map_or(capacity, |max_mass| capacity.min(max_mass))
+    }
+
+    ///
+    /// Наибольшая масса груза, которую можно принять в помещение [т].
+    /// Учитывает объем помещения при плотности груза, на который рассчитано помещение,
+    /// и допускаемую нагрузку на настил второго дна.
+    /// Возвращает None, если ни плотность, ни допускаемая нагрузка на настил не заданы.
+    pub fn max_mass(&self) -> Option<f64> {
+        match (self.density, self.max_tank_top_mass()) {
+            (Some(density), _) => Some(self.max_cargo_mass(density)),
+            (None, max_tank_top_mass) => max_tank_top_mass,
+        }
+    }
+
+    ///
+    /// Нагрузка от помещения, заполненного грузом плотностью density.
+    /// Нагрузка получает наименование помещения.
+    /// Parameters:
+    ///     filling - степень заполнения помещения (от 0 до 1),
+    ///     density - плотность груза [т/м^3],
+    ///     category - категория нагрузки.
+    pub fn shipload(&self, filling: f64, density: f64, category: LoadCategory) -> Shipload {
+        Shipload::new(
+            (self.capacity(density) * filling).my_round(2),
+            self.center_gravity,
+            self.length,
+        )
+        .with_name(&self.name, None)
+        .with_category(category)
+    }
+}
@@ -22,7 +22,7 @@ impl DeadweightIntensity {
     pub fn builder(
         shiploads: &Shiploads,
         ship_dimensions: ShipDimensions,
-    ) -> DeadweightIntensityBuilder {
+    ) -> DeadweightIntensityBuilder<'_> {
         DeadweightIntensityBuilder::new(shiploads, ship_dimensions)
     }
 
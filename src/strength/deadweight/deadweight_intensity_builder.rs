@@ -1,7 +1,7 @@
 use tracing::info;
 
 use crate::{
-    core::round::Round,
+    core::{round::Round, system_of_units::Tons},
     strength::ship::{
         ship_dimensions::ShipDimensions, spatium_function::SpatiumFunction,
         spatium_functions::SpatiumFunctions,
@@ -34,30 +34,104 @@ impl<'a> DeadweightIntensityBuilder<'a> {
     /// Возвращает объект DeadweightIntensity.
     pub fn build(&self) -> DeadweightIntensity {
         let number_spatiums = self.ship_dimensions.number_spatiums();
-        let length_between_perpendiculars = self.ship_dimensions.lbp();
+        let length_between_perpendiculars = self.ship_dimensions.lbp().value();
         let mut spatium_functions =
             SpatiumFunctions::filled_zeros(number_spatiums, length_between_perpendiculars);
         let shiploads = self.shiploads.shared_shiploads(&self.ship_dimensions);
-        for shipload in shiploads.into_iter() {
-            for spatium_function in self.shipload_intensity(shipload).into_iter() {
+        for shipload in shiploads.as_ref().iter() {
+            let intensity = match shipload.shape() {
+                Some(_) => self.shaped_shipload_intensity(shipload),
+                None => self.shipload_intensity(shipload),
+            };
+            for spatium_function in intensity.into_iter() {
                 spatium_functions.add(spatium_function);
             }
         }
         DeadweightIntensity::new(spatium_functions)
     }
 
+    ///
+    /// Интенсивность нагрузки с заданным законом распределения [т/м].
+    /// Для каждой теоретической шпации определяются масса F и статический момент M
+    /// части нагрузки относительно кормового шпангоута шпации, которые заменяются
+    /// линейной интенсивностью с теми же F и M:
+    /// f_x2 = 6M / h^2 - 2F / h, f_x1 = 2F / h - f_x2, где h - длина шпации.
+    /// Части нагрузки, выходящие за пределы крайних шпангоутов, относятся к крайним шпациям.
+    fn shaped_shipload_intensity(&self, shipload: &Shipload) -> Vec<SpatiumFunction> {
+        let number_spatiums = self.ship_dimensions.number_spatiums();
+        let length_spatium = self.ship_dimensions.length_spatium().value();
+        let mut loads = vec![(0.0, 0.0); number_spatiums as usize];
+        let spatium_bounds = |id: u64| {
+            let start = match id {
+                0 => f64::NEG_INFINITY,
+                _ => self.ship_dimensions.spatium_start_coordinate(id),
+            };
+            let end = match id {
+                id if id == number_spatiums - 1 => f64::INFINITY,
+                _ => self.ship_dimensions.spatium_end_coordinate(id),
+            };
+            (start, end)
+        };
+        let polyline = shipload.shape().and_then(|shape| {
+            shape.polyline(
+                shipload.load_start_coordinate(),
+                shipload.load_end_coordinate(),
+                shipload.value().value(),
+            )
+        });
+        for id in 0..number_spatiums {
+            let (start, end) = spatium_bounds(id);
+            let origin = self.ship_dimensions.spatium_start_coordinate(id);
+            let load = &mut loads[id as usize];
+            match &polyline {
+                Some(points) => {
+                    for segment in points.windows(2) {
+                        let ((x1, q1), (x2, q2)) = (segment[0], segment[1]);
+                        let (a, b) = (start.max(x1), end.min(x2));
+                        if a >= b {
+                            continue;
+                        }
+                        let q = |x: f64| q1 + (q2 - q1) * (x - x1) / (x2 - x1);
+                        let (qa, qb) = (q(a), q(b));
+                        load.0 += (qa + qb) / 2.0 * (b - a);
+                        load.1 += (b - a)
+                            * ((a - origin) * (qa + qb) / 2.0 + (b - a) * (qa + 2.0 * qb) / 6.0);
+                    }
+                }
+                None => {
+                    let x = shipload.longitudinal_center_gravity();
+                    if x >= start && x < end {
+                        let Tons(value) = shipload.value();
+                        load.0 += value;
+                        load.1 += value * (x - origin);
+                    }
+                }
+            }
+        }
+        loads
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (force, moment))| *force != 0.0 || *moment != 0.0)
+            .map(|(id, (force, moment))| {
+                let f_x2 = 6.0 * moment / length_spatium.powi(2) - 2.0 * force / length_spatium;
+                let f_x1 = 2.0 * force / length_spatium - f_x2;
+                SpatiumFunction::from_id(id as u64, &self.ship_dimensions, f_x1, f_x2)
+            })
+            .collect()
+    }
+
     ///
     /// Максимальная интенсивность распределенной нагрузки, расположенной в пределах одной шпации асимметрично.
-    fn max_intensity(&self, c_min: f64, shipload: Shipload) -> f64 {
-        shipload.value() * (0.5 + (c_min / self.ship_dimensions.length_spatium()))
-            / self.ship_dimensions.length_spatium()
+    fn max_intensity(&self, c_min: f64, shipload: &Shipload) -> f64 {
+        let length_spatium = self.ship_dimensions.length_spatium().value();
+        shipload.value().value() * (0.5 + (c_min / length_spatium)) / length_spatium
     }
 
     ///
     /// Минимальная интенсивность распределенной нагрузки, расположенной в пределах одной шпации асимметрично.
-    fn min_intensity(&self, c_min: f64, shipload: Shipload) -> f64 {
-        shipload.value() * (0.5 - (c_min / self.ship_dimensions.length_spatium()))
-            / self.ship_dimensions.length_spatium()
+    fn min_intensity(&self, c_min: f64, shipload: &Shipload) -> f64 {
+        let length_spatium = self.ship_dimensions.length_spatium().value();
+        shipload.value().value() * (0.5 - (c_min / length_spatium)) / length_spatium
     }
 
     ///
@@ -67,7 +141,7 @@ impl<'a> DeadweightIntensityBuilder<'a> {
         index: u64,
         next_index: u64,
         distance: f64,
-        shipload: Shipload,
+        shipload: &Shipload,
     ) -> Vec<SpatiumFunction> {
         let f_x_max_intensity = self.max_intensity(distance, shipload).my_round(2);
         let f_x_min_intensity = self.min_intensity(distance, shipload).my_round(2);
@@ -91,7 +165,7 @@ impl<'a> DeadweightIntensityBuilder<'a> {
     /// Интенсивность распределенной нагрузки, расположенной за пределами крайних шпангоутов судна.
     fn intensity_load_located_outside_outer_frames(
         &self,
-        shipload: Shipload,
+        shipload: &Shipload,
     ) -> Vec<SpatiumFunction> {
         let (spatium_id, next_spatium_id, distance) = {
             if shipload.load_start_coordinate() < self.ship_dimensions.coordinate_aft()
@@ -109,8 +183,8 @@ impl<'a> DeadweightIntensityBuilder<'a> {
                 (rightmost_spatium_id, rightmost_spatium_id - 1, distance)
             }
         };
-        let f_x = ((1.5 + (distance / self.ship_dimensions.length_spatium())) * shipload.value())
-            / self.ship_dimensions.length_spatium();
+        let length_spatium = self.ship_dimensions.length_spatium().value();
+        let f_x = ((1.5 + (distance / length_spatium)) * shipload.value().value()) / length_spatium;
         let mut spatium_functions: Vec<SpatiumFunction> = vec![];
         let spatium_function = SpatiumFunction::from_id(
             spatium_id,
@@ -120,8 +194,8 @@ impl<'a> DeadweightIntensityBuilder<'a> {
         );
         spatium_functions.push(spatium_function);
 
-        let f_x = -((0.5 + (distance / self.ship_dimensions.length_spatium())) * shipload.value())
-            / self.ship_dimensions.length_spatium();
+        let f_x =
+            -((0.5 + (distance / length_spatium)) * shipload.value().value()) / length_spatium;
         let spatium_function = SpatiumFunction::from_id(
             next_spatium_id,
             &self.ship_dimensions,
@@ -137,7 +211,7 @@ impl<'a> DeadweightIntensityBuilder<'a> {
     /// Интенсивность распределенной нагрузки, невыходящей за пределы крайних шпангоутов судна, и расположенной в пределах одной шпации.
     fn intensity_load_located_inside_outer_frames(
         &self,
-        shipload: Shipload,
+        shipload: &Shipload,
     ) -> Vec<SpatiumFunction> {
         let spatium_start_index = self
             .ship_dimensions
@@ -170,7 +244,7 @@ impl<'a> DeadweightIntensityBuilder<'a> {
         // Груз расположен в пределах одной шпации симметрично.
         } else {
             info!("Shipload.shipload_intensity | Вес груза распределяем на всю теоретическую шпацию. c_right = {}, c_left = {}", distance_right, distance_left);
-            let f_x = shipload.value() / self.ship_dimensions.length_spatium();
+            let f_x = shipload.value().value() / self.ship_dimensions.length_spatium().value();
             let spatium_function = SpatiumFunction::new(
                 spatium_start_index,
                 shipload.load_start_coordinate(),
@@ -186,7 +260,7 @@ impl<'a> DeadweightIntensityBuilder<'a> {
 
     ///
     /// Определяет интенсивность распределенной нагрузки на теоретические шпации судна [т/м].
-    fn shipload_intensity(&self, shipload: Shipload) -> Vec<SpatiumFunction> {
+    fn shipload_intensity(&self, shipload: &Shipload) -> Vec<SpatiumFunction> {
         // Нгарузка не выходит за пределы крайних шпангоутов.
         if shipload.longitudinal_center_gravity() > self.ship_dimensions.coordinate_aft()
             && shipload.longitudinal_center_gravity() < self.ship_dimensions.coordinate_nose()
@@ -33,7 +33,7 @@ impl Displacement {
     /// Возвращает объемное водоизмещение судна от осадки. [м^3]
     #[instrument(skip(self), err, target = "Displacement::displacement_by_drafts")]
     pub fn displacement_by_drafts(&self, aft_draft: f64, nose_draft: f64) -> Result<f64, String> {
-        let length_spatium = self.ship_dimensions.length_spatium();
+        let length_spatium = self.ship_dimensions.length_spatium().value();
         let coordinate_aft = self.ship_dimensions.coordinate_aft();
         let mut abscissa = coordinate_aft + length_spatium / 2.0;
         let coordinate_bow = self.ship_dimensions.coordinate_nose();
@@ -51,6 +51,12 @@ impl Displacement {
         Ok(ship_underwater_volume)
     }
 
+    ///
+    /// Плотность воды, в которой находится судно.
+    pub fn water_density(&self) -> WaterDensity {
+        self.water_density
+    }
+
     ///
     /// Возвращает объемное водоизмещение судна от массы. [м^3]
     pub fn displacement_by_mass(&self, massa: f64) -> f64 {
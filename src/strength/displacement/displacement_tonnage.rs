@@ -12,6 +12,6 @@ impl DisplacementTonnage {
     }
 
     pub fn displacement_tonnage(&self) -> f64 {
-        self.lw.lightweight() + self.dw.deadweight()
+        self.lw.lightweight().value() + self.dw.deadweight()
     }
 }
@@ -1,3 +1,4 @@
 pub mod displacement;
 pub mod displacement_intensity;
 pub mod displacement_tonnage;
+pub mod water_allowance;
@@ -0,0 +1,70 @@
+use serde::Serialize;
+
+use crate::strength::hydrostatic_curves::hydrostatic_curves::HydrostaticCurves;
+
+///
+/// Плотность пресной воды [т/м^3].
+pub const FRESH_WATER_DENSITY: f64 = 1.0;
+
+///
+/// Поправки к осадке на плотность воды.
+/// FWA (fresh water allowance) - увеличение средней осадки при переходе из морской воды в пресную,
+/// DWA (dock water allowance) - увеличение средней осадки при переходе из морской воды в воду заданной плотности.
+/// Parameters:
+///     displacement_tonnage - весовое водоизмещение [т],
+///     tpc - число тонн на 1 см осадки в морской воде [т/см],
+///     sea_water_density - плотность морской воды, для которой построены гидростатические кривые [т/м^3].
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct WaterAllowance {
+    displacement_tonnage: f64,
+    tpc: f64,
+    sea_water_density: f64,
+}
+
+impl WaterAllowance {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     hydrostatic_curves - гидростатические кривые,
+    ///     sea_water_density - плотность морской воды [т/м^3],
+    ///     displacement_tonnage - весовое водоизмещение [т],
+    ///     mean_draft - средняя осадка [м].
+    pub fn new(
+        hydrostatic_curves: &HydrostaticCurves,
+        sea_water_density: f64,
+        displacement_tonnage: f64,
+        mean_draft: f64,
+    ) -> Result<Self, String> {
+        if sea_water_density <= FRESH_WATER_DENSITY {
+            return Err(format!(
+                "Плотность морской воды {sea_water_density} т/м^3 должна быть больше плотности пресной воды {FRESH_WATER_DENSITY} т/м^3."
+            ));
+        }
+        let Some(waterline_area) = hydrostatic_curves.waterline_area(mean_draft)? else {
+            return Err(format!(
+                "Средняя осадка {mean_draft} м выходит за пределы гидростатических кривых."
+            ));
+        };
+        Ok(WaterAllowance {
+            displacement_tonnage,
+            tpc: waterline_area.value() * sea_water_density / 100.0,
+            sea_water_density,
+        })
+    }
+
+    ///
+    /// Поправка на пресную воду FWA = Δ / (4 * TPC) [м].
+    pub fn fwa(&self) -> f64 {
+        // Формула дает поправку в миллиметрах.
+        self.displacement_tonnage / (4.0 * self.tpc) / 1000.0
+    }
+
+    ///
+    /// Поправка на плотность воды DWA = FWA * (ρ_морск - ρ) / (ρ_морск - ρ_пресн) [м].
+    /// Parameters:
+    ///     water_density - плотность воды [т/м^3].
+    pub fn dwa(&self, water_density: f64) -> f64 {
+        self.fwa() * (self.sea_water_density - water_density)
+            / (self.sea_water_density - FRESH_WATER_DENSITY)
+    }
+}
@@ -2,7 +2,10 @@ use serde::Deserialize;
 use tracing::instrument;
 
 use crate::core::{
-    binary_search::BinarySearch, json_file::JsonFile, linear_interpolation::LinearInterpolation,
+    binary_search::BinarySearch,
+    data_file::{DataFile, DataFormat},
+    linear_interpolation::LinearInterpolation,
+    system_of_units::{Meters, SquareMeters, TonMetersPerCentimeter, Tons, TonsPerCentimeter},
 };
 
 use super::hydrostatic_typedata::HydrostaticTypeData;
@@ -18,8 +21,13 @@ use super::hydrostatic_typedata::HydrostaticTypeData;
 ///     x_c: абсцисса центра велечины,
 ///     waterline_area: площадь ватерлинии,
 ///     x_f: абсцисса центра тяжести ватерлиниии,
-///     lmr - продольный(большой) метацентрический радиус.
-#[derive(Deserialize, Debug)]
+///     lmr - продольный(большой) метацентрический радиус,
+///     kb - аппликата центра величины (необязательная кривая),
+///     km - аппликата поперечного метацентра (необязательная кривая),
+///     mct1cm - момент, дифферентующий на 1 см (необязательная кривая),
+///     tpc - число тонн на 1 см осадки (необязательная кривая),
+///     cb - коэффициент общей полноты (необязательная кривая).
+#[derive(Deserialize, Debug, Clone)]
 pub struct HydrostaticCurves {
     drafts: Vec<f64>,
     displacement_tonnage: Vec<f64>,
@@ -27,6 +35,16 @@ pub struct HydrostaticCurves {
     waterline_area: Vec<f64>,
     x_f: Vec<f64>,
     lmr: Vec<f64>,
+    #[serde(default)]
+    kb: Vec<f64>,
+    #[serde(default)]
+    km: Vec<f64>,
+    #[serde(default)]
+    mct1cm: Vec<f64>,
+    #[serde(default)]
+    tpc: Vec<f64>,
+    #[serde(default)]
+    cb: Vec<f64>,
 }
 
 impl HydrostaticCurves {
@@ -48,23 +66,134 @@ impl HydrostaticCurves {
             waterline_area,
             x_f,
             lmr: r_l,
+            kb: vec![],
+            km: vec![],
+            mct1cm: vec![],
+            tpc: vec![],
+            cb: vec![],
         })
         .validate_input_data()
     }
 
+    ///
+    /// Задает кривую аппликаты поперечного метацентра.
+    /// Parameters:
+    ///     km - аппликата поперечного метацентра для каждой осадки `drafts` [м].
+    #[instrument(skip_all, err, target = "HydrostaticCurves::with_km")]
+    pub fn with_km(mut self, km: Vec<f64>) -> Result<Self, String> {
+        self.km = km;
+        self.validate_input_data()
+    }
+
+    ///
+    /// Задает кривую аппликаты центра величины.
+    /// Parameters:
+    ///     kb - аппликата центра величины для каждой осадки `drafts` [м].
+    #[instrument(skip_all, err, target = "HydrostaticCurves::with_kb")]
+    pub fn with_kb(mut self, kb: Vec<f64>) -> Result<Self, String> {
+        self.kb = kb;
+        self.validate_input_data()
+    }
+
+    ///
+    /// Задает кривую момента, дифферентующего на 1 см.
+    /// Parameters:
+    ///     mct1cm - момент, дифферентующий на 1 см, для каждой осадки `drafts` [т*м/см].
+    #[instrument(skip_all, err, target = "HydrostaticCurves::with_mct1cm")]
+    pub fn with_mct1cm(mut self, mct1cm: Vec<f64>) -> Result<Self, String> {
+        self.mct1cm = mct1cm;
+        self.validate_input_data()
+    }
+
+    ///
+    /// Задает кривую числа тонн на 1 см осадки.
+    /// Parameters:
+    ///     tpc - число тонн на 1 см осадки для каждой осадки `drafts` [т/см].
+    #[instrument(skip_all, err, target = "HydrostaticCurves::with_tpc")]
+    pub fn with_tpc(mut self, tpc: Vec<f64>) -> Result<Self, String> {
+        self.tpc = tpc;
+        self.validate_input_data()
+    }
+
+    ///
+    /// Задает кривую коэффициента общей полноты.
+    /// Parameters:
+    ///     cb - коэффициент общей полноты для каждой осадки `drafts`.
+    #[instrument(skip_all, err, target = "HydrostaticCurves::with_cb")]
+    pub fn with_cb(mut self, cb: Vec<f64>) -> Result<Self, String> {
+        self.cb = cb;
+        self.validate_input_data()
+    }
+
     ///
     /// Вспомогательный конструктор.
+    /// Create the object from json, toml or yaml file.
     #[instrument(skip_all, err, target = "HydrostaticCurves::from_json_file")]
     pub fn from_json_file(file_path: String) -> Result<HydrostaticCurves, String> {
-        let json = JsonFile::new(file_path);
-        let content = json.content()?;
-        serde_json::from_reader(content).map_err(|err| err.to_string())
+        let curves: HydrostaticCurves = DataFile::new(file_path).deserialize()?;
+        curves.validate_input_data()
+    }
+
+    ///
+    /// Create the object from csv file.
+    /// Одна строка для каждой осадки, один столбец для каждой кривой. Заголовки столбцов:
+    /// drafts, displacement_tonnage, x_c, waterline_area, x_f, lmr и необязательные kb, km, mct1cm, tpc, cb.
+    #[instrument(skip_all, err, target = "HydrostaticCurves::from_csv_file")]
+    pub fn from_csv_file(file_path: String) -> Result<HydrostaticCurves, String> {
+        let table = DataFile::new(file_path.clone()).csv_table()?;
+        let error = |err: String| format!("Файл {file_path}: {err}");
+        if let Some(column) = table.header().iter().find(|column| {
+            column.as_str() != "drafts"
+                && !HydrostaticTypeData::all()
+                    .iter()
+                    .any(|type_data| type_data.name() == column.as_str())
+        }) {
+            return Err(error(format!("Неизвестный столбец \"{column}\".")));
+        }
+        let column = |name: &str| -> Result<Vec<f64>, String> {
+            let index = table
+                .column_index(name)
+                .ok_or(error(format!("Отсутствует столбец \"{name}\".")))?;
+            table.column(index, 0).map_err(error)
+        };
+        let mut curves = HydrostaticCurves::new(
+            column("drafts")?,
+            column("displacement_tonnage")?,
+            column("x_c")?,
+            column("waterline_area")?,
+            column("x_f")?,
+            column("lmr")?,
+        )
+        .map_err(error)?;
+        type WithCurve = fn(HydrostaticCurves, Vec<f64>) -> Result<HydrostaticCurves, String>;
+        let optional: [(&str, WithCurve); 5] = [
+            ("kb", HydrostaticCurves::with_kb),
+            ("km", HydrostaticCurves::with_km),
+            ("mct1cm", HydrostaticCurves::with_mct1cm),
+            ("tpc", HydrostaticCurves::with_tpc),
+            ("cb", HydrostaticCurves::with_cb),
+        ];
+        for (name, with_curve) in optional {
+            if table.column_index(name).is_some() {
+                curves = with_curve(curves, column(name)?).map_err(error)?;
+            }
+        }
+        Ok(curves)
+    }
+
+    ///
+    /// Create the object from json, toml, yaml or csv file, формат определяется по расширению файла.
+    pub fn from_file(file_path: String) -> Result<HydrostaticCurves, String> {
+        match DataFormat::from_path(&file_path)? {
+            DataFormat::Csv => HydrostaticCurves::from_csv_file(file_path),
+            _ => HydrostaticCurves::from_json_file(file_path),
+        }
     }
 
     ///
     /// Валидация входных данных.
     #[instrument(skip(self), err, target = "HydrostaticCurves::validate_input_data")]
-    fn validate_input_data(self) -> Result<HydrostaticCurves, String> {
+    pub(crate) fn validate_input_data(self) -> Result<HydrostaticCurves, String> {
         if let Err(err) = self.validate_empty_data() {
             return Err(err);
         }
@@ -77,44 +206,72 @@ impl HydrostaticCurves {
         if let Err(err) = self.validate_drafts() {
             return Err(err);
         }
+        self.validate_displacement_tonnage()?;
+        self.validate_block_coefficient()?;
         Ok(self)
     }
 
     ///
-    /// Валидация: все элементы теоретического чертежа должны быть заданы.
+    /// Валидация: осадки и все обязательные элементы теоретического чертежа должны быть заданы.
     fn validate_empty_data(&self) -> Result<(), String> {
-        if self.drafts.len() == 0
-            || self.displacement_tonnage.len() == 0
-            || self.x_c.len() == 0
-            || self.waterline_area.len() == 0
-            || self.x_f.len() == 0
-            || self.lmr.len() == 0
+        if self.drafts.is_empty()
+            || HydrostaticTypeData::all()
+                .iter()
+                .any(|type_data| type_data.is_required() && self.data(*type_data).is_empty())
         {
             return Err("Гидростатические кривые не заданы".to_string());
         }
         Ok(())
     }
 
+    ///
+    /// Валидация: осадка должна строго возрастать.
     fn validate_drafts(&self) -> Result<(), String> {
-        let priviuse_draft = self.drafts.first().unwrap();
-        for draft in self.drafts[1..].iter() {
-            if draft < priviuse_draft {
+        for pair in self.drafts.windows(2) {
+            if pair[1] <= pair[0] {
                 return Err("Осадка `drafts` должна быть отсортирована по возрастанию и не содержать повторяющихся значений.".to_string());
             }
         }
         Ok(())
     }
 
+    ///
+    /// Валидация: весовое водоизмещение должно строго возрастать с осадкой,
+    /// иначе средняя осадка по водоизмещению определяется неоднозначно.
+    fn validate_displacement_tonnage(&self) -> Result<(), String> {
+        for (index, pair) in self.displacement_tonnage.windows(2).enumerate() {
+            if pair[1] <= pair[0] {
+                return Err(format!(
+                    "Весовое водоизмещение `displacement_tonnage` должно строго возрастать с осадкой: при осадке {} м водоизмещение {} т не больше предыдущего значения {} т.",
+                    self.drafts[index + 1], pair[1], pair[0]
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Валидация: коэффициент общей полноты должен быть больше нуля и не больше единицы.
+    fn validate_block_coefficient(&self) -> Result<(), String> {
+        for (draft, cb) in self.drafts.iter().zip(self.cb.iter()) {
+            if *cb <= 0.0 || *cb > 1.0 {
+                return Err(format!(
+                    "Коэффициент общей полноты `cb` должен быть больше нуля и не больше единицы: при осадке {draft} м задано значение {cb}."
+                ));
+            }
+        }
+        Ok(())
+    }
+
     ///
     /// Валидация: массивы, содержащие данные элементов теоретического чертежа, должны иметь одинаковую длину.
+    /// Необязательные кривые (kb, km, mct1cm, tpc, cb) могут быть не заданы.
     fn validate_same_length(&self) -> Result<(), String> {
         let drafts_len = self.drafts.len();
-        if drafts_len == self.displacement_tonnage.len()
-            && drafts_len == self.x_c.len()
-            && drafts_len == self.waterline_area.len()
-            && drafts_len == self.x_f.len()
-            && drafts_len == self.lmr.len()
-        {
+        if HydrostaticTypeData::all().iter().all(|type_data| {
+            let data = self.data(*type_data);
+            drafts_len == data.len() || (!type_data.is_required() && data.is_empty())
+        }) {
             return Ok(());
         }
         Err("Массивы значений элементов теоретического чертежа имеют разную длину.".to_string())
@@ -178,6 +335,7 @@ impl HydrostaticCurves {
 
     ///
     /// Возвращает данные элементов теоретического чертежа от осадки судна.
+    /// Если кривая не задана или осадка вне диапазона кривых, возвращает None.
     /// Parameters:
     ///     draft - осадка судна,
     ///     type_data - enum HydrostaticTypeData
@@ -187,14 +345,10 @@ impl HydrostaticCurves {
         draft: f64,
         type_data: HydrostaticTypeData,
     ) -> Result<Option<f64>, String> {
-        let data = {
-            match type_data {
-                HydrostaticTypeData::LCB => &self.x_c,
-                HydrostaticTypeData::LCF => &self.x_f,
-                HydrostaticTypeData::WaterlineArea => &self.waterline_area,
-                HydrostaticTypeData::LMR => &self.lmr,
-            }
-        };
+        let data = self.data(type_data);
+        if data.is_empty() {
+            return Ok(None);
+        }
         if draft > *self.drafts.last().unwrap() || draft < *self.drafts.first().unwrap() {
             return Ok(None);
         }
@@ -213,6 +367,109 @@ impl HydrostaticCurves {
         }
     }
 
+    ///
+    /// Весовое водоизмещение при осадке draft.
+    pub fn displacement_tonnage(&self, draft: f64) -> Result<Option<Tons>, String> {
+        Ok(self
+            .get_data_by_draft(draft, HydrostaticTypeData::DisplacementTonnage)?
+            .map(Tons))
+    }
+
+    ///
+    /// Абсцисса центра величины при осадке draft.
+    pub fn lcb(&self, draft: f64) -> Result<Option<Meters>, String> {
+        Ok(self
+            .get_data_by_draft(draft, HydrostaticTypeData::LCB)?
+            .map(Meters))
+    }
+
+    ///
+    /// Абсцисса центра тяжести ватерлинии при осадке draft.
+    pub fn lcf(&self, draft: f64) -> Result<Option<Meters>, String> {
+        Ok(self
+            .get_data_by_draft(draft, HydrostaticTypeData::LCF)?
+            .map(Meters))
+    }
+
+    ///
+    /// Площадь ватерлинии при осадке draft.
+    pub fn waterline_area(&self, draft: f64) -> Result<Option<SquareMeters>, String> {
+        Ok(self
+            .get_data_by_draft(draft, HydrostaticTypeData::WaterlineArea)?
+            .map(SquareMeters))
+    }
+
+    ///
+    /// Продольный метацентрический радиус при осадке draft.
+    pub fn lmr(&self, draft: f64) -> Result<Option<Meters>, String> {
+        Ok(self
+            .get_data_by_draft(draft, HydrostaticTypeData::LMR)?
+            .map(Meters))
+    }
+
+    ///
+    /// Аппликата центра величины при осадке draft.
+    pub fn kb(&self, draft: f64) -> Result<Option<Meters>, String> {
+        Ok(self
+            .get_data_by_draft(draft, HydrostaticTypeData::KB)?
+            .map(Meters))
+    }
+
+    ///
+    /// Аппликата поперечного метацентра при осадке draft.
+    pub fn km(&self, draft: f64) -> Result<Option<Meters>, String> {
+        Ok(self
+            .get_data_by_draft(draft, HydrostaticTypeData::KM)?
+            .map(Meters))
+    }
+
+    ///
+    /// Момент, дифферентующий на 1 см, при осадке draft.
+    pub fn mct1cm(&self, draft: f64) -> Result<Option<TonMetersPerCentimeter>, String> {
+        Ok(self
+            .get_data_by_draft(draft, HydrostaticTypeData::MCT1cm)?
+            .map(TonMetersPerCentimeter))
+    }
+
+    ///
+    /// Число тонн на 1 см осадки при осадке draft.
+    pub fn tpc(&self, draft: f64) -> Result<Option<TonsPerCentimeter>, String> {
+        Ok(self
+            .get_data_by_draft(draft, HydrostaticTypeData::TPC)?
+            .map(TonsPerCentimeter))
+    }
+
+    ///
+    /// Коэффициент общей полноты при осадке draft, безразмерный.
+    pub fn cb(&self, draft: f64) -> Result<Option<f64>, String> {
+        self.get_data_by_draft(draft, HydrostaticTypeData::CB)
+    }
+
+    ///
+    /// Возвращает значения кривой для каждой осадки `drafts`.
+    /// Parameters:
+    ///     type_data - enum HydrostaticTypeData
+    pub fn data(&self, type_data: HydrostaticTypeData) -> &Vec<f64> {
+        match type_data {
+            HydrostaticTypeData::DisplacementTonnage => &self.displacement_tonnage,
+            HydrostaticTypeData::LCB => &self.x_c,
+            HydrostaticTypeData::LCF => &self.x_f,
+            HydrostaticTypeData::WaterlineArea => &self.waterline_area,
+            HydrostaticTypeData::LMR => &self.lmr,
+            HydrostaticTypeData::KB => &self.kb,
+            HydrostaticTypeData::KM => &self.km,
+            HydrostaticTypeData::MCT1cm => &self.mct1cm,
+            HydrostaticTypeData::TPC => &self.tpc,
+            HydrostaticTypeData::CB => &self.cb,
+        }
+    }
+
+    ///
+    /// Осадки, для которых заданы значения кривых [м].
+    pub fn drafts(&self) -> &Vec<f64> {
+        &self.drafts
+    }
+
     pub fn max_draft(&self) -> f64 {
         *self.drafts.last().unwrap()
     }
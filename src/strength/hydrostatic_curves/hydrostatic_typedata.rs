@@ -1,14 +1,77 @@
 ///
 /// Тип данных элементов теоретического чертежа.
 /// Paramenters:
+///     DisplacementTonnage: весовое водоизмещение,
 ///     LCB: абсцисса центра велечины,
 ///     WaterlineArea: площадь ватерлинии,
 ///     LCF: абсцисса центра тяжести ватерлиниии,
-///     LMR - продольный(большой) метацентрический радиус.
-#[derive(Debug)]
+///     LMR - продольный(большой) метацентрический радиус,
+///     KB - аппликата центра величины,
+///     KM - аппликата поперечного метацентра,
+///     MCT1cm - момент, дифферентующий на 1 см,
+///     TPC - число тонн на 1 см осадки,
+///     CB - коэффициент общей полноты.
+/// Названия типов - принятые в теории корабля сокращения.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum HydrostaticTypeData {
+    DisplacementTonnage,
     LCB,
     LCF,
     WaterlineArea,
     LMR,
+    KB,
+    KM,
+    MCT1cm,
+    TPC,
+    CB,
+}
+
+impl HydrostaticTypeData {
+    ///
+    /// Все типы данных элементов теоретического чертежа.
+    pub fn all() -> [HydrostaticTypeData; 10] {
+        [
+            HydrostaticTypeData::DisplacementTonnage,
+            HydrostaticTypeData::LCB,
+            HydrostaticTypeData::LCF,
+            HydrostaticTypeData::WaterlineArea,
+            HydrostaticTypeData::LMR,
+            HydrostaticTypeData::KB,
+            HydrostaticTypeData::KM,
+            HydrostaticTypeData::MCT1cm,
+            HydrostaticTypeData::TPC,
+            HydrostaticTypeData::CB,
+        ]
+    }
+
+    ///
+    /// Название кривой во входных файлах json и csv.
+    pub fn name(&self) -> &'static str {
+        match self {
+            HydrostaticTypeData::DisplacementTonnage => "displacement_tonnage",
+            HydrostaticTypeData::LCB => "x_c",
+            HydrostaticTypeData::LCF => "x_f",
+            HydrostaticTypeData::WaterlineArea => "waterline_area",
+            HydrostaticTypeData::LMR => "lmr",
+            HydrostaticTypeData::KB => "kb",
+            HydrostaticTypeData::KM => "km",
+            HydrostaticTypeData::MCT1cm => "mct1cm",
+            HydrostaticTypeData::TPC => "tpc",
+            HydrostaticTypeData::CB => "cb",
+        }
+    }
+
+    ///
+    /// Обязательная кривая, без которой гидростатические кривые не могут быть заданы.
+    pub fn is_required(&self) -> bool {
+        matches!(
+            self,
+            HydrostaticTypeData::DisplacementTonnage
+                | HydrostaticTypeData::LCB
+                | HydrostaticTypeData::LCF
+                | HydrostaticTypeData::WaterlineArea
+                | HydrostaticTypeData::LMR
+        )
+    }
 }
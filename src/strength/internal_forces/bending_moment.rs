@@ -1,5 +1,8 @@
 use super::{share_force::ShareForce, with_correction};
-use crate::strength::ship::{ship_dimensions::ShipDimensions, spatium_functions::SpatiumFunctions};
+use crate::{
+    core::system_of_units::TonMeters,
+    strength::ship::{ship_dimensions::ShipDimensions, spatium_functions::SpatiumFunctions},
+};
 
 ///
 /// Изгибающий момент.
@@ -35,4 +38,13 @@ impl BendingMoment {
     pub fn bending_moment_with_correction(&self) -> Option<&SpatiumFunctions> {
         self.bending_moment_with_correction_.as_ref()
     }
+
+    ///
+    /// Наибольший по модулю изгибающий момент (с поправкой, если он построен).
+    pub fn max(&self) -> Option<TonMeters> {
+        self.bending_moment_with_correction()
+            .unwrap_or(self.bending_momant())
+            .max()
+            .map(TonMeters)
+    }
 }
@@ -0,0 +1,166 @@
+use tracing::instrument;
+
+use super::bending_moment::BendingMoment;
+use crate::{
+    core::{
+        physical_constants::STEEL_YOUNG_MODULUS,
+        round::Round,
+        system_of_units::{KiloNewtonMeters, TonMeters},
+    },
+    cross_section_properties::cross_sections::CrossSections,
+    strength::{
+        buoyancy_intensity::side_drafts::SideDrafts,
+        ship::{spatium_function::SpatiumFunction, spatium_functions::SpatiumFunctions},
+    },
+};
+
+///
+/// Упругая линия (прогиб) корпуса судна как балки переменного сечения.
+/// Определяется двойным интегрированием кривизны w''(x) = -M(x) / (E * I(x)) методом трапеций
+/// с граничными условиями w = 0 на кормовом и носовом перпендикулярах.
+/// Знак прогиба: w > 0 - перегиб (hogging), середина судна выше прямой, соединяющей перпендикуляры,
+/// w < 0 - прогиб (sagging).
+/// Parameters:
+///     deflection - прогиб в начале и в конце каждой шпации [мм].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deflection {
+    deflection: SpatiumFunctions,
+}
+
+impl Deflection {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     bending_moment - эпюра изгибающих моментов [т*м],
+    ///     cross_sections - поперечные сечения корпуса.
+    #[instrument(skip_all, err, target = "Deflection::new")]
+    pub fn new(
+        bending_moment: &SpatiumFunctions,
+        cross_sections: &CrossSections,
+    ) -> Result<Self, String> {
+        let spatiums = bending_moment.as_ref();
+        if spatiums.is_empty() {
+            return Err("Эпюра изгибающих моментов не задана.".to_string());
+        }
+        let mut abscissas = vec![spatiums[0].x1()];
+        let mut moments = vec![spatiums[0].f_x1()];
+        for spatium in spatiums {
+            abscissas.push(spatium.x2());
+            moments.push(spatium.f_x2());
+        }
+        let mut curvatures = vec![];
+        for (x, moment) in abscissas.iter().zip(moments) {
+            // Изгибающий момент [т*м] -> [Н*м].
+            let moment = KiloNewtonMeters::from(TonMeters(moment)).value() * 1000.0;
+            curvatures
+                .push(-moment / (STEEL_YOUNG_MODULUS * cross_sections.moment_of_inertia(*x)?));
+        }
+        let integral = |values: &Vec<f64>| -> Vec<f64> {
+            let mut result = vec![0.0];
+            for i in 1..values.len() {
+                let dx = abscissas[i] - abscissas[i - 1];
+                result.push(result[i - 1] + (values[i - 1] + values[i]) / 2.0 * dx);
+            }
+            result
+        };
+        let deflection = integral(&integral(&curvatures));
+        let (x_aft, x_nose) = (abscissas[0], *abscissas.last().unwrap());
+        let w_nose = *deflection.last().unwrap();
+        // Прогиб [м] -> [мм] с учетом граничного условия w(x_nose) = 0.
+        let deflection: Vec<f64> = deflection
+            .iter()
+            .zip(abscissas.iter())
+            .map(|(w, x)| (w - w_nose * (x - x_aft) / (x_nose - x_aft)) * 1000.0)
+            .collect();
+        Ok(Deflection {
+            deflection: SpatiumFunctions::new(
+                spatiums
+                    .iter()
+                    .enumerate()
+                    .map(|(i, spatium)| {
+                        SpatiumFunction::new(
+                            spatium.id(),
+                            spatium.x1(),
+                            spatium.x2(),
+                            deflection[i],
+                            deflection[i + 1],
+                        )
+                    })
+                    .collect(),
+            ),
+        })
+    }
+
+    ///
+    /// Вспомогательный конструктор.
+    /// Используется эпюра изгибающих моментов с поправкой, если она построена.
+    pub fn from_bending_moment(
+        bending_moment: &BendingMoment,
+        cross_sections: &CrossSections,
+    ) -> Result<Self, String> {
+        Deflection::new(
+            bending_moment
+                .bending_moment_with_correction()
+                .unwrap_or(bending_moment.bending_momant()),
+            cross_sections,
+        )
+    }
+
+    ///
+    /// Прогиб в сечении с абсциссой x [мм]. Между концами шпации прогиб интерполируется линейно.
+    pub fn deflection_at(&self, x: f64) -> Result<f64, String> {
+        self.deflection.value_at(x).ok_or(format!(
+            "Абсцисса {x} м находится вне длины судна между перпендикулярами."
+        ))
+    }
+
+    ///
+    /// Прогиб на миделе [мм], положительный при перегибе.
+    pub fn midship_deflection(&self) -> Result<f64, String> {
+        self.deflection_at(0.0)
+    }
+
+    ///
+    /// Наибольший прогиб при перегибе (hogging) [мм]. Равен нулю, если перегиба нет.
+    pub fn max_hogging(&self) -> f64 {
+        self.nodes().fold(0.0, f64::max)
+    }
+
+    ///
+    /// Наибольший по модулю прогиб при прогибе (sagging) [мм]. Равен нулю, если прогиба нет.
+    pub fn max_sagging(&self) -> f64 {
+        self.nodes().fold(0.0, |max, w| max.max(-w))
+    }
+
+    ///
+    /// Осадки по маркам углубления на перпендикулярах и на миделе с учетом прогиба корпуса [м].
+    /// Осадки на перпендикулярах не изменяются, осадка на миделе при перегибе меньше
+    /// средней осадки на величину прогиба, при прогибе - больше.
+    /// Parameters:
+    ///     aft_draft, nose_draft - осадки на кормовом и носовом перпендикулярах [м].
+    pub fn draft_marks(&self, aft_draft: f64, nose_draft: f64) -> Result<SideDrafts, String> {
+        let mid = (aft_draft + nose_draft) / 2.0 - self.midship_deflection()? / 1000.0;
+        Ok(SideDrafts {
+            aft: aft_draft.my_round(3),
+            mid: mid.my_round(3),
+            nose: nose_draft.my_round(3),
+        })
+    }
+
+    ///
+    /// Средняя осадка с учетом прогиба корпуса ("среднее средних") [м]:
+    /// T = (T_aft + 6 * T_mid + T_nose) / 8.
+    /// Parameters:
+    ///     aft_draft, nose_draft - осадки на кормовом и носовом перпендикулярах [м].
+    pub fn quarter_mean_draft(&self, aft_draft: f64, nose_draft: f64) -> Result<f64, String> {
+        let mid = (aft_draft + nose_draft) / 2.0 - self.midship_deflection()? / 1000.0;
+        Ok((aft_draft + 6.0 * mid + nose_draft) / 8.0)
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = f64> + '_ {
+        self.deflection
+            .as_ref()
+            .iter()
+            .flat_map(|spatium| [spatium.f_x1(), spatium.f_x2()])
+    }
+}
@@ -3,7 +3,10 @@ use crate::strength::ship::spatium_function::SpatiumFunction;
 use super::ship::{ship_dimensions::ShipDimensions, spatium_functions::SpatiumFunctions};
 
 pub mod bending_moment;
+pub mod deflection;
 pub mod share_force;
+pub mod shear_stress;
+pub mod wave_bending_moment;
 
 ///
 /// Разносит невязки линейно по шпациям.
@@ -26,8 +29,8 @@ fn with_correction(
     let nose_value = spatium_functions.last().unwrap().f_x2();
     let mut f_x1 = 0.0;
     let mut internal_force_with_correct = vec![];
-    let mut x = ship_dimensions.length_spatium();
-    let lbp = ship_dimensions.lbp();
+    let mut x = ship_dimensions.length_spatium().value();
+    let lbp = ship_dimensions.lbp().value();
     for s_f in spatium_functions.as_ref() {
         let f_x2 = s_f.f_x2() - nose_value * x / lbp;
         internal_force_with_correct.push(SpatiumFunction::new(
@@ -38,7 +41,7 @@ fn with_correction(
             f_x2,
         ));
         f_x1 = f_x2;
-        x += ship_dimensions.length_spatium();
+        x += ship_dimensions.length_spatium().value();
     }
     SpatiumFunctions::new(internal_force_with_correct)
 }
@@ -1,6 +1,9 @@
-use crate::strength::{
-    load::total_shipload::TotalShipload,
-    ship::{ship_dimensions::ShipDimensions, spatium_functions::SpatiumFunctions},
+use crate::{
+    core::system_of_units::TonsForce,
+    strength::{
+        load::total_shipload::TotalShipload,
+        ship::{ship_dimensions::ShipDimensions, spatium_functions::SpatiumFunctions},
+    },
 };
 
 use super::with_correction;
@@ -39,4 +42,13 @@ impl ShareForce {
     pub fn share_force_with_correction(&self) -> Option<&SpatiumFunctions> {
         self.share_force_with_correction_.as_ref()
     }
+
+    ///
+    /// Наибольшая по модулю перерезывающая сила (с поправкой, если она построена).
+    pub fn max(&self) -> Option<TonsForce> {
+        self.share_force_with_correction()
+            .unwrap_or(self.share_force())
+            .max()
+            .map(TonsForce)
+    }
 }
@@ -0,0 +1,81 @@
+use tracing::instrument;
+
+use super::share_force::ShareForce;
+use crate::{
+    core::system_of_units::{MegaPascals, TonsPerSquareMeter},
+    cross_section_properties::cross_sections::CrossSections,
+    strength::ship::{spatium_function::SpatiumFunction, spatium_functions::SpatiumFunctions},
+};
+
+///
+/// Наибольшие касательные напряжения в обшивке борта и продольных переборках по длине судна.
+/// Касательное напряжение на нейтральной оси сечения: tau = N * S / (I * t), где
+///     N - перерезывающая сила,
+///     S - статический момент части сечения выше нейтральной оси относительно нейтральной оси,
+///     I - момент инерции сечения,
+///     t - суммарная толщина элементов, пересекающих нейтральную ось.
+/// Знак напряжения совпадает со знаком перерезывающей силы.
+/// Parameters:
+///     shear_stress - касательное напряжение в начале и в конце каждой шпации [МПа].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShearStress {
+    shear_stress: SpatiumFunctions,
+}
+
+impl ShearStress {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     share_force - эпюра перерезывающих сил [т],
+    ///     cross_sections - поперечные сечения корпуса.
+    #[instrument(skip_all, err, target = "ShearStress::new")]
+    pub fn new(
+        share_force: &SpatiumFunctions,
+        cross_sections: &CrossSections,
+    ) -> Result<Self, String> {
+        let stress = |share_force: f64, x: f64| -> Result<f64, String> {
+            let stress = share_force * cross_sections.shear_stress_factor(x)?;
+            Ok(MegaPascals::from(TonsPerSquareMeter(stress)).value())
+        };
+        let mut shear_stress = vec![];
+        for spatium in share_force.as_ref() {
+            shear_stress.push(SpatiumFunction::new(
+                spatium.id(),
+                spatium.x1(),
+                spatium.x2(),
+                stress(spatium.f_x1(), spatium.x1())?,
+                stress(spatium.f_x2(), spatium.x2())?,
+            ));
+        }
+        Ok(ShearStress {
+            shear_stress: SpatiumFunctions::new(shear_stress),
+        })
+    }
+
+    ///
+    /// Вспомогательный конструктор.
+    /// Используется эпюра перерезывающих сил с поправкой, если она построена.
+    pub fn from_share_force(
+        share_force: &ShareForce,
+        cross_sections: &CrossSections,
+    ) -> Result<Self, String> {
+        ShearStress::new(
+            share_force
+                .share_force_with_correction()
+                .unwrap_or(share_force.share_force()),
+            cross_sections,
+        )
+    }
+
+    ///
+    /// Касательное напряжение в начале и в конце каждой шпации [МПа].
+    pub fn shear_stress(&self) -> &SpatiumFunctions {
+        &self.shear_stress
+    }
+
+    ///
+    /// Наибольшее по модулю касательное напряжение.
+    pub fn max(&self) -> MegaPascals {
+        MegaPascals(self.shear_stress.max().unwrap_or(0.0))
+    }
+}
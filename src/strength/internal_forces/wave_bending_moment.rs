@@ -0,0 +1,106 @@
+use tracing::instrument;
+
+use crate::{
+    core::system_of_units::{KiloNewtonMeters, Meters, TonMeters},
+    strength::ship::{
+        ship_dimensions::ShipDimensions, spatium_function::SpatiumFunction,
+        spatium_functions::SpatiumFunctions,
+    },
+};
+
+///
+/// Волновой изгибающий момент по IACS UR S11:
+///     M_wh = +190 * m * C * L^2 * B * Cb * 10^-3 [кН*м] - на перегиб,
+///     M_ws = -110 * m * C * L^2 * B * (Cb + 0.7) * 10^-3 [кН*м] - на прогиб, где
+///     C - волновой коэффициент, зависящий от длины судна L,
+///     Cb - коэффициент общей полноты (не менее 0.6),
+///     m - коэффициент распределения по длине: 0 на перпендикулярах, 1 в районе от 0.4L до 0.65L
+///     от кормового перпендикуляра, между ними изменяется линейно.
+/// Parameters:
+///     hogging - волновой момент на перегиб (M > 0) [т*м],
+///     sagging - волновой момент на прогиб (M < 0) [т*м].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaveBendingMoment {
+    hogging: SpatiumFunctions,
+    sagging: SpatiumFunctions,
+}
+
+impl WaveBendingMoment {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     ship_dimensions - размерения судна, ширина судна должна быть задана.
+    #[instrument(skip_all, err, target = "WaveBendingMoment::new")]
+    pub fn new(ship_dimensions: ShipDimensions) -> Result<Self, String> {
+        let Some(Meters(breadth)) = ship_dimensions.breadth() else {
+            return Err(
+                "Для расчета волнового изгибающего момента необходимо задать ширину судна (breadth)."
+                    .to_string(),
+            );
+        };
+        let lbp = ship_dimensions.lbp().value();
+        let wave_coefficient = WaveBendingMoment::wave_coefficient(lbp)?;
+        let cb = ship_dimensions.completeness_coefficient().max(0.6);
+        let base = wave_coefficient * lbp.powi(2) * breadth * 1.0e-3;
+        let hogging_moment = TonMeters::from(KiloNewtonMeters(190.0 * base * cb)).value();
+        let sagging_moment = TonMeters::from(KiloNewtonMeters(-110.0 * base * (cb + 0.7))).value();
+        let diagram = |moment: f64| -> SpatiumFunctions {
+            let factor = |x: f64| {
+                WaveBendingMoment::distribution_factor((x - ship_dimensions.coordinate_aft()) / lbp)
+            };
+            SpatiumFunctions::new(
+                (0..ship_dimensions.number_spatiums())
+                    .map(|id| {
+                        let x1 = ship_dimensions.spatium_start_coordinate(id);
+                        let x2 = ship_dimensions.spatium_end_coordinate(id);
+                        SpatiumFunction::new(id, x1, x2, moment * factor(x1), moment * factor(x2))
+                    })
+                    .collect(),
+            )
+        };
+        Ok(WaveBendingMoment {
+            hogging: diagram(hogging_moment),
+            sagging: diagram(sagging_moment),
+        })
+    }
+
+    ///
+    /// Волновой коэффициент C для длины судна L [м], 90 <= L <= 500.
+    pub fn wave_coefficient(lbp: f64) -> Result<f64, String> {
+        match lbp {
+            lbp if (90.0..=300.0).contains(&lbp) => {
+                Ok(10.75 - ((300.0 - lbp) / 100.0).powf(1.5))
+            }
+            lbp if lbp > 300.0 && lbp <= 350.0 => Ok(10.75),
+            lbp if lbp > 350.0 && lbp <= 500.0 => Ok(10.75 - ((lbp - 350.0) / 150.0).powf(1.5)),
+            _ => Err(format!(
+                "Волновой изгибающий момент определяется для судов длиной от 90 до 500 м, длина судна {lbp} м."
+            )),
+        }
+    }
+
+    ///
+    /// Коэффициент распределения волнового момента по длине судна.
+    /// Parameters:
+    ///     ratio - отношение расстояния от кормового перпендикуляра к длине судна.
+    pub fn distribution_factor(ratio: f64) -> f64 {
+        match ratio {
+            ratio if ratio <= 0.0 || ratio >= 1.0 => 0.0,
+            ratio if ratio < 0.4 => ratio / 0.4,
+            ratio if ratio <= 0.65 => 1.0,
+            ratio => (1.0 - ratio) / 0.35,
+        }
+    }
+
+    ///
+    /// Волновой момент на перегиб (M > 0) [т*м].
+    pub fn hogging(&self) -> &SpatiumFunctions {
+        &self.hogging
+    }
+
+    ///
+    /// Волновой момент на прогиб (M < 0) [т*м].
+    pub fn sagging(&self) -> &SpatiumFunctions {
+        &self.sagging
+    }
+}
@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+
+use super::lightweight::Lightweight;
+use crate::core::{data_file::DataFile, round::Round, schema_version::SchemaVersion};
+
+///
+/// Версии формата файла опыта кренования:
+///     1 - поля displacement, km, pendulum_length, shifts и необязательные поля free_surface_moment, corrections.
+pub const INCLINING_EXPERIMENT_SCHEMA: SchemaVersion =
+    SchemaVersion::new("файла опыта кренования", 1, &[]);
+
+///
+/// Перемещение опытного груза при креновании.
+/// Parameters:
+///     weight - масса перемещаемого груза [т],
+///     distance - поперечное перемещение груза относительно исходного положения,
+///         положительное на правый борт [м],
+///     deflections - отклонения отвесов от исходного положения, положительные на правый борт [м].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct WeightShift {
+    weight: f64,
+    distance: f64,
+    deflections: Vec<f64>,
+}
+
+impl WeightShift {
+    ///
+    /// Кренящий момент [т*м].
+    fn heeling_moment(&self) -> f64 {
+        self.weight * self.distance
+    }
+
+    ///
+    /// Тангенс угла крена по среднему отклонению отвесов.
+    fn tan_heel(&self, pendulum_length: f64) -> f64 {
+        let deflection = self.deflections.iter().sum::<f64>() / self.deflections.len() as f64;
+        deflection / pendulum_length
+    }
+}
+
+///
+/// Масса, находящаяся на судне во время кренования и не входящая в массу судна порожнем
+/// (опытные грузы, балласт, люди и т.д.).
+/// Parameters:
+///     name - наименование,
+///     weight - масса [т],
+///     vcg - аппликата центра тяжести от основной плоскости [м].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct WeightCorrection {
+    name: String,
+    weight: f64,
+    vcg: f64,
+}
+
+///
+/// Опыт кренования судна.
+/// Определяет метацентрическую высоту по отклонениям отвесов при перемещении опытных грузов:
+/// GM = w * d / (D * tg(θ)), tg(θ) = a / l,
+/// где a - отклонение отвеса, l - длина отвеса.
+/// Метацентрическая высота определяется по всем перемещениям грузов методом наименьших квадратов.
+/// Parameters:
+///     displacement - водоизмещение судна во время опыта [т],
+///     km - аппликата поперечного метацентра при осадке во время опыта [м],
+///     pendulum_length - длина отвесов [м],
+///     free_surface_moment - момент инерции свободных поверхностей жидких грузов во время опыта [т*м],
+///     shifts - перемещения опытных грузов,
+///     corrections - массы, не входящие в массу судна порожнем.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct IncliningExperiment {
+    displacement: f64,
+    km: f64,
+    pendulum_length: f64,
+    #[serde(default)]
+    free_surface_moment: f64,
+    shifts: Vec<WeightShift>,
+    #[serde(default)]
+    corrections: Vec<WeightCorrection>,
+}
+
+impl IncliningExperiment {
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Версия формата проверяется до разбора содержимого (INCLINING_EXPERIMENT_SCHEMA).
+    #[instrument(skip_all, err, target = "IncliningExperiment::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let value: Value = DataFile::new(file_path.clone()).deserialize()?;
+        let value = INCLINING_EXPERIMENT_SCHEMA
+            .upgrade(value)
+            .map_err(|err| format!("Файл {file_path}: {err}"))?;
+        let experiment: IncliningExperiment =
+            serde_json::from_value(value).map_err(|err| err.to_string())?;
+        experiment.validate_input_data()
+    }
+
+    ///
+    /// Валидация входных данных.
+    fn validate_input_data(self) -> Result<IncliningExperiment, String> {
+        if self.displacement <= 0.0 {
+            return Err("Водоизмещение судна во время опыта должно быть больше нуля.".to_string());
+        }
+        if self.pendulum_length <= 0.0 {
+            return Err("Длина отвесов должна быть больше нуля.".to_string());
+        }
+        if self.free_surface_moment < 0.0 {
+            return Err(
+                "Момент инерции свободных поверхностей не может быть отрицательным.".to_string(),
+            );
+        }
+        if self.shifts.is_empty() {
+            return Err("Перемещения опытных грузов не заданы.".to_string());
+        }
+        for (i, shift) in self.shifts.iter().enumerate() {
+            if shift.weight <= 0.0 {
+                return Err(format!(
+                    "Перемещение {i}: масса опытного груза должна быть больше нуля."
+                ));
+            }
+            if shift.deflections.is_empty() {
+                return Err(format!("Перемещение {i}: отклонения отвесов не заданы."));
+            }
+        }
+        let tan_sum: f64 = self
+            .shifts
+            .iter()
+            .map(|shift| shift.tan_heel(self.pendulum_length).powi(2))
+            .sum();
+        if tan_sum == 0.0 {
+            return Err(
+                "Отклонения отвесов при перемещении опытных грузов равны нулю.".to_string(),
+            );
+        }
+        let corrections: f64 = self.corrections.iter().map(|item| item.weight).sum();
+        if corrections >= self.displacement {
+            return Err(
+                "Сумма масс, не входящих в массу судна порожнем, должна быть меньше водоизмещения судна во время опыта."
+                    .to_string(),
+            );
+        }
+        Ok(self)
+    }
+
+    ///
+    /// Метацентрическая высота судна во время опыта (с учетом влияния свободных поверхностей) [м].
+    pub fn metacentric_height(&self) -> f64 {
+        let (moments, tans): (f64, f64) = self
+            .shifts
+            .iter()
+            .map(|shift| {
+                let tan_heel = shift.tan_heel(self.pendulum_length);
+                (shift.heeling_moment() * tan_heel, tan_heel.powi(2))
+            })
+            .fold((0.0, 0.0), |(a, b), (m, t)| (a + m, b + t));
+        moments / (self.displacement * tans)
+    }
+
+    ///
+    /// Аппликата центра тяжести судна во время опыта [м].
+    /// KG = KM - GM - Mfs / D.
+    pub fn vcg(&self) -> f64 {
+        self.km - self.metacentric_height() - self.free_surface_moment / self.displacement
+    }
+
+    ///
+    /// Масса судна порожнем с аппликатой центра тяжести, полученными по результатам опыта.
+    pub fn lightweight(&self) -> Lightweight {
+        let (weight, moment) = self
+            .corrections
+            .iter()
+            .fold((0.0, 0.0), |(weight, moment), item| {
+                (weight + item.weight, moment + item.weight * item.vcg)
+            });
+        let lightweight = self.displacement - weight;
+        let vcg = (self.displacement * self.vcg() - moment) / lightweight;
+        Lightweight::new(lightweight.my_round(2)).with_vcg(vcg.my_round(3))
+    }
+}
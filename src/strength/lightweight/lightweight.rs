@@ -1,30 +1,84 @@
 use serde::Deserialize;
 use tracing::instrument;
 
-use crate::core::json_file::JsonFile;
+use crate::core::{data_file::DataFile, system_of_units::Tons};
 
 ///
 /// Масса судна, когда оно было построено на верфи.
 ///Не включает массу любого расходного материала, такого как топливо, вода, масло или другие расходные материалы.
+/// lightweight_lcg - абсцисса центра тяжести судна порожнем относительно мидель шпангоута [м],
+/// если не задана, определяется принятым методом распределения массы по длине судна,
+/// lightweight_vcg - аппликата центра тяжести судна порожнем от основной плоскости [м],
+/// lightweight_tcg - ордината центра тяжести судна порожнем от диаметральной плоскости [м].
 #[derive(Deserialize, Debug, Clone, Copy)]
 pub struct Lightweight {
     lightweight: f64,
+    #[serde(default)]
+    lightweight_lcg: Option<f64>,
+    #[serde(default)]
+    lightweight_vcg: Option<f64>,
+    #[serde(default)]
+    lightweight_tcg: Option<f64>,
 }
 
 impl Lightweight {
     pub fn new(lightweight: f64) -> Self {
-        Lightweight { lightweight }
+        Lightweight {
+            lightweight,
+            lightweight_lcg: None,
+            lightweight_vcg: None,
+            lightweight_tcg: None,
+        }
     }
 
-    /// Create the object from json file.
-    #[instrument(skip_all, err, target = "Lightweight::from_json_file")]
-    pub fn from_json_file(file_path: String) -> Result<Self, String> {
-        let json = JsonFile::new(file_path);
-        let content = json.content().map_err(|err| err.to_string())?;
-        serde_json::from_reader(content).map_err(|err| err.to_string())
+    ///
+    /// Задает абсциссу центра тяжести судна порожнем [м].
+    pub fn with_lcg(mut self, lcg: f64) -> Self {
+        self.lightweight_lcg = Some(lcg);
+        self
     }
 
-    pub fn lightweight(&self) -> f64 {
-        self.lightweight
+    ///
+    /// Задает аппликату центра тяжести судна порожнем [м].
+    pub fn with_vcg(mut self, vcg: f64) -> Self {
+        self.lightweight_vcg = Some(vcg);
+        self
+    }
+
+    ///
+    /// Задает ординату центра тяжести судна порожнем [м].
+    pub fn with_tcg(mut self, tcg: f64) -> Self {
+        self.lightweight_tcg = Some(tcg);
+        self
+    }
+
+    /// Create the object from json, toml or yaml file.
+    #[instrument(skip_all, err, target = "Lightweight::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        DataFile::new(file_path).deserialize()
+    }
+
+    ///
+    /// Масса судна порожнем.
+    pub fn lightweight(&self) -> Tons {
+        Tons(self.lightweight)
+    }
+
+    ///
+    /// Абсцисса центра тяжести судна порожнем [м].
+    pub fn lcg(&self) -> Option<f64> {
+        self.lightweight_lcg
+    }
+
+    ///
+    /// Аппликата центра тяжести судна порожнем [м].
+    pub fn vcg(&self) -> Option<f64> {
+        self.lightweight_vcg
+    }
+
+    ///
+    /// Ордината центра тяжести судна порожнем [м].
+    pub fn tcg(&self) -> Option<f64> {
+        self.lightweight_tcg
     }
 }
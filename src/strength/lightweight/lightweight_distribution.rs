@@ -0,0 +1,268 @@
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use super::{lightweight::Lightweight, lightweight_intensity::LightweightIntensity};
+use crate::{core::data_file::DataFile, strength::ship::ship_dimensions::ShipDimensions};
+
+///
+/// Статья нагрузки массы судна порожнем (корпус, механизмы, оборудование и т.д.).
+/// Масса статьи распределяется по линейному закону в пределах её протяженности
+/// так, чтобы сохранялась абсцисса её центра тяжести.
+/// Parameters:
+///     name - наименование статьи,
+///     weight - масса [т],
+///     lcg - абсцисса центра тяжести относительно мидель шпангоута [м],
+///     start, end - абсциссы начала и конца протяженности статьи относительно мидель шпангоута [м].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LightweightItem {
+    name: String,
+    weight: f64,
+    lcg: f64,
+    start: f64,
+    end: f64,
+}
+
+impl LightweightItem {
+    ///
+    /// Валидация статьи нагрузки.
+    fn validate(&self, ship_dimensions: &ShipDimensions) -> Result<(), String> {
+        if self.weight <= 0.0 {
+            return Err(format!(
+                "Статья нагрузки \"{}\": масса должна быть больше нуля.",
+                self.name
+            ));
+        }
+        if self.start >= self.end {
+            return Err(format!(
+                "Статья нагрузки \"{}\": начало протяженности должно быть меньше конца.",
+                self.name
+            ));
+        }
+        if self.start < ship_dimensions.coordinate_aft()
+            || self.end > ship_dimensions.coordinate_nose()
+        {
+            return Err(format!(
+                "Статья нагрузки \"{}\" выходит за пределы длины судна между перпендикулярами.",
+                self.name
+            ));
+        }
+        let length = self.end - self.start;
+        if (self.lcg - (self.start + self.end) / 2.0).abs() > length / 6.0 {
+            return Err(format!(
+                "Статья нагрузки \"{}\": центр тяжести должен находиться в средней трети протяженности статьи.",
+                self.name
+            ));
+        }
+        Ok(())
+    }
+
+    ///
+    /// Интенсивность массы статьи в начале и конце её протяженности [т/м].
+    fn intensities(&self) -> (f64, f64) {
+        let length = self.end - self.start;
+        let eccentricity = self.lcg - (self.start + self.end) / 2.0;
+        let mean = self.weight / length;
+        let slope = 12.0 * self.weight * eccentricity / length.powi(3);
+        (mean - slope * length / 2.0, mean + slope * length / 2.0)
+    }
+}
+
+///
+/// Метод распределения массы судна порожнем по длине судна.
+///     trapezoidal - три трапециевидные зоны [Прочность корабля Курдюмов А.А.] (по умолчанию),
+///     parabolic_rectangular - прямоугольник (половина массы) и парабола (половина массы),
+///     biles - эпюра Бильса: прямоугольник в средней трети и трапеции в оконечностях,
+///     coefficient_table - относительные интенсивности массы для каждой шпации, заданные пользователем,
+///     items - сумма статей нагрузки с заданными массами, центрами тяжести и протяженностью.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum LightweightDistribution {
+    #[default]
+    Trapezoidal,
+    ParabolicRectangular,
+    Biles,
+    CoefficientTable {
+        coefficients: Vec<f64>,
+    },
+    Items {
+        items: Vec<LightweightItem>,
+    },
+}
+
+///
+/// Метод распределения массы судна порожнем, заданный в файле с основными данными о судне.
+#[derive(Deserialize)]
+struct LightweightDistributionInput {
+    #[serde(default)]
+    lightweight_distribution: LightweightDistribution,
+}
+
+impl LightweightDistribution {
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Если метод распределения в файле не задан, возвращает LightweightDistribution::Trapezoidal.
+    #[instrument(skip_all, err, target = "LightweightDistribution::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let input: LightweightDistributionInput = DataFile::new(file_path).deserialize()?;
+        Ok(input.lightweight_distribution)
+    }
+
+    ///
+    /// Возвращает массы судна порожнем, приходящиеся на каждую шпацию [т],
+    /// и абсциссу центра тяжести, которой должно соответствовать распределение [м].
+    /// Если абсцисса центра тяжести судна порожнем не задана, для распределения по статьям нагрузки
+    /// принимается абсцисса центра тяжести статей, для остальных методов - None.
+    /// Parameters:
+    ///     ship_dimensions - размерения судна,
+    ///     lw - масса судна порожнем.
+    pub fn spatium_masses(
+        &self,
+        ship_dimensions: &ShipDimensions,
+        lw: &Lightweight,
+    ) -> Result<(Vec<f64>, Option<f64>), String> {
+        let weight = lw.lightweight().value();
+        let lbp = ship_dimensions.lbp().value();
+        let spatiums = 0..ship_dimensions.number_spatiums();
+        let bounds = |id: u64| {
+            (
+                ship_dimensions.spatium_start_coordinate(id),
+                ship_dimensions.spatium_end_coordinate(id),
+            )
+        };
+        match self {
+            LightweightDistribution::Trapezoidal => {
+                let masses = LightweightIntensity::from_ship_input_data(*ship_dimensions, *lw)
+                    .lightweight_intensity()
+                    .as_ref()
+                    .iter()
+                    .map(|spatium| spatium.integral())
+                    .collect::<Vec<f64>>();
+                // Сумма относительных интенсивностей зон не равна точно количеству шпаций,
+                // поэтому распределение приводится к массе судна порожнем.
+                let sum: f64 = masses.iter().sum();
+                Ok((
+                    masses.iter().map(|mass| mass * weight / sum).collect(),
+                    lw.lcg(),
+                ))
+            }
+            LightweightDistribution::ParabolicRectangular => {
+                // q(x) = 0.5 * W / L + 0.75 * W / L * (1 - (2x / L)^2).
+                let primitive = |x: f64| {
+                    0.5 * weight / lbp * x
+                        + 0.75 * weight / lbp * (x - 4.0 * x.powi(3) / (3.0 * lbp.powi(2)))
+                };
+                let masses = spatiums
+                    .map(|id| {
+                        let (start, end) = bounds(id);
+                        primitive(end) - primitive(start)
+                    })
+                    .collect();
+                Ok((masses, lw.lcg()))
+            }
+            LightweightDistribution::Biles => {
+                let aft = ship_dimensions.coordinate_aft();
+                let nose = ship_dimensions.coordinate_nose();
+                let points = [
+                    (aft, 0.75 * weight / lbp),
+                    (aft + lbp / 3.0, 1.125 * weight / lbp),
+                    (nose - lbp / 3.0, 1.125 * weight / lbp),
+                    (nose, 0.75 * weight / lbp),
+                ];
+                let masses = spatiums
+                    .map(|id| {
+                        let (start, end) = bounds(id);
+                        polyline_integral(&points, start, end)
+                    })
+                    .collect();
+                Ok((masses, lw.lcg()))
+            }
+            LightweightDistribution::CoefficientTable { coefficients } => {
+                if coefficients.len() as u64 != ship_dimensions.number_spatiums() {
+                    return Err(format!(
+                        "Количество коэффициентов распределения массы судна порожнем ({}) не равно количеству шпаций ({}).",
+                        coefficients.len(),
+                        ship_dimensions.number_spatiums()
+                    ));
+                }
+                if coefficients.iter().any(|coefficient| *coefficient < 0.0) {
+                    return Err(
+                        "Коэффициенты распределения массы судна порожнем не могут быть отрицательными."
+                            .to_string(),
+                    );
+                }
+                let sum: f64 = coefficients.iter().sum();
+                if sum <= 0.0 {
+                    return Err(
+                        "Сумма коэффициентов распределения массы судна порожнем должна быть больше нуля."
+                            .to_string(),
+                    );
+                }
+                let masses = coefficients
+                    .iter()
+                    .map(|coefficient| weight * coefficient / sum)
+                    .collect();
+                Ok((masses, lw.lcg()))
+            }
+            LightweightDistribution::Items { items } => {
+                if items.is_empty() {
+                    return Err("Статьи нагрузки массы судна порожнем не заданы.".to_string());
+                }
+                for item in items.iter() {
+                    item.validate(ship_dimensions)?;
+                }
+                let items_weight: f64 = items.iter().map(|item| item.weight).sum();
+                let items_lcg =
+                    items.iter().map(|item| item.weight * item.lcg).sum::<f64>() / items_weight;
+                if (items_weight - weight).abs() > 0.01 * weight {
+                    warn!(
+                        "LightweightDistribution.spatium_masses | Сумма масс статей нагрузки {items_weight} т отличается от массы судна порожнем {weight} т. Распределение приведено к массе судна порожнем."
+                    );
+                }
+                if let Some(lcg) = lw.lcg() {
+                    if (items_lcg - lcg).abs() > 0.005 * lbp {
+                        warn!(
+                            "LightweightDistribution.spatium_masses | Абсцисса центра тяжести статей нагрузки {items_lcg} м отличается от заданной абсциссы центра тяжести судна порожнем {lcg} м. Распределение приведено к заданной абсциссе."
+                        );
+                    }
+                }
+                let masses = spatiums
+                    .map(|id| {
+                        let (start, end) = bounds(id);
+                        items
+                            .iter()
+                            .map(|item| {
+                                let (q_start, q_end) = item.intensities();
+                                polyline_integral(
+                                    &[(item.start, q_start), (item.end, q_end)],
+                                    start,
+                                    end,
+                                )
+                            })
+                            .sum::<f64>()
+                            * weight
+                            / items_weight
+                    })
+                    .collect();
+                Ok((masses, Some(lw.lcg().unwrap_or(items_lcg))))
+            }
+        }
+    }
+}
+
+///
+/// Интеграл кусочно-линейной функции, заданной точками (x, f(x)) в порядке возрастания x,
+/// на отрезке [start, end]. Вне диапазона точек функция равна нулю.
+fn polyline_integral(points: &[(f64, f64)], start: f64, end: f64) -> f64 {
+    let mut integral = 0.0;
+    for segment in points.windows(2) {
+        let ((x1, f1), (x2, f2)) = (segment[0], segment[1]);
+        let (a, b) = (start.max(x1), end.min(x2));
+        if a >= b {
+            continue;
+        }
+        let value = |x: f64| f1 + (f2 - f1) * (x - x1) / (x2 - x1);
+        integral += (value(a) + value(b)) / 2.0 * (b - a);
+    }
+    integral
+}
@@ -1,6 +1,6 @@
-use super::lightweight::Lightweight;
+use super::{lightweight::Lightweight, lightweight_distribution::LightweightDistribution};
 use crate::{
-    core::{json_file::JsonFile, round::Round},
+    core::round::Round,
     strength::ship::{
         ship_dimensions::ShipDimensions, spatium_function::SpatiumFunction,
         spatium_functions::SpatiumFunctions,
@@ -31,52 +31,117 @@ impl LightweightIntensity {
         lw: Lightweight,
     ) -> LightweightIntensity {
         let mut lightweight_intensity: Vec<SpatiumFunction> = vec![];
-        let half_length_spatium = ship_dimensions.length_spatium() / 2.0;
+        let lbp = ship_dimensions.lbp().value();
+        let length_spatium = ship_dimensions.length_spatium().value();
+        let half_length_spatium = length_spatium / 2.0;
         let mut current_coord = ship_dimensions.coordinate_aft() + half_length_spatium;
         let (a, b, c) = LightweightIntensity::lightweight_intensity_parameters(
             ship_dimensions.completeness_coefficient(),
         );
         let intensity_load = |ratio: f64| {
-            ((lw.lightweight() / ship_dimensions.number_spatiums() as f64) * ratio)
-                / ship_dimensions.length_spatium()
+            ((lw.lightweight().value() / ship_dimensions.number_spatiums() as f64) * ratio)
+                / length_spatium
         };
         let mut ratio: f64;
         for id in 0..ship_dimensions.number_spatiums() {
             let end_coord = current_coord + half_length_spatium;
             let start_coord = current_coord - half_length_spatium;
             if current_coord > ship_dimensions.coordinate_aft()
-                && current_coord < (ship_dimensions.coordinate_aft() + ship_dimensions.lbp() / 3.0)
+                && current_coord < (ship_dimensions.coordinate_aft() + lbp / 3.0)
             {
-                ratio = a
-                    + ((b - a) * ((ship_dimensions.lbp() / 2.0) - current_coord.abs()))
-                        / (ship_dimensions.lbp() / 3.0);
-            } else if current_coord
-                >= ship_dimensions.coordinate_aft() + ship_dimensions.lbp() / 3.0
-                && current_coord < (ship_dimensions.coordinate_nose() - ship_dimensions.lbp() / 3.0)
+                ratio = a + ((b - a) * ((lbp / 2.0) - current_coord.abs())) / (lbp / 3.0);
+            } else if current_coord >= ship_dimensions.coordinate_aft() + lbp / 3.0
+                && current_coord < (ship_dimensions.coordinate_nose() - lbp / 3.0)
             {
                 ratio = b;
             } else {
-                ratio = c
-                    + ((b - c) * (ship_dimensions.lbp() / 2.0 - current_coord))
-                        / (ship_dimensions.lbp() / 3.0);
+                ratio = c + ((b - c) * (lbp / 2.0 - current_coord)) / (lbp / 3.0);
             }
             let f_x = intensity_load(ratio).my_round(2);
             let spatium_function =
                 SpatiumFunction::new(id, start_coord.my_round(2), end_coord.my_round(2), f_x, f_x);
             lightweight_intensity.push(spatium_function);
 
-            current_coord += ship_dimensions.length_spatium();
+            current_coord += length_spatium;
         }
         LightweightIntensity::new(SpatiumFunctions::new(lightweight_intensity))
     }
 
     ///
     /// Вспомогательный конструктор.
-    #[instrument(skip_all, err, target = "LightweightIntensity::from_json_file")]
-    pub fn from_json_file(file_path: String) -> Result<LightweightIntensity, String> {
-        let json = JsonFile::new(file_path);
-        let content = json.content().map_err(|err| err.to_string())?;
-        serde_json::from_reader(content).map_err(|err| err.to_string())
+    /// Создает объект по заданному методу распределения массы судна порожнем.
+    /// Если задана абсцисса центра тяжести судна порожнем, к распределению добавляется
+    /// линейная по длине судна поправка, не изменяющая массу и смещающая центр тяжести
+    /// в заданную точку. Для трапециевидного распределения без заданной абсциссы
+    /// центра тяжести результат совпадает с LightweightIntensity::from_ship_input_data.
+    /// Parameters:
+    ///     ship_dimensions - размерения судна,
+    ///     lw - масса судна порожнем,
+    ///     distribution - метод распределения массы судна порожнем по длине.
+    #[instrument(skip_all, err, target = "LightweightIntensity::from_distribution")]
+    pub fn from_distribution(
+        ship_dimensions: ShipDimensions,
+        lw: Lightweight,
+        distribution: &LightweightDistribution,
+    ) -> Result<LightweightIntensity, String> {
+        let (mut masses, lcg) = distribution.spatium_masses(&ship_dimensions, &lw)?;
+        let lcg = match (distribution, lcg) {
+            (LightweightDistribution::Trapezoidal, None) => {
+                return Ok(LightweightIntensity::from_ship_input_data(
+                    ship_dimensions,
+                    lw,
+                ));
+            }
+            (_, lcg) => lcg,
+        };
+        let coordinates: Vec<f64> = (0..ship_dimensions.number_spatiums())
+            .map(|id| {
+                (ship_dimensions.spatium_start_coordinate(id)
+                    + ship_dimensions.spatium_end_coordinate(id))
+                    / 2.0
+            })
+            .collect();
+        if let Some(lcg) = lcg {
+            let weight: f64 = masses.iter().sum();
+            let moment: f64 = masses
+                .iter()
+                .zip(coordinates.iter())
+                .map(|(m, x)| m * x)
+                .sum();
+            // Поправка dm_i = k * x_i не изменяет массу (шпации симметричны относительно миделя)
+            // и изменяет статический момент на k * sum(x_i^2).
+            let k = (weight * lcg - moment) / coordinates.iter().map(|x| x * x).sum::<f64>();
+            for (mass, x) in masses.iter_mut().zip(coordinates.iter()) {
+                *mass += k * x;
+            }
+            if masses.iter().any(|mass| *mass < 0.0) {
+                return Err(format!(
+                    "Распределение массы судна порожнем с абсциссой центра тяжести {lcg} м содержит отрицательные значения интенсивности."
+                ));
+            }
+        }
+        let length_spatium = ship_dimensions.length_spatium().value();
+        let lightweight_intensity = masses
+            .iter()
+            .enumerate()
+            .map(|(id, mass)| {
+                let f_x = (mass / length_spatium).my_round(2);
+                SpatiumFunction::new(
+                    id as u64,
+                    ship_dimensions
+                        .spatium_start_coordinate(id as u64)
+                        .my_round(2),
+                    ship_dimensions
+                        .spatium_end_coordinate(id as u64)
+                        .my_round(2),
+                    f_x,
+                    f_x,
+                )
+            })
+            .collect();
+        Ok(LightweightIntensity::new(SpatiumFunctions::new(
+            lightweight_intensity,
+        )))
     }
 
     ///
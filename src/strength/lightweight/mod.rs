@@ -1,2 +1,4 @@
+pub mod inclining_experiment;
 pub mod lightweight;
+pub mod lightweight_distribution;
 pub mod lightweight_intensity;
@@ -0,0 +1,130 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::shiploads::Shiploads;
+use crate::core::round::Round;
+
+///
+/// Категория нагрузки.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadCategory {
+    Cargo,
+    Ballast,
+    FuelOil,
+    FreshWater,
+    Stores,
+    Crew,
+    Constant,
+}
+
+impl LoadCategory {
+    ///
+    /// Все категории нагрузок.
+    pub fn all() -> Vec<LoadCategory> {
+        vec![
+            LoadCategory::Cargo,
+            LoadCategory::Ballast,
+            LoadCategory::FuelOil,
+            LoadCategory::FreshWater,
+            LoadCategory::Stores,
+            LoadCategory::Crew,
+            LoadCategory::Constant,
+        ]
+    }
+}
+
+impl Display for LoadCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            LoadCategory::Cargo => "Груз",
+            LoadCategory::Ballast => "Балласт",
+            LoadCategory::FuelOil => "Топливо",
+            LoadCategory::FreshWater => "Пресная вода",
+            LoadCategory::Stores => "Провизия и снабжение",
+            LoadCategory::Crew => "Экипаж",
+            LoadCategory::Constant => "Постоянные грузы",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for LoadCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_value(serde_json::Value::String(s.to_string()))
+            .map_err(|_| format!("Неизвестная категория нагрузки: {s}."))
+    }
+}
+
+///
+/// Сводка нагрузок одной категории.
+/// Parameters:
+///     category - категория нагрузок (None - категория не задана),
+///     weight - суммарная масса [т],
+///     lcg - абсцисса центра тяжести относительно мидель шпангоута [м],
+///     vcg - аппликата центра тяжести от основной плоскости [м].
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct CategorySummary {
+    category: Option<LoadCategory>,
+    weight: f64,
+    lcg: f64,
+    vcg: f64,
+}
+
+impl CategorySummary {
+    ///
+    /// Возвращает сводку нагрузок по категориям в порядке LoadCategory::all(),
+    /// нагрузки без категории - последней строкой. Категории без нагрузок не включаются.
+    pub fn from_shiploads(shiploads: &Shiploads) -> Vec<CategorySummary> {
+        let mut categories: Vec<Option<LoadCategory>> =
+            LoadCategory::all().into_iter().map(Some).collect();
+        categories.push(None);
+        categories
+            .into_iter()
+            .filter_map(|category| {
+                let (weight, moment_x, moment_z, count) = shiploads
+                    .as_ref()
+                    .iter()
+                    .filter(|shipload| shipload.category() == category)
+                    .fold((0.0, 0.0, 0.0, 0), |(weight, x, z, count), shipload| {
+                        (
+                            weight + shipload.value().value(),
+                            x + shipload.moment().value(),
+                            z + shipload.value().value() * shipload.center_gravity().z,
+                            count + 1,
+                        )
+                    });
+                if count == 0 {
+                    return None;
+                }
+                let (lcg, vcg) = match weight {
+                    0.0 => (0.0, 0.0),
+                    _ => (moment_x / weight, moment_z / weight),
+                };
+                Some(CategorySummary {
+                    category,
+                    weight: weight.my_round(2),
+                    lcg: lcg.my_round(2),
+                    vcg: vcg.my_round(2),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Display for CategorySummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let category = self.category.map_or(
+            "Категория не задана".to_string(),
+            |category| category.to_string(),
+        );
+        write!(
+            f,
+            "{:<22} | {:>10.1} | {:>8.2} | {:>8.2}",
+            category, self.weight, self.lcg, self.vcg
+        )
+    }
+}
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+
+///
+/// Закон распределения нагрузки по её протяженности.
+/// Протяженность нагрузки - отрезок длиной length с серединой в абсциссе center_gravity нагрузки.
+///     uniform - равномерно распределенная нагрузка,
+///     trapezoid - нагрузка, изменяющаяся по линейному закону от интенсивности aft (кормовой конец)
+///         до интенсивности nose (носовой конец),
+///     point - сосредоточенная нагрузка, приложенная в абсциссе center_gravity,
+///     curve - нагрузка, заданная точками [доля протяженности от кормового конца (от 0 до 1), интенсивность],
+///         между точками интенсивность изменяется по линейному закону.
+/// Интенсивности trapezoid и curve задаются в относительных единицах и приводятся к массе нагрузки value.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LoadShape {
+    Uniform,
+    Trapezoid { aft: f64, nose: f64 },
+    Point,
+    Curve { points: Vec<(f64, f64)> },
+}
+
+impl LoadShape {
+    ///
+    /// Валидация закона распределения нагрузки.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            LoadShape::Uniform | LoadShape::Point => Ok(()),
+            LoadShape::Trapezoid { aft, nose } => {
+                if *aft < 0.0 || *nose < 0.0 || aft + nose <= 0.0 {
+                    return Err(
+                        "Интенсивности трапециевидной нагрузки должны быть неотрицательными и не равными нулю одновременно."
+                            .to_string(),
+                    );
+                }
+                Ok(())
+            }
+            LoadShape::Curve { points } => {
+                if points.len() < 2 {
+                    return Err(
+                        "Кривая интенсивности нагрузки должна содержать не менее двух точек."
+                            .to_string(),
+                    );
+                }
+                if points.first().map(|point| point.0) != Some(0.0)
+                    || points.last().map(|point| point.0) != Some(1.0)
+                    || points.windows(2).any(|pair| pair[0].0 >= pair[1].0)
+                {
+                    return Err(
+                        "Абсциссы точек кривой интенсивности нагрузки должны возрастать от 0 до 1."
+                            .to_string(),
+                    );
+                }
+                if points.iter().any(|point| point.1 < 0.0) {
+                    return Err("Интенсивность нагрузки не может быть отрицательной.".to_string());
+                }
+                if points.windows(2).all(|pair| pair[0].1 + pair[1].1 == 0.0) {
+                    return Err("Кривая интенсивности нагрузки равна нулю.".to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    ///
+    /// Возвращает интенсивность нагрузки в виде ломаной [(x, q(x))] [м, т/м]
+    /// с интегралом, равным value. Для сосредоточенной нагрузки возвращает None.
+    /// Parameters:
+    ///     start, end - абсциссы начала и конца протяженности нагрузки [м],
+    ///     value - масса нагрузки [т].
+    pub fn polyline(&self, start: f64, end: f64, value: f64) -> Option<Vec<(f64, f64)>> {
+        let relative = match self {
+            LoadShape::Point => return None,
+            LoadShape::Uniform => vec![(0.0, 1.0), (1.0, 1.0)],
+            LoadShape::Trapezoid { aft, nose } => vec![(0.0, *aft), (1.0, *nose)],
+            LoadShape::Curve { points } => points.clone(),
+        };
+        let length = end - start;
+        let area: f64 = relative
+            .windows(2)
+            .map(|pair| (pair[0].1 + pair[1].1) / 2.0 * (pair[1].0 - pair[0].0) * length)
+            .sum();
+        Some(
+            relative
+                .into_iter()
+                .map(|(ratio, q)| (start + ratio * length, q * value / area))
+                .collect(),
+        )
+    }
+
+    ///
+    /// Абсцисса центра тяжести нагрузки [м].
+    /// Parameters:
+    ///     start, end - абсциссы начала и конца протяженности нагрузки [м].
+    pub fn center_gravity(&self, start: f64, end: f64) -> f64 {
+        match self.polyline(start, end, 1.0) {
+            Some(points) => points
+                .windows(2)
+                .map(|pair| {
+                    let ((x1, q1), (x2, q2)) = (pair[0], pair[1]);
+                    (x2 - x1) * (x1 * (q1 + q2) / 2.0 + (x2 - x1) * (q1 + 2.0 * q2) / 6.0)
+                })
+                .sum(),
+            None => (start + end) / 2.0,
+        }
+    }
+
+    ///
+    /// Часть нагрузки на отрезке [part_start, part_end] её протяженности.
+    /// Возвращает долю массы нагрузки, приходящуюся на отрезок, и закон распределения части нагрузки.
+    /// Для сосредоточенной нагрузки возвращает None.
+    /// Parameters:
+    ///     start, end - абсциссы начала и конца протяженности нагрузки [м],
+    ///     part_start, part_end - абсциссы начала и конца части нагрузки [м].
+    pub fn part(
+        &self,
+        start: f64,
+        end: f64,
+        part_start: f64,
+        part_end: f64,
+    ) -> Option<(f64, LoadShape)> {
+        let points = self.polyline(start, end, 1.0)?;
+        let intensity = |x: f64| {
+            points
+                .windows(2)
+                .find(|pair| x <= pair[1].0)
+                .map(|pair| {
+                    let ((x1, q1), (x2, q2)) = (pair[0], pair[1]);
+                    q1 + (q2 - q1) * (x - x1) / (x2 - x1)
+                })
+                .unwrap_or(points[points.len() - 1].1)
+        };
+        let mut part = vec![(part_start, intensity(part_start))];
+        part.extend(
+            points
+                .iter()
+                .filter(|(x, _)| part_start < *x && *x < part_end)
+                .copied(),
+        );
+        part.push((part_end, intensity(part_end)));
+        let ratio: f64 = part
+            .windows(2)
+            .map(|pair| (pair[0].1 + pair[1].1) / 2.0 * (pair[1].0 - pair[0].0))
+            .sum();
+        let length = part_end - part_start;
+        let shape = match self {
+            LoadShape::Uniform => LoadShape::Uniform,
+            LoadShape::Trapezoid { .. } => LoadShape::Trapezoid {
+                aft: part[0].1,
+                nose: part[part.len() - 1].1,
+            },
+            _ => LoadShape::Curve {
+                points: part
+                    .into_iter()
+                    .map(|(x, q)| ((x - part_start) / length, q))
+                    .collect(),
+            },
+        };
+        Some((ratio, shape))
+    }
+}
@@ -1,3 +1,5 @@
+pub mod load_category;
+pub mod load_shape;
 pub mod shipload;
 pub mod shiploads;
 pub mod total_shipload;
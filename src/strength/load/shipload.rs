@@ -1,4 +1,8 @@
-use crate::core::round::Round;
+use super::{load_category::LoadCategory, load_shape::LoadShape};
+use crate::core::{
+    round::Round,
+    system_of_units::{MassMoment, Meters, Tons},
+};
 use crate::{core::point::Point, strength::ship::ship_dimensions::ShipDimensions};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -8,11 +12,24 @@ use std::fmt::Debug;
 /// value - load value in tons.
 /// center_gravity -  the center gravity of the load relative to the amidships(the middle of a ship).
 /// length - load length.
-#[derive(Deserialize, Debug, Clone, Copy, Serialize)]
+/// shape - закон распределения нагрузки по её протяженности,
+/// если не задан, нагрузка распределяется по теоретическим шпациям прямоугольниками.
+/// name - идентификатор нагрузки (например, "Трюм 1", "Цистерна ТТ 3 ЛБ").
+/// description - описание нагрузки.
+/// category - категория нагрузки.
+#[derive(Deserialize, Debug, Clone, Serialize)]
 pub struct Shipload {
     value: f64,
     center_gravity: Point,
     length: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shape: Option<LoadShape>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    category: Option<LoadCategory>,
 }
 
 impl Shipload {
@@ -23,9 +40,52 @@ impl Shipload {
             value,
             center_gravity,
             length,
+            shape: None,
+            name: None,
+            description: None,
+            category: None,
         }
     }
 
+    ///
+    /// Задает идентификатор и описание нагрузки.
+    pub fn with_name(mut self, name: &str, description: Option<&str>) -> Self {
+        self.name = Some(name.to_string());
+        self.description = description.map(|description| description.to_string());
+        self
+    }
+
+    ///
+    /// Задает категорию нагрузки.
+    pub fn with_category(mut self, category: LoadCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    ///
+    /// Идентификатор нагрузки.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    ///
+    /// Категория нагрузки.
+    pub fn category(&self) -> Option<LoadCategory> {
+        self.category
+    }
+
+    ///
+    /// Центр тяжести нагрузки.
+    pub fn center_gravity(&self) -> Point {
+        self.center_gravity
+    }
+
+    ///
+    /// Закон распределения нагрузки по её протяженности.
+    pub fn shape(&self) -> Option<&LoadShape> {
+        self.shape.as_ref()
+    }
+
     ///
     /// Return the coordinate of the start of the load relative to the amidships(the middle of a ship).
     pub fn load_start_coordinate(&self) -> f64 {
@@ -44,10 +104,16 @@ impl Shipload {
         self.center_gravity.x
     }
 
+    ///
+    /// Return shipload length.
+    pub fn length(&self) -> Meters {
+        Meters(self.length)
+    }
+
     ///
     /// Return shipload value in tons.
-    pub fn value(&self) -> f64 {
-        self.value
+    pub fn value(&self) -> Tons {
+        Tons(self.value)
     }
 
     ///
@@ -69,30 +135,62 @@ impl Shipload {
 
     ///
     /// Share shipload by coordinates.
+    /// Часть нагрузки сохраняет идентификатор, описание и категорию нагрузки,
+    /// масса и закон распределения части определяются по закону распределения нагрузки.
     /// Params:
     /// load_start_coordinate - coordinate of the start of the new shipload.
     /// load_end_coordinate - coordinate of the end of the new shipload.
     /// Return: Shipload.
     fn shared_shipload(&self, load_start_coordinate: f64, load_end_coordinate: f64) -> Shipload {
         let load_length = (load_end_coordinate - load_start_coordinate).abs();
-        let load_value = (load_length / self.length) * self.value;
+        let (ratio, shape) = match self.shape.as_ref().and_then(|shape| {
+            shape.part(
+                self.load_start_coordinate(),
+                self.load_end_coordinate(),
+                load_start_coordinate,
+                load_end_coordinate,
+            )
+        }) {
+            Some((ratio, shape)) => (ratio, Some(shape)),
+            None => (load_length / self.length, self.shape.clone()),
+        };
+        let load_value = ratio * self.value;
         let x = load_start_coordinate + (load_length / 2.0);
         let center_gravity =
             Point::new(x.my_round(2), self.center_gravity.y, self.center_gravity.z);
-        Shipload::new(
-            load_value.my_round(2),
-            center_gravity,
-            load_length.my_round(2),
-        )
+        Shipload {
+            shape,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            category: self.category,
+            ..Shipload::new(
+                load_value.my_round(2),
+                center_gravity,
+                load_length.my_round(2),
+            )
+        }
     }
 
-    pub fn moment(&self) -> f64 {
-        self.value * self.longitudinal_center_gravity()
+    ///
+    /// Статический момент массы нагрузки относительно мидель шпангоута [т*м].
+    /// Для нагрузки с заданным законом распределения учитывает положение центра тяжести эпюры нагрузки.
+    pub fn moment(&self) -> MassMoment {
+        let lcg = match &self.shape {
+            Some(shape) => {
+                shape.center_gravity(self.load_start_coordinate(), self.load_end_coordinate())
+            }
+            None => self.longitudinal_center_gravity(),
+        };
+        self.value() * Meters(lcg)
     }
 
     ///
     /// Share the shipload by spatiums.
+    /// Сосредоточенная нагрузка не делится.
     pub fn shared_shiploads(&self, ship_dimensions: &ShipDimensions) -> Vec<Shipload> {
+        if self.shape == Some(LoadShape::Point) {
+            return vec![self.clone()];
+        }
         let mut shared_shiploads = vec![];
         let mut load_start_coordinate = self.load_start_coordinate();
         let spatium_shipload_start_index =
@@ -100,7 +198,7 @@ impl Shipload {
         let load_end_coordinate = self.load_end_coordinate();
         let mut current_coordinate =
             ship_dimensions.spatium_start_coordinate(spatium_shipload_start_index);
-        let spatium_length = ship_dimensions.length_spatium();
+        let spatium_length = ship_dimensions.length_spatium().value();
         while current_coordinate < load_end_coordinate {
             if current_coordinate > load_start_coordinate {
                 let shipload = self.shared_shipload(load_start_coordinate, current_coordinate);
@@ -1,29 +1,141 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use tracing::instrument;
 
-use crate::core::json_file::JsonFile;
-use crate::strength::load::shipload::Shipload;
+use crate::core::{
+    data_file::DataFile,
+    schema_version::{Migration, SchemaVersion},
+    system_of_units::Meters,
+};
+use crate::strength::load::{
+    load_category::{CategorySummary, LoadCategory},
+    load_shape::LoadShape,
+    shipload::Shipload,
+};
 use crate::strength::ship::ship_dimensions::ShipDimensions;
 
+///
+/// Версии формата файла схемы загрузки:
+///     1 - файл без поля `version`, названия нагрузок не обязательны,
+///     2 - каждая нагрузка имеет название `name`.
+pub const SHIPLOADS_SCHEMA: SchemaVersion = SchemaVersion::new(
+    "файла схемы загрузки",
+    2,
+    &[Migration {
+        from: 1,
+        description: "нагрузкам без названия присвоены названия \"Нагрузка <индекс>\"",
+        migrate: name_shiploads,
+    }],
+);
+
+///
+/// Миграция с версии 1: присваивает нагрузкам без названия названия по их индексу,
+/// как они выводятся в таблице нагрузок.
+fn name_shiploads(mut value: Value) -> Result<Value, String> {
+    let shiploads = value
+        .get_mut("shiploads")
+        .and_then(|shiploads| shiploads.as_array_mut())
+        .ok_or("Файл схемы загрузки должен содержать массив `shiploads`.".to_string())?;
+    for (index, shipload) in shiploads.iter_mut().enumerate() {
+        if let Value::Object(shipload) = shipload {
+            shipload
+                .entry("name")
+                .or_insert(Value::from(format!("Нагрузка {index}")));
+        }
+    }
+    Ok(value)
+}
+
 ///
 /// Сontains all the loads acting on the ship
-#[derive(Deserialize, Debug, Serialize)]
+/// water_density - плотность забортной воды для данного состояния загрузки [т/м^3],
+/// если не задана, используется плотность из основных данных о судне.
+#[derive(Deserialize, Debug, Serialize, Clone)]
 pub struct Shiploads {
     shiploads: Vec<Shipload>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    water_density: Option<f64>,
 }
 
 impl Shiploads {
     ///
     /// Create new object.
     pub fn new(shiploads: Vec<Shipload>) -> Self {
-        Shiploads { shiploads }
+        Shiploads {
+            shiploads,
+            water_density: None,
+        }
+    }
+
+    ///
+    /// Задает плотность забортной воды для состояния загрузки [т/м^3].
+    pub fn with_water_density(mut self, water_density: f64) -> Self {
+        self.water_density = Some(water_density);
+        self
     }
 
-    #[instrument(skip_all, err, target = "Shiploads::from_json_file")]
-    pub fn from_json_file(file_path: String) -> Result<Self, String> {
-        let json = JsonFile::new(file_path);
-        let content = json.content().map_err(|err| err.to_string())?;
-        serde_json::from_reader(content).map_err(|err| err.to_string())
+    ///
+    /// Плотность забортной воды для состояния загрузки [т/м^3].
+    pub fn water_density(&self) -> Option<f64> {
+        self.water_density
+    }
+
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Файлы старых версий формата обновляются до текущей версии (SHIPLOADS_SCHEMA),
+    /// в файле текущей версии каждая нагрузка должна иметь название.
+    #[instrument(skip_all, err, target = "Shiploads::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let value: Value = DataFile::new(file_path.clone()).deserialize()?;
+        Shiploads::from_value(value).map_err(|err| format!("Файл {file_path}: {err}"))
+    }
+
+    ///
+    /// Create the object from json value (содержимое файла или тело запроса).
+    /// Старые версии формата обновляются до текущей версии (SHIPLOADS_SCHEMA),
+    /// в текущей версии каждая нагрузка должна иметь название.
+    pub fn from_value(value: Value) -> Result<Self, String> {
+        let value = SHIPLOADS_SCHEMA.upgrade(value)?;
+        let shiploads: Shiploads = serde_json::from_value(value).map_err(|err| err.to_string())?;
+        shiploads.validate_names()?.validate_input_data()
+    }
+
+    ///
+    /// Валидация: каждая нагрузка имеет непустое название (версия формата 2).
+    fn validate_names(self) -> Result<Shiploads, String> {
+        for (index, shipload) in self.shiploads.iter().enumerate() {
+            if shipload.name().is_none_or(|name| name.trim().is_empty()) {
+                return Err(format!(
+                    "Нагрузка с индексом {index}: не задано название `name`."
+                ));
+            }
+        }
+        Ok(self)
+    }
+
+    ///
+    /// Валидация законов распределения нагрузок и плотности забортной воды.
+    pub(crate) fn validate_input_data(self) -> Result<Shiploads, String> {
+        if let Some(water_density) = self.water_density {
+            if water_density <= 0.0 {
+                return Err(format!(
+                    "Плотность забортной воды должна быть больше нуля: {water_density} т/м^3."
+                ));
+            }
+        }
+        for (index, shipload) in self.shiploads.iter().enumerate() {
+            if let Some(shape) = shipload.shape() {
+                if *shape != LoadShape::Point && shipload.length() <= Meters(0.0) {
+                    return Err(format!(
+                        "Нагрузка с индексом {index}: длина распределенной нагрузки должна быть больше нуля."
+                    ));
+                }
+                shape
+                    .validate()
+                    .map_err(|err| format!("Нагрузка с индексом {index}: {err}"))?;
+            }
+        }
+        Ok(self)
     }
 
     pub fn shared_shiploads(&self, ship_dimensions: &ShipDimensions) -> Shiploads {
@@ -34,12 +146,70 @@ impl Shiploads {
         Shiploads::new(shiploads)
     }
 
+    ///
+    /// Add the shipload to the end of the list.
+    pub fn add(&mut self, shipload: Shipload) {
+        self.shiploads.push(shipload);
+    }
+
+    ///
+    /// Remove the shipload by index.
+    pub fn remove(&mut self, index: usize) -> Result<Shipload, String> {
+        if index >= self.shiploads.len() {
+            return Err(format!(
+                "Нагрузка с индексом {index} отсутствует. Количество нагрузок: {}.",
+                self.shiploads.len()
+            ));
+        }
+        Ok(self.shiploads.remove(index))
+    }
+
+    ///
+    /// Replace the shipload by index.
+    pub fn replace(&mut self, index: usize, shipload: Shipload) -> Result<(), String> {
+        match self.shiploads.get_mut(index) {
+            Some(item) => {
+                *item = shipload;
+                Ok(())
+            }
+            None => Err(format!(
+                "Нагрузка с индексом {index} отсутствует. Количество нагрузок: {}.",
+                self.shiploads.len()
+            )),
+        }
+    }
+
+    ///
+    /// Возвращает схему загрузки, содержащую только нагрузки заданных категорий
+    /// (для расчетов "что если"). Нагрузки без категории не включаются.
+    pub fn with_categories(&self, categories: &[LoadCategory]) -> Shiploads {
+        Shiploads {
+            shiploads: self
+                .shiploads
+                .iter()
+                .filter(|shipload| {
+                    shipload
+                        .category()
+                        .is_some_and(|category| categories.contains(&category))
+                })
+                .cloned()
+                .collect(),
+            water_density: self.water_density,
+        }
+    }
+
+    ///
+    /// Сводка нагрузок по категориям: масса, абсцисса и аппликата центра тяжести.
+    pub fn category_summary(&self) -> Vec<CategorySummary> {
+        CategorySummary::from_shiploads(self)
+    }
+
     ///
     /// Return the shiploads sum.
     pub fn sum(&self) -> f64 {
         let mut sum = 0.0;
         for shipload in self.shiploads.iter() {
-            sum += shipload.value();
+            sum += shipload.value().value();
         }
         sum
     }
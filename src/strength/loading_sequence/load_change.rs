@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::strength::load::{shipload::Shipload, shiploads::Shiploads};
+
+///
+/// Изменение схемы загрузки судна на одном шаге грузовых операций.
+/// Индексы нагрузок относятся к схеме загрузки, полученной на предыдущем шаге.
+///     Add - погрузка (добавление нагрузки),
+///     Remove - выгрузка (удаление нагрузки),
+///     Replace - замена нагрузки (например, догрузка трюма).
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "operation", rename_all = "snake_case")]
+pub enum LoadChange {
+    Add { shipload: Shipload },
+    Remove { index: usize },
+    Replace { index: usize, shipload: Shipload },
+}
+
+impl LoadChange {
+    ///
+    /// Применяет изменение к схеме загрузки.
+    pub fn apply(&self, shiploads: &mut Shiploads) -> Result<(), String> {
+        match self {
+            LoadChange::Add { shipload } => {
+                shiploads.add(shipload.clone());
+                Ok(())
+            }
+            LoadChange::Remove { index } => shiploads.remove(*index).map(|_| ()),
+            LoadChange::Replace { index, shipload } => shiploads.replace(*index, shipload.clone()),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod load_change;
+pub mod sequence;
+pub mod sequence_report;
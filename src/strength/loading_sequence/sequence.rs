@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+
+use super::{load_change::LoadChange, sequence_report::SequenceReport};
+use crate::{
+    core::{data_file::DataFile, schema_version::SchemaVersion},
+    strength::{load::shiploads::Shiploads, ship::ship_model::Ship},
+};
+
+///
+/// Версии формата файла последовательности грузовых операций:
+///     1 - поле steps (шаги грузовых операций).
+pub const LOADING_SEQUENCE_SCHEMA: SchemaVersion =
+    SchemaVersion::new("файла последовательности грузовых операций", 1, &[]);
+
+///
+/// Шаг грузовых операций.
+/// Parameters:
+///     name - описание шага (например, "Порция 1 в трюм 3"),
+///     changes - изменения схемы загрузки, выполняемые на данном шаге.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LoadingStep {
+    name: String,
+    changes: Vec<LoadChange>,
+}
+
+impl LoadingStep {
+    ///
+    /// Описание шага.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    ///
+    /// Применяет изменения шага к схеме загрузки.
+    pub fn apply(&self, shiploads: &mut Shiploads) -> Result<(), String> {
+        for change in self.changes.iter() {
+            change
+                .apply(shiploads)
+                .map_err(|err| format!("Шаг \"{}\": {err}", self.name))?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// Последовательность грузовых операций (погрузки или выгрузки),
+/// выполняемых над исходной схемой загрузки судна.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LoadingSequence {
+    steps: Vec<LoadingStep>,
+}
+
+impl LoadingSequence {
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Версия формата проверяется до разбора содержимого (LOADING_SEQUENCE_SCHEMA).
+    #[instrument(skip_all, err, target = "LoadingSequence::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let value: Value = DataFile::new(file_path.clone()).deserialize()?;
+        let value = LOADING_SEQUENCE_SCHEMA
+            .upgrade(value)
+            .map_err(|err| format!("Файл {file_path}: {err}"))?;
+        serde_json::from_value(value).map_err(|err| err.to_string())
+    }
+
+    ///
+    /// Возвращает схемы загрузки судна: исходную и после каждого шага грузовых операций.
+    /// Parameters:
+    ///     initial - исходная схема загрузки.
+    #[instrument(skip_all, err, target = "LoadingSequence::conditions")]
+    pub fn conditions(&self, initial: &Shiploads) -> Result<Vec<Shiploads>, String> {
+        let mut current = initial.clone();
+        let mut conditions = vec![current.clone()];
+        for step in self.steps.iter() {
+            step.apply(&mut current)?;
+            conditions.push(current.clone());
+        }
+        Ok(conditions)
+    }
+
+    ///
+    /// Выполняет расчет прочности, осадок и дифферента для исходной схемы загрузки
+    /// и после каждого шага грузовых операций.
+    /// Parameters:
+    ///     ship - данные судна,
+    ///     initial - исходная схема загрузки.
+    #[instrument(skip_all, err, target = "LoadingSequence::run")]
+    pub fn run(&self, ship: &Ship, initial: &Shiploads) -> Result<SequenceReport, String> {
+        let conditions = self.conditions(initial)?;
+        let mut names = vec!["Исходное состояние"];
+        names.extend(self.steps.iter().map(|step| step.name()));
+        Ok(SequenceReport::new(
+            ship,
+            names.into_iter().zip(conditions.iter()),
+        ))
+    }
+}
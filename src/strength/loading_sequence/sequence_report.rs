@@ -0,0 +1,231 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::{
+    core::{round::Round, system_of_units::Meters, unit_system::UnitSystem},
+    strength::{
+        load::shiploads::Shiploads, ship::ship_model::Ship, strength::Strength,
+        strength_check::still_water_check::StrengthCheck,
+    },
+};
+
+///
+/// Результаты расчета для одного шага грузовых операций.
+/// Parameters:
+///     step - номер шага (0 - исходное состояние),
+///     name - описание шага,
+///     deadweight - дедвейт [т],
+///     water_density - плотность забортной воды, принятая в расчете [т/м^3],
+///     aft_draft, nose_draft - осадки кормой и носом [м],
+///     trim - дифферент, положительный на нос [м],
+///     strength_check - результаты проверки прочности,
+///     error - ошибка расчета, если расчет для данного шага не выполнен.
+#[derive(Debug, Serialize)]
+pub struct StepResult {
+    step: usize,
+    name: String,
+    deadweight: f64,
+    water_density: f64,
+    aft_draft: Option<f64>,
+    nose_draft: Option<f64>,
+    trim: Option<f64>,
+    strength_check: Option<StrengthCheck>,
+    error: Option<String>,
+}
+
+impl StepResult {
+    ///
+    /// Выполняет расчет для схемы загрузки.
+    pub fn new(step: usize, name: &str, ship: &Ship, shiploads: &Shiploads) -> Self {
+        let mut result = StepResult {
+            step,
+            name: name.to_string(),
+            deadweight: shiploads.sum().my_round(2),
+            water_density: shiploads
+                .water_density()
+                .unwrap_or(ship.water_density().water_density()),
+            aft_draft: None,
+            nose_draft: None,
+            trim: None,
+            strength_check: None,
+            error: None,
+        };
+        let calculated = Strength::from_ship(ship, shiploads).and_then(|strength| {
+            let (Meters(aft_draft), Meters(nose_draft)) = strength.draft()?;
+            Ok((
+                aft_draft,
+                nose_draft,
+                strength.strength_check(&ship.permissible_limits()?)?,
+            ))
+        });
+        match calculated {
+            Ok((aft_draft, nose_draft, strength_check)) => {
+                result.aft_draft = Some(aft_draft);
+                result.nose_draft = Some(nose_draft);
+                result.trim = Some((nose_draft - aft_draft).my_round(2));
+                result.strength_check = Some(strength_check);
+            }
+            Err(err) => result.error = Some(err),
+        }
+        result
+    }
+
+    ///
+    /// Номер шага.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    ///
+    /// Плотность забортной воды, принятая в расчете [т/м^3].
+    pub fn water_density(&self) -> f64 {
+        self.water_density
+    }
+
+    ///
+    /// Осадки кормой и носом [м].
+    pub fn drafts(&self) -> Option<(f64, f64)> {
+        self.aft_draft.zip(self.nose_draft)
+    }
+
+    ///
+    /// Результаты проверки прочности.
+    pub fn strength_check(&self) -> Option<&StrengthCheck> {
+        self.strength_check.as_ref()
+    }
+
+    ///
+    /// Ошибка расчета.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    ///
+    /// Наибольшее отношение внутренних силовых факторов к допускаемым.
+    /// Если расчет не выполнен, возвращает f64::INFINITY.
+    pub fn max_utilisation(&self) -> f64 {
+        self.strength_check
+            .map_or(f64::INFINITY, |check| check.max_utilisation())
+    }
+}
+
+///
+/// Отчет о расчете последовательности грузовых операций.
+#[derive(Debug, Serialize)]
+pub struct SequenceReport {
+    steps: Vec<StepResult>,
+    critical_step: Option<usize>,
+}
+
+impl SequenceReport {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     ship - данные судна,
+    ///     conditions - описания шагов и соответствующие им схемы загрузки.
+    pub fn new<'a>(
+        ship: &Ship,
+        conditions: impl Iterator<Item = (&'a str, &'a Shiploads)>,
+    ) -> Self {
+        let steps: Vec<StepResult> = conditions
+            .enumerate()
+            .map(|(step, (name, shiploads))| StepResult::new(step, name, ship, shiploads))
+            .collect();
+        let critical_step = steps
+            .iter()
+            .max_by(|a, b| a.max_utilisation().total_cmp(&b.max_utilisation()))
+            .map(|step| step.step());
+        SequenceReport {
+            steps,
+            critical_step,
+        }
+    }
+
+    ///
+    /// Пересчитывает силы и моменты в результатах проверки прочности в заданную систему единиц.
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        for step in self.steps.iter_mut() {
+            step.strength_check = step
+                .strength_check
+                .map(|check| check.with_unit_system(unit_system));
+        }
+        self
+    }
+
+    ///
+    /// Результаты расчета для всех шагов.
+    pub fn steps(&self) -> &Vec<StepResult> {
+        &self.steps
+    }
+
+    ///
+    /// Критический шаг - шаг с наибольшим отношением внутренних силовых факторов к допускаемым
+    /// (или шаг, для которого расчет не выполнен).
+    pub fn critical_step(&self) -> Option<&StepResult> {
+        self.critical_step.and_then(|step| self.steps.get(step))
+    }
+
+    ///
+    /// Прочность обеспечена на всех шагах грузовых операций.
+    pub fn passed(&self) -> bool {
+        self.steps.iter().all(|step| step.max_utilisation() <= 1.0)
+    }
+}
+
+impl Display for SequenceReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>4} | {:<30} | {:>10} | {:>7} | {:>7} | {:>7} | {:>7} | {:>6} | {:>6} | {:>6}",
+            "Шаг",
+            "Операция",
+            "DW, т",
+            "ρ, т/м3",
+            "Tк, м",
+            "Tн, м",
+            "Диф, м",
+            "N, %",
+            "Mh, %",
+            "Ms, %"
+        )?;
+        let critical_step = self.critical_step().map(|step| step.step());
+        for step in self.steps() {
+            let marker = if Some(step.step()) == critical_step {
+                "*"
+            } else {
+                " "
+            };
+            match (step.drafts(), step.strength_check(), step.error()) {
+                (Some((aft_draft, nose_draft)), Some(check), _) => writeln!(
+                    f,
+                    "{:>3}{marker} | {:<30} | {:>10.1} | {:>7.3} | {:>7.2} | {:>7.2} | {:>7.2} | {:>6.1} | {:>6.1} | {:>6.1}",
+                    step.step,
+                    step.name,
+                    step.deadweight,
+                    step.water_density(),
+                    aft_draft,
+                    nose_draft,
+                    nose_draft - aft_draft,
+                    check.share_force_utilisation() * 100.0,
+                    check.hogging_utilisation() * 100.0,
+                    check.sagging_utilisation() * 100.0
+                )?,
+                (_, _, error) => writeln!(
+                    f,
+                    "{:>3}{marker} | {:<30} | {:>10.1} | {:>7.3} | {}",
+                    step.step,
+                    step.name,
+                    step.deadweight,
+                    step.water_density(),
+                    error.unwrap_or("расчет не выполнен")
+                )?,
+            }
+        }
+        if self.passed() {
+            writeln!(f, "Прочность обеспечена на всех шагах.")
+        } else {
+            writeln!(f, "Прочность не обеспечена, критический шаг отмечен *.")
+        }
+    }
+}
@@ -1,10 +1,20 @@
+pub mod ballast;
 pub mod bonjean_scale;
 pub mod buoyancy_intensity;
+pub mod compartment;
 pub mod deadweight;
 pub mod displacement;
 pub mod hydrostatic_curves;
 pub mod internal_forces;
 pub mod lightweight;
 pub mod load;
+pub mod loading_sequence;
+pub mod project;
+pub mod report;
 pub mod ship;
+pub mod stability;
+pub mod stowage;
 pub mod strength;
+pub mod strength_check;
+pub mod validation;
+pub mod voyage;
@@ -0,0 +1,84 @@
+use std::path::Path;
+
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::instrument;
+
+use super::ship_project::ShipProject;
+use crate::{
+    core::{data_file::DataFile, schema_version::SchemaVersion},
+    strength::load::{shipload::Shipload, shiploads::Shiploads},
+};
+
+///
+/// Версии формата файла схемы загрузки, ссылающегося на проект судна:
+///     1 - поля project, shiploads и необязательное поле water_density.
+pub const LOADING_CONDITION_SCHEMA: SchemaVersion =
+    SchemaVersion::new("файла схемы загрузки по проекту судна", 1, &[]);
+
+///
+/// Содержимое файла схемы загрузки, ссылающегося на проект судна.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LoadingConditionInput {
+    project: String,
+    #[serde(default)]
+    water_density: Option<f64>,
+    shiploads: Vec<Shipload>,
+}
+
+///
+/// Схема загрузки судна, заданная отдельным файлом со ссылкой на проект судна.
+/// Путь к проекту задается относительно каталога файла схемы загрузки.
+/// Parameters:
+///     project - проект судна,
+///     shiploads - нагрузки, действующие на судно.
+#[derive(Debug, Clone)]
+pub struct LoadingCondition {
+    project: ShipProject,
+    shiploads: Shiploads,
+}
+
+impl LoadingCondition {
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Версия формата проверяется до разбора содержимого (LOADING_CONDITION_SCHEMA).
+    #[instrument(skip_all, err, target = "LoadingCondition::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let error = |err: String| format!("Файл схемы загрузки {file_path}: {err}");
+        let value: Value = DataFile::new(file_path.clone())
+            .deserialize()
+            .map_err(error)?;
+        let mut value = LOADING_CONDITION_SCHEMA.upgrade(value).map_err(error)?;
+        // Версия формата проверена, содержимое файла разбирается без нее.
+        if let Value::Object(map) = &mut value {
+            map.remove("version");
+        }
+        let input: LoadingConditionInput =
+            serde_json::from_value(value).map_err(|err| error(err.to_string()))?;
+        let project_path = Path::new(&file_path)
+            .parent()
+            .map_or(Path::new(&input.project).to_path_buf(), |dir| {
+                dir.join(&input.project)
+            });
+        let project = ShipProject::from_file(project_path.to_string_lossy().into_owned())?;
+        let mut shiploads = Shiploads::new(input.shiploads);
+        if let Some(water_density) = input.water_density {
+            shiploads = shiploads.with_water_density(water_density);
+        }
+        let shiploads = shiploads.validate_input_data().map_err(error)?;
+        Ok(LoadingCondition { project, shiploads })
+    }
+
+    ///
+    /// Проект судна.
+    pub fn project(&self) -> &ShipProject {
+        &self.project
+    }
+
+    ///
+    /// Нагрузки, действующие на судно.
+    pub fn shiploads(&self) -> &Shiploads {
+        &self.shiploads
+    }
+}
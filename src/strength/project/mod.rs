@@ -0,0 +1,2 @@
+pub mod loading_condition;
+pub mod ship_project;
@@ -0,0 +1,211 @@
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::instrument;
+
+use crate::{
+    core::{
+        data_file::DataFile, schema_version::SchemaVersion, unit_system::UnitSystem,
+        water_density::WaterDensity,
+    },
+    cross_section_properties::{cross_section::CrossSection, cross_sections::CrossSections},
+    strength::{
+        bonjean_scale::{frame::Frame, frames::Frames},
+        compartment::{compartments::Compartments, ship_compartment::Compartment},
+        hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
+        lightweight::{
+            lightweight::Lightweight, lightweight_distribution::LightweightDistribution,
+        },
+        ship::{ship_dimensions::ShipDimensions, ship_model::Ship},
+        strength_check::permissible_limits::PermissibleLimits,
+        validation::{
+            bonjean_validation::BonjeanValidation, hydrostatic_validation::HydrostaticValidation,
+        },
+    },
+};
+
+///
+/// Версии формата файла проекта судна:
+///     1 - разделы particulars, lightweight, permissible_limits, compartments, frames, hydrostatic_curves
+///     и необязательный раздел cross_sections (поперечные сечения корпуса).
+pub const PROJECT_SCHEMA: SchemaVersion = SchemaVersion::new("файла проекта судна", 1, &[]);
+
+///
+/// Главные размерения и общие данные судна.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ParticularsInput {
+    ship_name: String,
+    lbp: f64,
+    #[serde(default)]
+    breadth: Option<f64>,
+    number_spatiums: u64,
+    completeness_coefficient: f64,
+    water_density: f64,
+    #[serde(default)]
+    unit_system: UnitSystem,
+}
+
+///
+/// Масса судна порожнем, ее центр тяжести и метод распределения по длине.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LightweightInput {
+    mass: f64,
+    #[serde(default)]
+    lcg: Option<f64>,
+    #[serde(default)]
+    vcg: Option<f64>,
+    #[serde(default)]
+    tcg: Option<f64>,
+    #[serde(default)]
+    distribution: LightweightDistribution,
+}
+
+///
+/// Допускаемые значения внутренних силовых факторов.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PermissibleLimitsInput {
+    share_force: f64,
+    hogging_moment: f64,
+    sagging_moment: f64,
+    #[serde(default)]
+    shear_stress: Option<f64>,
+}
+
+///
+/// Содержимое файла проекта судна.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ShipProjectInput {
+    particulars: ParticularsInput,
+    lightweight: LightweightInput,
+    permissible_limits: PermissibleLimitsInput,
+    #[serde(default)]
+    compartments: Vec<Compartment>,
+    frames: Vec<Frame>,
+    hydrostatic_curves: HydrostaticCurves,
+    #[serde(default)]
+    cross_sections: Vec<CrossSection>,
+}
+
+///
+/// Проект судна - единый файл с данными судна, не зависящими от схемы загрузки:
+/// главные размерения, масса судна порожнем, масштаб Бонжана, гидростатические кривые,
+/// грузовые помещения и допускаемые значения внутренних силовых факторов.
+/// Неизвестные и отсутствующие обязательные поля файла приводят к ошибке.
+/// Parameters:
+///     ship_name - название судна,
+///     unit_system - система единиц вывода результатов,
+///     ship - данные судна.
+#[derive(Debug, Clone)]
+pub struct ShipProject {
+    ship_name: String,
+    unit_system: UnitSystem,
+    ship: Ship,
+}
+
+impl ShipProject {
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Версия формата проверяется до разбора разделов проекта (PROJECT_SCHEMA).
+    #[instrument(skip_all, err, target = "ShipProject::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let error = |err: String| format!("Файл проекта судна {file_path}: {err}");
+        let value: Value = DataFile::new(file_path.clone())
+            .deserialize()
+            .map_err(error)?;
+        let mut value = PROJECT_SCHEMA.upgrade(value).map_err(error)?;
+        // Версия формата проверена, разделы проекта разбираются без нее.
+        if let Value::Object(map) = &mut value {
+            map.remove("version");
+        }
+        let input: ShipProjectInput =
+            serde_json::from_value(value).map_err(|err| error(err.to_string()))?;
+        ShipProject::from_input(input).map_err(error)
+    }
+
+    ///
+    /// Создает данные судна из разделов проекта.
+    fn from_input(input: ShipProjectInput) -> Result<Self, String> {
+        let particulars = input.particulars;
+        let mut ship_dimensions = ShipDimensions::new(
+            particulars.lbp,
+            particulars.number_spatiums,
+            particulars.completeness_coefficient,
+        );
+        if let Some(breadth) = particulars.breadth {
+            ship_dimensions = ship_dimensions.with_breadth(breadth);
+        }
+        if particulars.water_density <= 0.0 {
+            return Err(format!(
+                "Плотность забортной воды должна быть больше нуля: {} т/м^3.",
+                particulars.water_density
+            ));
+        }
+        let lightweight = input.lightweight;
+        let mut lw = Lightweight::new(lightweight.mass);
+        if let Some(lcg) = lightweight.lcg {
+            lw = lw.with_lcg(lcg);
+        }
+        if let Some(vcg) = lightweight.vcg {
+            lw = lw.with_vcg(vcg);
+        }
+        if let Some(tcg) = lightweight.tcg {
+            lw = lw.with_tcg(tcg);
+        }
+        let frames = Frames::new(input.frames)?;
+        BonjeanValidation::new(&frames, ship_dimensions)
+            .findings()
+            .check()?;
+        let limits = input.permissible_limits;
+        let mut permissible_limits = PermissibleLimits::new(
+            limits.share_force,
+            limits.hogging_moment,
+            limits.sagging_moment,
+        )?;
+        if let Some(shear_stress) = limits.shear_stress {
+            permissible_limits = permissible_limits.with_shear_stress(shear_stress)?;
+        }
+        let hydrostatic_curves = input.hydrostatic_curves.validate_input_data()?;
+        HydrostaticValidation::new(&hydrostatic_curves)
+            .findings()
+            .check()?;
+        let mut ship = Ship::new(
+            lw,
+            lightweight.distribution,
+            ship_dimensions,
+            WaterDensity::new(particulars.water_density),
+            frames,
+            hydrostatic_curves,
+        )
+        .with_permissible_limits(permissible_limits)
+        .with_compartments(Compartments::new(input.compartments));
+        if !input.cross_sections.is_empty() {
+            ship = ship.with_cross_sections(CrossSections::new(input.cross_sections)?);
+        }
+        Ok(ShipProject {
+            ship_name: particulars.ship_name,
+            unit_system: particulars.unit_system,
+            ship,
+        })
+    }
+
+    ///
+    /// Название судна.
+    pub fn ship_name(&self) -> &str {
+        &self.ship_name
+    }
+
+    ///
+    /// Система единиц вывода результатов.
+    pub fn unit_system(&self) -> UnitSystem {
+        self.unit_system
+    }
+
+    ///
+    /// Данные судна.
+    pub fn ship(&self) -> &Ship {
+        &self.ship
+    }
+}
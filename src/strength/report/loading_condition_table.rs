@@ -0,0 +1,275 @@
+use serde::Serialize;
+use tracing::instrument;
+
+use super::report_format::ReportFormat;
+use crate::{
+    core::{round::Round, system_of_units::Meters, unit_system::UnitSystem},
+    strength::{
+        displacement::water_allowance::WaterAllowance, load::shiploads::Shiploads,
+        ship::ship_model::Ship, strength::Strength,
+        strength_check::still_water_check::StrengthCheck,
+    },
+};
+
+///
+/// Строка таблицы нагрузок.
+/// Parameters:
+///     name - наименование статьи нагрузки,
+///     weight - масса [т],
+///     lcg - абсцисса центра тяжести относительно мидель шпангоута [м],
+///     longitudinal_moment - статический момент относительно мидель шпангоута [т*м],
+///     vcg - аппликата центра тяжести от основной плоскости [м], None - не задана,
+///     vertical_moment - статический момент относительно основной плоскости [т*м].
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct TableRow {
+    name: String,
+    weight: f64,
+    lcg: f64,
+    longitudinal_moment: f64,
+    vcg: Option<f64>,
+    vertical_moment: Option<f64>,
+}
+
+impl TableRow {
+    ///
+    /// Основной конструктор.
+    pub fn new(name: String, weight: f64, lcg: f64, vcg: Option<f64>) -> Self {
+        TableRow {
+            name,
+            weight: weight.my_round(2),
+            lcg: lcg.my_round(3),
+            longitudinal_moment: (weight * lcg).my_round(2),
+            vcg: vcg.map(|vcg| vcg.my_round(3)),
+            vertical_moment: vcg.map(|vcg| (weight * vcg).my_round(2)),
+        }
+    }
+
+    ///
+    /// Строка суммы нескольких строк.
+    /// Если аппликата центра тяжести хотя бы одной строки не задана, аппликата суммы не задается.
+    fn total(name: &str, rows: &[&TableRow]) -> Self {
+        let weight: f64 = rows.iter().map(|row| row.weight).sum();
+        let longitudinal_moment: f64 = rows.iter().map(|row| row.longitudinal_moment).sum();
+        let vertical_moment: Option<f64> = rows.iter().map(|row| row.vertical_moment).sum();
+        let (lcg, vcg) = match weight {
+            0.0 => (0.0, vertical_moment.map(|_| 0.0)),
+            _ => (
+                longitudinal_moment / weight,
+                vertical_moment.map(|moment| moment / weight),
+            ),
+        };
+        TableRow::new(name.to_string(), weight, lcg, vcg)
+    }
+
+    fn cells(&self) -> Vec<String> {
+        let optional = |value: Option<f64>| value.map_or("-".to_string(), |v| format!("{v:.2}"));
+        vec![
+            self.name.clone(),
+            format!("{:.2}", self.weight),
+            format!("{:.3}", self.lcg),
+            format!("{:.2}", self.longitudinal_moment),
+            optional(self.vcg),
+            optional(self.vertical_moment),
+        ]
+    }
+}
+
+///
+/// Таблица нагрузок судна (сводка по состоянию загрузки) в форме,
+/// принятой классификационными обществами: статьи дедвейта, итоги для судна порожнем,
+/// дедвейта и водоизмещения, осадки, дифферент, плотность забортной воды с поправками
+/// к осадке FWA и DWA и результаты проверки прочности.
+/// Внутренние силовые факторы выводятся в заданной системе единиц.
+#[derive(Debug, Serialize)]
+pub struct LoadingConditionTable {
+    items: Vec<TableRow>,
+    lightweight: TableRow,
+    deadweight: TableRow,
+    displacement: TableRow,
+    aft_draft: f64,
+    mean_draft: f64,
+    nose_draft: f64,
+    trim: f64,
+    water_density: f64,
+    fwa: Option<f64>,
+    dwa: Option<f64>,
+    strength_check: StrengthCheck,
+}
+
+const HEADER: [&str; 6] = [
+    "Статья нагрузки",
+    "Масса, т",
+    "LCG, м",
+    "Мx, т*м",
+    "VCG, м",
+    "Мz, т*м",
+];
+
+impl LoadingConditionTable {
+    ///
+    /// Выполняет расчет прочности и формирует таблицу нагрузок.
+    /// Parameters:
+    ///     ship - данные судна,
+    ///     shiploads - схема загрузки судна.
+    #[instrument(skip_all, err, target = "LoadingConditionTable::new")]
+    pub fn new(ship: &Ship, shiploads: &Shiploads) -> Result<Self, String> {
+        let strength = Strength::from_ship(ship, shiploads)?;
+        let (Meters(aft_draft), Meters(nose_draft)) = strength.draft()?;
+        let items: Vec<TableRow> = shiploads
+            .as_ref()
+            .iter()
+            .enumerate()
+            .map(|(i, shipload)| {
+                let lcg = match shipload.value().value() {
+                    0.0 => shipload.longitudinal_center_gravity(),
+                    value => shipload.moment().value() / value,
+                };
+                TableRow::new(
+                    shipload
+                        .name()
+                        .map_or(format!("Нагрузка {i}"), |name| name.to_string()),
+                    shipload.value().value(),
+                    lcg,
+                    Some(shipload.center_gravity().z),
+                )
+            })
+            .collect();
+        let lw = ship.lightweight();
+        let lightweight_lcg = lw.lcg().unwrap_or_else(|| {
+            let intensity = strength.lightweight_intensity();
+            let moment: f64 = intensity
+                .as_ref()
+                .iter()
+                .map(|spatium| spatium.integral() * spatium.abscissa())
+                .sum();
+            moment / intensity.integral().value()
+        });
+        let lightweight = TableRow::new(
+            "Судно порожнем".to_string(),
+            lw.lightweight().value(),
+            lightweight_lcg,
+            lw.vcg(),
+        );
+        let deadweight = TableRow::total("Дедвейт", &items.iter().collect::<Vec<&TableRow>>());
+        let displacement = TableRow::total("Водоизмещение", &[&lightweight, &deadweight]);
+        let mean_draft = (aft_draft + nose_draft) / 2.0;
+        let water_density = strength.water_density().value();
+        // Поправки не выводятся, если их невозможно определить
+        // (нет площади ватерлинии или плотность морской воды не больше плотности пресной).
+        let allowance = WaterAllowance::new(
+            ship.hydrostatic_curves(),
+            ship.water_density().water_density(),
+            strength.displacemnt_tonnage().value(),
+            mean_draft,
+        )
+        .ok();
+        Ok(LoadingConditionTable {
+            items,
+            lightweight,
+            deadweight,
+            displacement,
+            aft_draft,
+            mean_draft: mean_draft.my_round(2),
+            nose_draft,
+            trim: (nose_draft - aft_draft).my_round(2),
+            water_density,
+            fwa: allowance.map(|allowance| allowance.fwa().my_round(3)),
+            dwa: allowance.map(|allowance| allowance.dwa(water_density).my_round(3)),
+            strength_check: strength.strength_check(&ship.permissible_limits()?)?,
+        })
+    }
+
+    ///
+    /// Задает систему единиц для вывода внутренних силовых факторов.
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        self.strength_check = self.strength_check.with_unit_system(unit_system);
+        self
+    }
+
+    ///
+    /// Поправки к осадке на пресную воду и на плотность забортной воды (FWA [м], DWA [м]),
+    /// если их возможно определить.
+    pub fn water_allowance(&self) -> Option<(f64, f64)> {
+        self.fwa.zip(self.dwa)
+    }
+
+    ///
+    /// Формирует отчет в заданном формате.
+    pub fn render(&self, format: ReportFormat) -> String {
+        let items: Vec<Vec<String>> = self.items.iter().map(|row| row.cells()).collect();
+        let totals: Vec<Vec<String>> = [&self.lightweight, &self.deadweight, &self.displacement]
+            .iter()
+            .map(|row| row.cells())
+            .collect();
+        let value = |name: &str, value: f64| vec![name.to_string(), format!("{value:.2}")];
+        let precise = |name: &str, value: f64| vec![name.to_string(), format!("{value:.3}")];
+        let check = &self.strength_check;
+        let units = check.unit_system();
+        let mut floating = vec![
+            value("Осадка кормой, м", self.aft_draft),
+            value("Осадка на миделе, м", self.mean_draft),
+            value("Осадка носом, м", self.nose_draft),
+            value("Дифферент (+ на нос), м", self.trim),
+            precise("Плотность забортной воды, т/м^3", self.water_density),
+        ];
+        if let Some((fwa, dwa)) = self.water_allowance() {
+            floating.extend([
+                precise("Поправка на пресную воду FWA, м", fwa),
+                precise("Поправка на плотность воды DWA, м", dwa),
+                value("Средняя осадка в морской воде, м", self.mean_draft - dwa),
+            ]);
+        }
+        let mut strength = vec![
+            vec![
+                "Перерезывающая сила".to_string(),
+                format!("{:.2} {}", check.max_share_force(), units.force_unit()),
+                format!("{:.1}", check.share_force_utilisation() * 100.0),
+            ],
+            vec![
+                "Изгибающий момент (перегиб)".to_string(),
+                format!("{:.2} {}", check.max_hogging_moment(), units.moment_unit()),
+                format!("{:.1}", check.hogging_utilisation() * 100.0),
+            ],
+            vec![
+                "Изгибающий момент (прогиб)".to_string(),
+                format!("{:.2} {}", check.max_sagging_moment(), units.moment_unit()),
+                format!("{:.1}", check.sagging_utilisation() * 100.0),
+            ],
+        ];
+        if let (Some(max_shear_stress), Some(utilisation)) =
+            (check.max_shear_stress(), check.shear_stress_utilisation())
+        {
+            strength.push(vec![
+                "Касательное напряжение".to_string(),
+                format!("{max_shear_stress:.2} МПа"),
+                format!("{:.1}", utilisation * 100.0),
+            ]);
+        }
+        let sections = [
+            ("Статьи дедвейта", HEADER.to_vec(), items),
+            ("Итоги", HEADER.to_vec(), totals),
+            ("Посадка", vec!["Параметр", "Значение"], floating),
+            (
+                if check.passed() {
+                    "Прочность: обеспечена"
+                } else {
+                    "Прочность: не обеспечена"
+                },
+                vec!["Силовой фактор", "Наибольшее значение", "% от допускаемого"],
+                strength,
+            ),
+        ];
+        sections
+            .iter()
+            .map(|(title, header, rows)| {
+                let title = match format {
+                    ReportFormat::Markdown => format!("### {title}\n\n"),
+                    ReportFormat::Html => format!("<h3>{title}</h3>\n"),
+                    ReportFormat::Csv => format!("{title}\n"),
+                };
+                title + &format.table(header, rows)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
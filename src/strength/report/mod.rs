@@ -0,0 +1,2 @@
+pub mod loading_condition_table;
+pub mod report_format;
@@ -0,0 +1,86 @@
+use std::str::FromStr;
+
+///
+/// Формат вывода отчетов.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+    Csv,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(ReportFormat::Markdown),
+            "html" => Ok(ReportFormat::Html),
+            "csv" => Ok(ReportFormat::Csv),
+            _ => Err(format!(
+                "Неизвестный формат отчета: {s}. Допустимые форматы: markdown, html, csv."
+            )),
+        }
+    }
+}
+
+impl ReportFormat {
+    ///
+    /// Формирует таблицу в заданном формате.
+    /// Parameters:
+    ///     header - заголовки столбцов,
+    ///     rows - строки таблицы.
+    pub fn table(&self, header: &[&str], rows: &[Vec<String>]) -> String {
+        match self {
+            ReportFormat::Markdown => {
+                let mut table = format!("| {} |\n", header.join(" | "));
+                table += &format!("|{}\n", "---|".repeat(header.len()));
+                for row in rows {
+                    table += &format!("| {} |\n", row.join(" | "));
+                }
+                table
+            }
+            ReportFormat::Html => {
+                let cells = |row: &[String], tag: &str| {
+                    row.iter()
+                        .map(|cell| format!("<{tag}>{}</{tag}>", escape_html(cell)))
+                        .collect::<String>()
+                };
+                let header: Vec<String> = header.iter().map(|cell| cell.to_string()).collect();
+                let mut table = format!("<table>\n<tr>{}</tr>\n", cells(&header, "th"));
+                for row in rows {
+                    table += &format!("<tr>{}</tr>\n", cells(row, "td"));
+                }
+                table + "</table>\n"
+            }
+            ReportFormat::Csv => {
+                let line = |row: Vec<String>| {
+                    row.iter()
+                        .map(|cell| escape_csv(cell))
+                        .collect::<Vec<String>>()
+                        .join(",")
+                };
+                let mut table = line(header.iter().map(|cell| cell.to_string()).collect()) + "\n";
+                for row in rows {
+                    table += &(line(row.clone()) + "\n");
+                }
+                table
+            }
+        }
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
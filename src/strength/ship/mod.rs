@@ -1,3 +1,4 @@
 pub mod ship_dimensions;
+pub mod ship_model;
 pub mod spatium_function;
 pub mod spatium_functions;
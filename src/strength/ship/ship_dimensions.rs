@@ -1,18 +1,21 @@
 use serde::Deserialize;
 use tracing::instrument;
 
-use crate::core::json_file::JsonFile;
+use crate::core::{data_file::DataFile, system_of_units::Meters};
 
 ///
 /// ShipDimensions struct contains ship dimensions.
 /// - length_between_perpendiculars - https://en.wikipedia.org/wiki/Length_between_perpendiculars,
 /// - completeness_coefficient - коэффициент полноты корабля,
 /// - number_spatiums - количество теоретических шпаций,
+/// - breadth - ширина судна по ватерлинии [м], необязательный параметр.
 #[derive(Deserialize, Debug, Clone, Copy)]
 pub struct ShipDimensions {
     lbp: f64,
     number_spatiums: u64,
     completeness_coefficient: f64,
+    #[serde(default)]
+    breadth: Option<f64>,
 }
 
 impl ShipDimensions {
@@ -21,22 +24,28 @@ impl ShipDimensions {
             lbp,
             number_spatiums,
             completeness_coefficient,
+            breadth: None,
         }
     }
 
     ///
-    /// Create the object from json file.
-    #[instrument(skip_all, err, target = "ShipDimensions::from_json_file")]
-    pub fn from_json_file(file_path: String) -> Result<Self, String> {
-        let json = JsonFile::new(file_path);
-        let content = json.content().map_err(|err| err.to_string())?;
-        serde_json::from_reader(content).map_err(|err| err.to_string())
+    /// Задает ширину судна [м].
+    pub fn with_breadth(mut self, breadth: f64) -> Self {
+        self.breadth = Some(breadth);
+        self
+    }
+
+    ///
+    /// Create the object from json, toml or yaml file.
+    #[instrument(skip_all, err, target = "ShipDimensions::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        DataFile::new(file_path).deserialize()
     }
 
     ///
     /// Return length spatium
-    pub fn length_spatium(&self) -> f64 {
-        self.lbp / self.number_spatiums as f64
+    pub fn length_spatium(&self) -> Meters {
+        Meters(self.lbp / self.number_spatiums as f64)
     }
 
     ///
@@ -64,20 +73,26 @@ impl ShipDimensions {
 
     ///
     /// Return length bettwen perpendiculars
-    pub fn lbp(&self) -> f64 {
-        self.lbp
+    pub fn lbp(&self) -> Meters {
+        Meters(self.lbp)
+    }
+
+    ///
+    /// Return breadth of the ship, if it is defined.
+    pub fn breadth(&self) -> Option<Meters> {
+        self.breadth.map(Meters)
     }
 
     ///
     /// Return spatium start coordinate.
     pub fn spatium_start_coordinate(&self, id: u64) -> f64 {
-        id as f64 * self.length_spatium() - (self.lbp() / 2.0)
+        id as f64 * self.length_spatium().value() - (self.lbp / 2.0)
     }
 
     ///
     /// Return spatium end coordinate.
     pub fn spatium_end_coordinate(&self, id: u64) -> f64 {
-        self.spatium_start_coordinate(id) + self.length_spatium()
+        self.spatium_start_coordinate(id) + self.length_spatium().value()
     }
 
     ///
@@ -85,7 +100,7 @@ impl ShipDimensions {
     pub fn spatium_index_by_coordinate(&self, x: f64) -> u64 {
         // Если координата x выходит за пределы корабля(кормы или носа) необходимо выдывать соответствующую ошибку.
         let mut spatium_start_coordinate = -self.lbp / 2.0;
-        let mut spatium_end_coordinate = spatium_start_coordinate + self.length_spatium();
+        let mut spatium_end_coordinate = spatium_start_coordinate + self.length_spatium().value();
         let mut index = 0;
         for id in 0..self.number_spatiums {
             if x >= spatium_start_coordinate && x < spatium_end_coordinate {
@@ -93,7 +108,7 @@ impl ShipDimensions {
                 break;
             }
             spatium_start_coordinate = spatium_end_coordinate;
-            spatium_end_coordinate += self.length_spatium();
+            spatium_end_coordinate += self.length_spatium().value();
         }
         index
     }
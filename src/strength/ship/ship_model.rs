@@ -0,0 +1,188 @@
+use tracing::instrument;
+
+use super::ship_dimensions::ShipDimensions;
+use crate::{
+    core::water_density::WaterDensity,
+    cross_section_properties::cross_sections::CrossSections,
+    strength::{
+        bonjean_scale::frames::Frames,
+        compartment::compartments::Compartments,
+        hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
+        lightweight::{
+            lightweight::Lightweight, lightweight_distribution::LightweightDistribution,
+        },
+        strength_check::permissible_limits::PermissibleLimits,
+        validation::{
+            bonjean_validation::BonjeanValidation, hydrostatic_validation::HydrostaticValidation,
+        },
+    },
+};
+
+///
+/// Данные судна, не зависящие от схемы загрузки.
+/// Parameters:
+///     lw - масса пустого корпуса судна,
+///     lightweight_distribution - метод распределения массы судна порожнем по длине,
+///     ship_dimensions - размерения судна,
+///     water_density - плотность забортной воды,
+///     frames - шпангоуты судна (масштаб Бонжана),
+///     hydrostatic_curves - гидростатические кривые,
+///     permissible_limits - допускаемые значения внутренних силовых факторов (необязательные),
+///     compartments - грузовые помещения и цистерны судна,
+///     cross_sections - поперечные сечения корпуса (необязательные).
+#[derive(Debug, Clone)]
+pub struct Ship {
+    lw: Lightweight,
+    lightweight_distribution: LightweightDistribution,
+    ship_dimensions: ShipDimensions,
+    water_density: WaterDensity,
+    frames: Frames,
+    hydrostatic_curves: HydrostaticCurves,
+    permissible_limits: Option<PermissibleLimits>,
+    compartments: Compartments,
+    cross_sections: Option<CrossSections>,
+}
+
+impl Ship {
+    ///
+    /// Основной конструктор.
+    pub fn new(
+        lw: Lightweight,
+        lightweight_distribution: LightweightDistribution,
+        ship_dimensions: ShipDimensions,
+        water_density: WaterDensity,
+        frames: Frames,
+        hydrostatic_curves: HydrostaticCurves,
+    ) -> Self {
+        Ship {
+            lw,
+            lightweight_distribution,
+            ship_dimensions,
+            water_density,
+            frames,
+            hydrostatic_curves,
+            permissible_limits: None,
+            compartments: Compartments::default(),
+            cross_sections: None,
+        }
+    }
+
+    ///
+    /// Задает допускаемые значения внутренних силовых факторов,
+    /// необходимые для проверки прочности.
+    pub fn with_permissible_limits(mut self, permissible_limits: PermissibleLimits) -> Self {
+        self.permissible_limits = Some(permissible_limits);
+        self
+    }
+
+    ///
+    /// Задает грузовые помещения и цистерны судна.
+    pub fn with_compartments(mut self, compartments: Compartments) -> Self {
+        self.compartments = compartments;
+        self
+    }
+
+    ///
+    /// Задает поперечные сечения корпуса, необходимые для расчета прогиба корпуса.
+    pub fn with_cross_sections(mut self, cross_sections: CrossSections) -> Self {
+        self.cross_sections = Some(cross_sections);
+        self
+    }
+
+    ///
+    /// Вспомогательный конструктор.
+    /// Масштаб Бонжана проверяется на согласованность с размерениями судна (BonjeanValidation),
+    /// гидростатические кривые - на выпадающие точки (HydrostaticValidation).
+    /// Допускаемые значения внутренних силовых факторов загружаются, если они заданы в input_path.
+    /// Входные параметры:
+    ///     input_path - путь к файлу json, toml или yaml, содержащему основные данные о судне,
+    ///     frames_file - путь к файлу json или csv, содержащему масштаб Бонжана,
+    ///     hydrostatic_curves - пусть к файлу json или csv, содержащему гидростатические кривые судна.
+    #[instrument(skip_all, err, target = "Ship::from_files")]
+    pub fn from_files(
+        input_path: String,
+        frames_file: String,
+        hydrostatic_curves: String,
+    ) -> Result<Self, String> {
+        let ship_dimensions = ShipDimensions::from_file(input_path.clone())?;
+        let frames = Frames::from_file(frames_file)?;
+        BonjeanValidation::new(&frames, ship_dimensions)
+            .findings()
+            .check()?;
+        let hydrostatic_curves = HydrostaticCurves::from_file(hydrostatic_curves)?;
+        HydrostaticValidation::new(&hydrostatic_curves)
+            .findings()
+            .check()?;
+        let ship = Ship::new(
+            Lightweight::from_file(input_path.clone())?,
+            LightweightDistribution::from_file(input_path.clone())?,
+            ship_dimensions,
+            WaterDensity::from_file(input_path.clone())?,
+            frames,
+            hydrostatic_curves,
+        )
+        .with_compartments(Compartments::from_file(input_path.clone())?);
+        Ok(match PermissibleLimits::from_file(input_path)? {
+            Some(permissible_limits) => ship.with_permissible_limits(permissible_limits),
+            None => ship,
+        })
+    }
+
+    ///
+    /// Масса пустого корпуса судна.
+    pub fn lightweight(&self) -> Lightweight {
+        self.lw
+    }
+
+    ///
+    /// Метод распределения массы судна порожнем по длине.
+    pub fn lightweight_distribution(&self) -> &LightweightDistribution {
+        &self.lightweight_distribution
+    }
+
+    ///
+    /// Размерения судна.
+    pub fn ship_dimensions(&self) -> ShipDimensions {
+        self.ship_dimensions
+    }
+
+    ///
+    /// Плотность забортной воды.
+    pub fn water_density(&self) -> WaterDensity {
+        self.water_density
+    }
+
+    ///
+    /// Шпангоуты судна (масштаб Бонжана).
+    pub fn frames(&self) -> &Frames {
+        &self.frames
+    }
+
+    ///
+    /// Гидростатические кривые.
+    pub fn hydrostatic_curves(&self) -> &HydrostaticCurves {
+        &self.hydrostatic_curves
+    }
+
+    ///
+    /// Допускаемые значения внутренних силовых факторов.
+    /// Возвращает ошибку, если они не заданы в данных судна.
+    pub fn permissible_limits(&self) -> Result<PermissibleLimits, String> {
+        self.permissible_limits.ok_or(
+            "Допускаемые значения перерезывающей силы и изгибающего момента не заданы в данных судна."
+                .to_string(),
+        )
+    }
+
+    ///
+    /// Грузовые помещения и цистерны судна.
+    pub fn compartments(&self) -> &Compartments {
+        &self.compartments
+    }
+
+    ///
+    /// Поперечные сечения корпуса, если заданы.
+    pub fn cross_sections(&self) -> Option<&CrossSections> {
+        self.cross_sections.as_ref()
+    }
+}
@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
 use crate::core::round::Round;
@@ -8,7 +8,7 @@ use super::ship_dimensions::ShipDimensions;
 ///
 /// Содержит результаты расчета
 /// (интенсивность водоизмещение, перерезывающая сила, изгибающий момент и.т.д) для одной теоретической шпации судна.
-#[derive(Debug, PartialEq, Copy, Clone, Deserialize)]
+#[derive(Debug, PartialEq, Copy, Clone, Deserialize, Serialize)]
 pub struct SpatiumFunction {
     id: u64,
     x1: f64,
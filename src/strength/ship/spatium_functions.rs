@@ -1,11 +1,12 @@
 use super::spatium_function::SpatiumFunction;
-use serde::Deserialize;
+use crate::core::system_of_units::Tons;
+use serde::{Deserialize, Serialize};
 
 ///
 /// Содержит результаты вычислений
 /// (изгибающий момент, перерезывающая сила, интенсивности водоизмещения, дедвейта, сил поддержания и.т.д)
 /// для всех шпаций судна.
-#[derive(Debug, PartialEq, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone)]
 pub struct SpatiumFunctions {
     spatium_functions: Vec<SpatiumFunction>,
 }
@@ -51,12 +52,34 @@ impl SpatiumFunctions {
         max_value
     }
 
-    pub fn integral(&self) -> f64 {
+    ///
+    /// Возвращает эпюру, значения которой пересчитаны функцией convert
+    /// (например, при выводе в другой системе единиц).
+    pub fn converted(&self, convert: impl Fn(f64) -> f64) -> SpatiumFunctions {
+        SpatiumFunctions::new(
+            self.spatium_functions
+                .iter()
+                .map(|s_f| {
+                    SpatiumFunction::new(
+                        s_f.id(),
+                        s_f.x1(),
+                        s_f.x2(),
+                        convert(s_f.f_x1()),
+                        convert(s_f.f_x2()),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    ///
+    /// Интеграл эпюры интенсивности нагрузки [т/м] по длине судна - масса нагрузки [т].
+    pub fn integral(&self) -> Tons {
         let mut integral = 0.0;
         for s_f in &self.spatium_functions {
             integral += s_f.integral();
         }
-        integral
+        Tons(integral)
     }
 
     ///
@@ -90,6 +113,21 @@ impl SpatiumFunctions {
         }
     }
 
+    ///
+    /// Значение в сечении с абсциссой x, линейно интерполированное между концами шпации.
+    /// Возвращает None, если абсцисса находится вне шпаций.
+    pub fn value_at(&self, x: f64) -> Option<f64> {
+        let spatium = self
+            .spatium_functions
+            .iter()
+            .find(|spatium| spatium.x1() <= x && x <= spatium.x2())?;
+        Some(
+            spatium.f_x1()
+                + (spatium.f_x2() - spatium.f_x1()) * (x - spatium.x1())
+                    / (spatium.x2() - spatium.x1()),
+        )
+    }
+
     ///
     /// Возвращает ссылку на шпацию по ее id.
     pub fn get(&self, id: u64) -> Option<&SpatiumFunction> {
@@ -0,0 +1,175 @@
+use serde::Serialize;
+use tracing::instrument;
+
+use crate::{
+    core::{round::Round, system_of_units::Meters},
+    strength::{
+        buoyancy_intensity::side_drafts::SideDrafts, load::shiploads::Shiploads,
+        ship::ship_model::Ship, strength::Strength,
+    },
+};
+
+///
+/// Крен судна на тихой воде по начальной остойчивости.
+/// Ордината центра тяжести отсчитывается от диаметральной плоскости и положительна
+/// в сторону правого борта, угол крена положителен при крене на правый борт.
+/// Parameters:
+///     displacement_tonnage - весовое водоизмещение [т],
+///     kg - аппликата центра тяжести судна [м],
+///     tcg - ордината центра тяжести судна [м],
+///     km - аппликата поперечного метацентра [м],
+///     drafts - осадки на перпендикулярах и на миделе без учета крена [м], осадка на миделе
+///     определяется с учетом прогиба корпуса, если заданы поперечные сечения корпуса,
+///     breadth - ширина судна [м], если не задана, осадки по бортам не определяются.
+#[derive(Debug, Clone, Serialize)]
+pub struct Heel {
+    displacement_tonnage: f64,
+    kg: f64,
+    tcg: f64,
+    km: f64,
+    drafts: SideDrafts,
+    breadth: Option<f64>,
+}
+
+impl Heel {
+    ///
+    /// Выполняет расчет посадки судна и определяет крен для заданной схемы загрузки.
+    /// Требует задания аппликаты центра тяжести судна порожнем и кривой аппликаты
+    /// поперечного метацентра в гидростатических кривых. Если заданы поперечные сечения корпуса,
+    /// осадка на миделе определяется с учетом прогиба корпуса от изгибающего момента.
+    /// Parameters:
+    ///     ship - данные судна,
+    ///     shiploads - схема загрузки судна.
+    #[instrument(skip_all, err, target = "Heel::new")]
+    pub fn new(ship: &Ship, shiploads: &Shiploads) -> Result<Self, String> {
+        let lw = ship.lightweight();
+        let Some(lightweight_vcg) = lw.vcg() else {
+            return Err(
+                "Для расчета крена необходимо задать аппликату центра тяжести судна порожнем `lightweight_vcg`."
+                    .to_string(),
+            );
+        };
+        let strength = Strength::from_ship(ship, shiploads)?;
+        let (Meters(aft_draft), Meters(nose_draft)) = strength.draft()?;
+        let mean_draft = (aft_draft + nose_draft) / 2.0;
+        let drafts = match ship.cross_sections() {
+            Some(cross_sections) => strength
+                .deflection(cross_sections)?
+                .draft_marks(aft_draft, nose_draft)?,
+            None => SideDrafts {
+                aft: aft_draft,
+                mid: mean_draft,
+                nose: nose_draft,
+            },
+        };
+        let Some(km) = ship.hydrostatic_curves().km(mean_draft)? else {
+            return Err(format!(
+                "Аппликата поперечного метацентра `km` не задана в гидростатических кривых для осадки {} м.",
+                mean_draft.my_round(2)
+            ));
+        };
+        let lightweight = lw.lightweight().value();
+        let (weight, vertical_moment, transverse_moment) = shiploads.as_ref().iter().fold(
+            (
+                lightweight,
+                lightweight * lightweight_vcg,
+                lightweight * lw.tcg().unwrap_or_default(),
+            ),
+            |(weight, vertical_moment, transverse_moment), shipload| {
+                let center_gravity = shipload.center_gravity();
+                (
+                    weight + shipload.value().value(),
+                    vertical_moment + shipload.value().value() * center_gravity.z,
+                    transverse_moment + shipload.value().value() * center_gravity.y,
+                )
+            },
+        );
+        Ok(Heel {
+            displacement_tonnage: weight,
+            kg: vertical_moment / weight,
+            tcg: transverse_moment / weight,
+            km: km.value(),
+            drafts,
+            breadth: ship
+                .ship_dimensions()
+                .breadth()
+                .map(|breadth| breadth.value()),
+        })
+    }
+
+    ///
+    /// Аппликата центра тяжести судна [м].
+    pub fn kg(&self) -> f64 {
+        self.kg
+    }
+
+    ///
+    /// Ордината центра тяжести судна [м].
+    pub fn tcg(&self) -> f64 {
+        self.tcg
+    }
+
+    ///
+    /// Начальная поперечная метацентрическая высота GM = KM - KG [м].
+    pub fn metacentric_height(&self) -> f64 {
+        self.km - self.kg
+    }
+
+    ///
+    /// Кренящий момент от смещения центра тяжести от диаметральной плоскости [т*м].
+    pub fn heeling_moment(&self) -> f64 {
+        self.displacement_tonnage * self.tcg
+    }
+
+    ///
+    /// Угол крена [град]: tg(θ) = TCG / GM.
+    /// Возвращает ошибку, если начальная метацентрическая высота не положительна.
+    pub fn angle(&self) -> Result<f64, String> {
+        let gm = self.metacentric_height();
+        if gm <= 0.0 {
+            return Err(format!(
+                "Начальная метацентрическая высота {} м не положительна, крен по начальной остойчивости не определяется.",
+                gm.my_round(3)
+            ));
+        }
+        Ok((self.tcg / gm).atan().to_degrees())
+    }
+
+    ///
+    /// Осадки левого борта [м]. Возвращает None, если не задана ширина судна.
+    pub fn port_drafts(&self) -> Result<Option<SideDrafts>, String> {
+        self.side_drafts(-1.0)
+    }
+
+    ///
+    /// Осадки правого борта [м]. Возвращает None, если не задана ширина судна.
+    pub fn starboard_drafts(&self) -> Result<Option<SideDrafts>, String> {
+        self.side_drafts(1.0)
+    }
+
+    ///
+    /// Масса груза, который необходимо принять на расстоянии `arm` от диаметральной плоскости
+    /// для спрямления судна [т]. Отрицательное значение - груз необходимо снять.
+    /// Parameters:
+    ///     arm - ордината центра тяжести принимаемого груза [м].
+    pub fn correction_mass(&self, arm: f64) -> Result<f64, String> {
+        if arm == 0.0 {
+            return Err(
+                "Груз в диаметральной плоскости не создает спрямляющего момента.".to_string(),
+            );
+        }
+        Ok((-self.heeling_moment() / arm).my_round(2))
+    }
+
+    fn side_drafts(&self, side: f64) -> Result<Option<SideDrafts>, String> {
+        let Some(breadth) = self.breadth else {
+            return Ok(None);
+        };
+        let delta = side * breadth / 2.0 * self.angle()?.to_radians().tan();
+        Ok(Some(SideDrafts {
+            aft: (self.drafts.aft + delta).my_round(2),
+            mid: (self.drafts.mid + delta).my_round(2),
+            nose: (self.drafts.nose + delta).my_round(2),
+        }))
+    }
+}
@@ -0,0 +1,2 @@
+pub mod stowage_candidate;
+pub mod stowage_planner;
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::unit_system::UnitSystem, strength::strength_check::still_water_check::StrengthCheck,
+};
+
+///
+/// Схема распределения навалочного груза по трюмам.
+///     Homogeneous - однородная загрузка: груз принимается во все трюмы пропорционально их объему,
+///     AlternateHold - загрузка через трюм: груз принимается в нечетные трюмы (1, 3, 5, ...),
+///     BlockLoading - блочная загрузка: груз принимается в пары смежных трюмов,
+///         разделенные порожним трюмом (1, 2, 4, 5, ...).
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StowageStrategy {
+    Homogeneous,
+    AlternateHold,
+    BlockLoading,
+}
+
+impl StowageStrategy {
+    ///
+    /// Все схемы распределения груза.
+    pub fn all() -> Vec<StowageStrategy> {
+        vec![
+            StowageStrategy::Homogeneous,
+            StowageStrategy::AlternateHold,
+            StowageStrategy::BlockLoading,
+        ]
+    }
+
+    ///
+    /// Принимается ли груз в трюм с порядковым номером index (нумерация с нуля от кормы).
+    pub fn is_loaded(&self, index: usize) -> bool {
+        match self {
+            StowageStrategy::Homogeneous => true,
+            StowageStrategy::AlternateHold => index.is_multiple_of(2),
+            StowageStrategy::BlockLoading => index % 3 != 2,
+        }
+    }
+}
+
+///
+/// Груз в трюме.
+/// Parameters:
+///     name - наименование трюма,
+///     cargo - масса груза [т],
+///     filling - степень заполнения трюма по объему (от 0 до 1),
+///     tank_top_load - нагрузка на настил второго дна [т/м^2], если задана площадь настила.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HoldLoad {
+    name: String,
+    cargo: f64,
+    filling: f64,
+    tank_top_load: Option<f64>,
+}
+
+impl HoldLoad {
+    ///
+    /// Основной конструктор.
+    pub fn new(name: String, cargo: f64, filling: f64, tank_top_load: Option<f64>) -> Self {
+        HoldLoad {
+            name,
+            cargo,
+            filling,
+            tank_top_load,
+        }
+    }
+}
+
+///
+/// Вариант распределения груза по трюмам и результаты его расчета.
+/// Parameters:
+///     strategy - схема распределения груза,
+///     hold_loads - груз в трюмах,
+///     aft_draft, nose_draft - осадки кормой и носом [м],
+///     strength_check - результаты проверки прочности,
+///     error - причина, по которой вариант не может быть принят
+///         (груз не размещается в трюмах или расчет не выполнен).
+#[derive(Debug, Clone, Serialize)]
+pub struct StowageCandidate {
+    strategy: StowageStrategy,
+    hold_loads: Vec<HoldLoad>,
+    aft_draft: Option<f64>,
+    nose_draft: Option<f64>,
+    strength_check: Option<StrengthCheck>,
+    error: Option<String>,
+}
+
+impl StowageCandidate {
+    ///
+    /// Вариант, для которого выполнен расчет прочности.
+    pub fn new(
+        strategy: StowageStrategy,
+        hold_loads: Vec<HoldLoad>,
+        (aft_draft, nose_draft): (f64, f64),
+        strength_check: StrengthCheck,
+    ) -> Self {
+        StowageCandidate {
+            strategy,
+            hold_loads,
+            aft_draft: Some(aft_draft),
+            nose_draft: Some(nose_draft),
+            strength_check: Some(strength_check),
+            error: None,
+        }
+    }
+
+    ///
+    /// Вариант, который не может быть принят.
+    pub fn rejected(strategy: StowageStrategy, hold_loads: Vec<HoldLoad>, error: String) -> Self {
+        StowageCandidate {
+            strategy,
+            hold_loads,
+            aft_draft: None,
+            nose_draft: None,
+            strength_check: None,
+            error: Some(error),
+        }
+    }
+
+    ///
+    /// Пересчитывает силы и моменты в результатах проверки прочности в заданную систему единиц.
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        self.strength_check = self
+            .strength_check
+            .map(|check| check.with_unit_system(unit_system));
+        self
+    }
+
+    ///
+    /// Наибольшее отношение внутренних силовых факторов к допускаемым.
+    /// Для отклоненного варианта возвращает f64::INFINITY.
+    pub fn max_utilisation(&self) -> f64 {
+        self.strength_check
+            .map_or(f64::INFINITY, |check| check.max_utilisation())
+    }
+}
@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+
+use super::stowage_candidate::{HoldLoad, StowageCandidate, StowageStrategy};
+use crate::{
+    core::{
+        data_file::DataFile, round::Round, schema_version::SchemaVersion, system_of_units::Meters,
+    },
+    strength::{
+        compartment::ship_compartment::Compartment,
+        load::{load_category::LoadCategory, shiploads::Shiploads},
+        ship::ship_model::Ship,
+        strength::Strength,
+    },
+};
+
+///
+/// Версии формата файла размещения навалочного груза:
+///     1 - поля holds, cargo_mass, cargo_density и необязательное поле strategies.
+pub const STOWAGE_SCHEMA: SchemaVersion =
+    SchemaVersion::new("файла размещения навалочного груза", 1, &[]);
+
+///
+/// Планирование размещения навалочного груза по трюмам.
+/// Для каждой схемы распределения груза формирует вариант загрузки,
+/// выполняет расчет прочности и ранжирует варианты по наибольшему
+/// отношению внутренних силовых факторов к допускаемым.
+/// Parameters:
+///     holds - трюмы судна в порядке от кормы в нос,
+///     cargo_mass - общая масса груза [т],
+///     cargo_density - плотность груза (величина, обратная удельному погрузочному объему) [т/м^3],
+///     strategies - рассматриваемые схемы распределения груза (по умолчанию все).
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct StowagePlanner {
+    holds: Vec<Compartment>,
+    cargo_mass: f64,
+    cargo_density: f64,
+    #[serde(default = "StowageStrategy::all")]
+    strategies: Vec<StowageStrategy>,
+}
+
+impl StowagePlanner {
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Версия формата проверяется до разбора содержимого (STOWAGE_SCHEMA).
+    #[instrument(skip_all, err, target = "StowagePlanner::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let value: Value = DataFile::new(file_path.clone()).deserialize()?;
+        let value = STOWAGE_SCHEMA
+            .upgrade(value)
+            .map_err(|err| format!("Файл {file_path}: {err}"))?;
+        let planner: StowagePlanner =
+            serde_json::from_value(value).map_err(|err| err.to_string())?;
+        planner.validate_input_data()
+    }
+
+    ///
+    /// Валидация входных данных.
+    fn validate_input_data(self) -> Result<StowagePlanner, String> {
+        if self.holds.is_empty() {
+            return Err("Трюмы не заданы.".to_string());
+        }
+        if self.strategies.is_empty() {
+            return Err("Схемы распределения груза не заданы.".to_string());
+        }
+        if self.cargo_mass <= 0.0 || self.cargo_density <= 0.0 {
+            return Err("Масса и плотность груза должны быть больше нуля.".to_string());
+        }
+        Ok(self)
+    }
+
+    ///
+    /// Формирует и ранжирует варианты распределения груза по трюмам.
+    /// Первым в списке идет вариант с наименьшим отношением внутренних силовых
+    /// факторов к допускаемым, отклоненные варианты - в конце списка.
+    /// Parameters:
+    ///     ship - данные судна,
+    ///     base - схема загрузки судна без навалочного груза (запасы, балласт и т.д.).
+    #[instrument(skip_all, target = "StowagePlanner::plan")]
+    pub fn plan(&self, ship: &Ship, base: &Shiploads) -> Vec<StowageCandidate> {
+        let mut candidates: Vec<StowageCandidate> = self
+            .strategies
+            .iter()
+            .map(|strategy| self.candidate(ship, base, *strategy))
+            .collect();
+        candidates.sort_by(|a, b| a.max_utilisation().total_cmp(&b.max_utilisation()));
+        candidates
+    }
+
+    ///
+    /// Вариант загрузки для заданной схемы распределения груза.
+    fn candidate(
+        &self,
+        ship: &Ship,
+        base: &Shiploads,
+        strategy: StowageStrategy,
+    ) -> StowageCandidate {
+        let masses = match self.distribute(strategy) {
+            Ok(masses) => masses,
+            Err(err) => return StowageCandidate::rejected(strategy, vec![], err),
+        };
+        let hold_loads = self.hold_loads(&masses);
+        let mut shiploads = base.clone();
+        for (hold, mass) in self.holds.iter().zip(masses.iter()) {
+            if *mass > 0.0 {
+                shiploads.add(hold.shipload(
+                    mass / hold.capacity(self.cargo_density),
+                    self.cargo_density,
+                    LoadCategory::Cargo,
+                ));
+            }
+        }
+        let calculated = Strength::from_ship(ship, &shiploads).and_then(|strength| {
+            let (Meters(aft_draft), Meters(nose_draft)) = strength.draft()?;
+            Ok((
+                (aft_draft, nose_draft),
+                strength.strength_check(&ship.permissible_limits()?)?,
+            ))
+        });
+        match calculated {
+            Ok((drafts, strength_check)) => {
+                StowageCandidate::new(strategy, hold_loads, drafts, strength_check)
+            }
+            Err(err) => StowageCandidate::rejected(strategy, hold_loads, err),
+        }
+    }
+
+    ///
+    /// Распределяет груз по трюмам, принимаемым по заданной схеме, пропорционально их объему.
+    /// Если в трюм нельзя принять причитающуюся ему массу груза (ограничения по объему
+    /// или по нагрузке на настил второго дна), трюм загружается до предела,
+    /// а остаток груза распределяется между остальными трюмами.
+    /// Возвращает массы груза в трюмах [т].
+    fn distribute(&self, strategy: StowageStrategy) -> Result<Vec<f64>, String> {
+        let mut masses = vec![0.0; self.holds.len()];
+        let mut open: Vec<usize> = (0..self.holds.len())
+            .filter(|index| strategy.is_loaded(*index))
+            .collect();
+        let mut remaining = self.cargo_mass;
+        while remaining > 1.0e-6 && !open.is_empty() {
+            let total_volume: f64 = open.iter().map(|index| self.holds[*index].volume()).sum();
+            let mut capped = vec![];
+            for index in open.iter() {
+                let hold = &self.holds[*index];
+                let share = remaining * hold.volume() / total_volume;
+                let available = hold.max_cargo_mass(self.cargo_density) - masses[*index];
+                if share >= available {
+                    capped.push((*index, available));
+                }
+            }
+            if capped.is_empty() {
+                for index in open.iter() {
+                    masses[*index] += remaining * self.holds[*index].volume() / total_volume;
+                }
+                remaining = 0.0;
+            } else {
+                for (index, available) in capped.iter() {
+                    masses[*index] += available;
+                    remaining -= available;
+                }
+                open.retain(|index| capped.iter().all(|(capped, _)| capped != index));
+            }
+        }
+        if remaining > 1.0e-6 {
+            return Err(format!(
+                "Груз массой {} т не размещается в трюмах по схеме {:?}.",
+                remaining.my_round(2),
+                strategy
+            ));
+        }
+        Ok(masses)
+    }
+
+    ///
+    /// Груз в трюмах.
+    fn hold_loads(&self, masses: &[f64]) -> Vec<HoldLoad> {
+        self.holds
+            .iter()
+            .zip(masses.iter())
+            .map(|(hold, mass)| {
+                let tank_top_load = hold.tank_top_area().map(|area| mass / area);
+                HoldLoad::new(
+                    hold.name().to_string(),
+                    mass.my_round(2),
+                    (mass / hold.capacity(self.cargo_density)).my_round(3),
+                    tank_top_load.map(|load| load.my_round(2)),
+                )
+            })
+            .collect()
+    }
+}
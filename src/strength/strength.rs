@@ -8,15 +8,30 @@ use super::{
     ship::{ship_dimensions::ShipDimensions, spatium_functions::SpatiumFunctions},
 };
 use crate::{
-    core::water_density::WaterDensity,
+    core::{
+        system_of_units::{CubicMeters, Meters, TonMeters, Tons, TonsForce, TonsPerCubicMeter},
+        water_density::WaterDensity,
+    },
+    cross_section_properties::cross_sections::CrossSections,
     strength::{
-        bonjean_scale::{bonjean_scale::BonjeanScale, frames::Frames, lcb::LCB},
-        buoyancy_intensity::{buoyancy_intensity::BuoyancyIntensity, draft::Draft, lcg::LCG},
+        bonjean_scale::{bonjean_scale::BonjeanScale, lcb::LCB},
+        buoyancy_intensity::{
+            buoyancy_intensity::BuoyancyIntensity, draft::Draft, lcg::LCG, quick_draft::QuickDraft,
+        },
         deadweight::deadweight::Deadweight,
         displacement::{displacement::Displacement, displacement_tonnage::DisplacementTonnage},
-        hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
-        internal_forces::{bending_moment::BendingMoment, share_force::ShareForce},
+        internal_forces::{
+            bending_moment::BendingMoment, deflection::Deflection, share_force::ShareForce,
+            shear_stress::ShearStress, wave_bending_moment::WaveBendingMoment,
+        },
         load::total_shipload::TotalShipload,
+        ship::ship_model::Ship,
+        strength_check::{
+            buckling_check::BucklingCheck, permissible_limits::PermissibleLimits,
+            shear_stress_check::ShearStressCheck, still_water_check::StrengthCheck,
+            ultimate_strength_check::UltimateStrengthCheck,
+        },
+        validation::loading_validation::LoadingValidation,
     },
 };
 use std::rc::Rc;
@@ -58,6 +73,7 @@ pub struct Strength {
     water_density: WaterDensity,
     ship_dimensions: ShipDimensions,
     draft_: Draft,
+    cross_sections: Option<CrossSections>,
 }
 
 impl Strength {
@@ -98,9 +114,18 @@ impl Strength {
             water_density,
             ship_dimensions,
             draft_,
+            cross_sections: None,
         }
     }
 
+    ///
+    /// Задает поперечные сечения корпуса, по которым в проверку прочности
+    /// включается проверка касательных напряжений.
+    pub fn with_cross_sections(mut self, cross_sections: CrossSections) -> Self {
+        self.cross_sections = Some(cross_sections);
+        self
+    }
+
     ///
     /// Вспомогательный конструктор.
     /// Входные параметры:
@@ -115,17 +140,33 @@ impl Strength {
         frames_file: String,
         hydrostatic_curves: String,
     ) -> Result<Self, String> {
-        let lw = Lightweight::from_json_file(input_path.clone())?;
-        let ship_dimensions = ShipDimensions::from_json_file(input_path.clone())?;
-        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions.clone(), lw);
-        let shiploads = Shiploads::from_json_file(shiploads_file)?;
-        let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
-        let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i)?;
-        let dw = Deadweight::from_shiplods(&shiploads);
-        let d_t = DisplacementTonnage::new(lw, dw);
-        let water_density = WaterDensity::from_json_file(input_path.clone())?;
-        let frames = Frames::from_json_file(frames_file)?;
-        let bonjean_scale = Rc::new(BonjeanScale::new(frames, ship_dimensions));
+        let ship = Ship::from_files(input_path, frames_file, hydrostatic_curves)?;
+        let shiploads = Shiploads::from_file(shiploads_file)?;
+        Strength::from_ship(&ship, &shiploads)
+    }
+
+    ///
+    /// Вспомогательный конструктор.
+    /// Выполняет расчет прочности судна для заданной схемы загрузки.
+    /// Перед расчетом выполняется проверка схемы загрузки (LoadingValidation).
+    /// Используется плотность забортной воды, заданная для схемы загрузки,
+    /// а если она не задана - плотность из данных судна.
+    /// Входные параметры:
+    ///     ship - данные судна, не зависящие от схемы загрузки,
+    ///     shiploads - нагрузки, действующие на судно.
+    #[instrument(skip_all, err, target = "Strength::from_ship")]
+    pub fn from_ship(ship: &Ship, shiploads: &Shiploads) -> Result<Self, String> {
+        let LoadingMasses {
+            lw_i,
+            dw_i,
+            disp_i,
+            dw,
+            d_t,
+            water_density,
+        } = LoadingMasses::new(ship, shiploads)?;
+        let lw = ship.lightweight();
+        let ship_dimensions = ship.ship_dimensions();
+        let bonjean_scale = Rc::new(BonjeanScale::new(ship.frames().clone(), ship_dimensions));
         let disp = Rc::new(Displacement::new(
             bonjean_scale.clone(),
             ship_dimensions,
@@ -133,8 +174,9 @@ impl Strength {
         ));
         let lcb = Rc::new(LCB::new(bonjean_scale.clone(), ship_dimensions));
         let lcg = LCG::from_disp_i(&disp_i);
-        let hydrostatic_curves = HydrostaticCurves::from_json_file(hydrostatic_curves)?;
-        let draft = Draft::new(lcb.clone(), disp.clone(), lcg, d_t, hydrostatic_curves);
+        let hydrostatic_curves = ship.hydrostatic_curves().clone();
+        let draft = Draft::new(lcb.clone(), disp.clone(), lcg, d_t, hydrostatic_curves)
+            .with_sea_water_density(ship.water_density());
         let b_i =
             BuoyancyIntensity::constructor(ship_dimensions, &draft, &bonjean_scale, water_density)?;
         let total_shipload = TotalShipload::from_disp_i_and_b_i(&disp_i, &b_i)?;
@@ -142,7 +184,7 @@ impl Strength {
             ShareForce::from_total_ship_load(&total_shipload).with_correction(ship_dimensions);
         let bending_moment =
             BendingMoment::from_share_force(&share_force).with_correction(ship_dimensions);
-        Ok(Strength::new(
+        let strength = Strength::new(
             lw,
             lw_i,
             dw,
@@ -159,7 +201,11 @@ impl Strength {
             water_density,
             ship_dimensions,
             draft,
-        ))
+        );
+        Ok(match ship.cross_sections() {
+            Some(cross_sections) => strength.with_cross_sections(cross_sections.clone()),
+            None => strength,
+        })
     }
 
     ///
@@ -169,15 +215,15 @@ impl Strength {
     }
 
     ///
-    /// Масса пустого корпуса судна. Размерность: [т]
-    pub fn lightweight(&self) -> f64 {
+    /// Масса пустого корпуса судна.
+    pub fn lightweight(&self) -> Tons {
         self.lw.lightweight()
     }
 
     ///
-    /// Общая масса груза на судне. Размерность: [т].
-    pub fn deadweight(&self) -> f64 {
-        self.dw.deadweight()
+    /// Общая масса груза на судне.
+    pub fn deadweight(&self) -> Tons {
+        Tons(self.dw.deadweight())
     }
 
     ///
@@ -188,9 +234,17 @@ impl Strength {
 
     ///
     /// Объемное водоизмещение судна. Размерность: [м^3].
-    pub fn displacement(&self) -> Result<f64, String> {
+    pub fn displacement(&self) -> Result<CubicMeters, String> {
         let (aft_draft, nose_draft) = self.draft_.draft(self.ship_dimensions)?;
-        self.disp.displacement_by_drafts(aft_draft, nose_draft)
+        self.disp
+            .displacement_by_drafts(aft_draft, nose_draft)
+            .map(CubicMeters)
+    }
+
+    ///
+    /// Плотность забортной воды, принятая в расчете.
+    pub fn water_density(&self) -> TonsPerCubicMeter {
+        TonsPerCubicMeter(self.water_density.water_density())
     }
 
     ///
@@ -200,31 +254,38 @@ impl Strength {
     }
 
     ///
-    /// Весовое водоизмещение судна. Размерность: [т].
-    pub fn displacemnt_tonnage(&self) -> f64 {
-        self.d_t.displacement_tonnage()
+    /// Весовое водоизмещение судна.
+    pub fn displacemnt_tonnage(&self) -> Tons {
+        Tons(self.d_t.displacement_tonnage())
     }
 
     ///
     /// Осадка судна при текущей схеме загрузки.
     /// Возвращает осадку кормы и носа судна (aft_draft [м], nose_nose [м]).
-    pub fn draft(&self) -> Result<(f64, f64), String> {
-        self.draft_.draft(self.ship_dimensions)
+    pub fn draft(&self) -> Result<(Meters, Meters), String> {
+        let (aft_draft, nose_draft) = self.draft_.draft(self.ship_dimensions)?;
+        Ok((Meters(aft_draft), Meters(nose_draft)))
+    }
+
+    ///
+    /// Быстрая оценка посадки судна по гидростатическим кривым без удифферентовки.
+    pub fn quick_draft(&self) -> Result<QuickDraft, String> {
+        self.draft_.quick_draft(self.ship_dimensions)
     }
 
     ///
     /// Абсцисса центра велечины (центр тяжести погруженного объема судна). Размерность: [м].
     /// Отсчитывается от мидель шпангоута. Имеет положительный знак от мидель шпангоута в нос судна.
-    pub fn lcb(&self) -> Result<f64, String> {
+    pub fn lcb(&self) -> Result<Meters, String> {
         let (aft_draft, nose_draft) = self.draft_.draft(self.ship_dimensions)?;
-        self.lcb_.lcb(aft_draft, nose_draft)
+        self.lcb_.lcb(aft_draft, nose_draft).map(Meters)
     }
 
     ///
     /// Абсцисса центра тяжести судна. Размерность: [м].
     /// Отсчитывается от мидель шпангоута. Имеет положительный знак от мидель шпангоута в нос судна.
-    pub fn lcg(&self) -> f64 {
-        self.lcg_.lcg()
+    pub fn lcg(&self) -> Meters {
+        Meters(self.lcg_.lcg())
     }
 
     ///
@@ -263,4 +324,155 @@ impl Strength {
     pub fn bending_moment_with_correction(&self) -> Option<&SpatiumFunctions> {
         self.bending_moment_.bending_moment_with_correction()
     }
+
+    ///
+    /// Прогиб корпуса судна от изгибающего момента (с поправкой, если он построен).
+    /// Parameters:
+    ///     cross_sections - поперечные сечения корпуса.
+    pub fn deflection(&self, cross_sections: &CrossSections) -> Result<Deflection, String> {
+        Deflection::from_bending_moment(&self.bending_moment_, cross_sections)
+    }
+
+    ///
+    /// Касательные напряжения в обшивке борта и продольных переборках от перерезывающей силы
+    /// (с поправкой, если она построена).
+    /// Parameters:
+    ///     cross_sections - поперечные сечения корпуса.
+    pub fn shear_stress(&self, cross_sections: &CrossSections) -> Result<ShearStress, String> {
+        ShearStress::from_share_force(&self.share_force_, cross_sections)
+    }
+
+    ///
+    /// Наибольшая по модулю перерезывающая сила (с поправкой, если она построена).
+    pub fn max_share_force(&self) -> Option<TonsForce> {
+        self.share_force_.max()
+    }
+
+    ///
+    /// Наибольший по модулю изгибающий момент (с поправкой, если он построен).
+    pub fn max_bending_moment(&self) -> Option<TonMeters> {
+        self.bending_moment_.max()
+    }
+
+    ///
+    /// Проверка общей продольной прочности судна на тихой воде.
+    /// Используются эпюры перерезывающих сил и изгибающих моментов с поправкой, если они построены.
+    /// Если заданы поперечные сечения корпуса, проверяются также касательные напряжения.
+    pub fn strength_check(&self, limits: &PermissibleLimits) -> Result<StrengthCheck, String> {
+        let share_force = self
+            .share_force_with_correction()
+            .unwrap_or(self.share_force());
+        let bending_moment = self
+            .bending_moment_with_correction()
+            .unwrap_or(self.bending_moment());
+        let check = StrengthCheck::new(share_force, bending_moment, limits);
+        Ok(match &self.cross_sections {
+            Some(cross_sections) => {
+                check.with_shear_stress_check(&self.shear_stress_check(cross_sections, limits)?)
+            }
+            None => check,
+        })
+    }
+
+    ///
+    /// Проверка касательных напряжений во всех расчетных сечениях по длине судна.
+    /// Parameters:
+    ///     cross_sections - поперечные сечения корпуса,
+    ///     limits - допускаемые значения.
+    pub fn shear_stress_check(
+        &self,
+        cross_sections: &CrossSections,
+        limits: &PermissibleLimits,
+    ) -> Result<ShearStressCheck, String> {
+        Ok(ShearStressCheck::new(
+            &self.shear_stress(cross_sections)?,
+            limits,
+        ))
+    }
+
+    ///
+    /// Проверка устойчивости и текучести панелей палубы и днища от общего изгиба
+    /// во всех расчетных сечениях. Используется эпюра изгибающих моментов с поправкой, если она построена.
+    /// Parameters:
+    ///     cross_sections - поперечные сечения корпуса с заданными панелями палубы и днища.
+    pub fn buckling_check(&self, cross_sections: &CrossSections) -> Result<BucklingCheck, String> {
+        let bending_moment = self
+            .bending_moment_with_correction()
+            .unwrap_or(self.bending_moment());
+        BucklingCheck::new(bending_moment, cross_sections)
+    }
+
+    ///
+    /// Запасы предельной прочности корпуса (метод Смита) относительно суммы изгибающего момента
+    /// на тихой воде (с поправкой, если она построена) и волнового момента.
+    /// Parameters:
+    ///     cross_sections - поперечные сечения корпуса.
+    pub fn ultimate_strength_check(
+        &self,
+        cross_sections: &CrossSections,
+    ) -> Result<UltimateStrengthCheck, String> {
+        let bending_moment = self
+            .bending_moment_with_correction()
+            .unwrap_or(self.bending_moment());
+        UltimateStrengthCheck::new(
+            bending_moment,
+            &WaveBendingMoment::new(self.ship_dimensions)?,
+            cross_sections,
+        )
+    }
+}
+
+///
+/// Распределение масс судна для схемы загрузки - общая часть расчета прочности (Strength)
+/// и быстрой оценки посадки (QuickDraft).
+/// Перед расчетом выполняется проверка схемы загрузки (LoadingValidation).
+/// Используется плотность забортной воды, заданная для схемы загрузки,
+/// а если она не задана - плотность из данных судна.
+/// Parameters:
+///     lw_i - интенсивность массы пустого корпуса судна по длине [т/м],
+///     dw_i - интенсивность дедвейта по длине судна [т/м],
+///     disp_i - интенсивность весового водоизмещения судна по его длине [т/м],
+///     dw - дедвейт [т],
+///     d_t - весовое водоизмещение судна [т],
+///     water_density - плотность забортной воды [т/м^3].
+pub(crate) struct LoadingMasses {
+    pub(crate) lw_i: LightweightIntensity,
+    pub(crate) dw_i: DeadweightIntensity,
+    pub(crate) disp_i: DisplacementIntensity,
+    pub(crate) dw: Deadweight,
+    pub(crate) d_t: DisplacementTonnage,
+    pub(crate) water_density: WaterDensity,
+}
+
+impl LoadingMasses {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     ship - данные судна, не зависящие от схемы загрузки,
+    ///     shiploads - нагрузки, действующие на судно.
+    pub(crate) fn new(ship: &Ship, shiploads: &Shiploads) -> Result<Self, String> {
+        LoadingValidation::new(ship, shiploads).findings().check()?;
+        let lw = ship.lightweight();
+        let ship_dimensions = ship.ship_dimensions();
+        let water_density = shiploads
+            .water_density()
+            .map_or(ship.water_density(), WaterDensity::new);
+        let lw_i = LightweightIntensity::from_distribution(
+            ship_dimensions,
+            lw,
+            ship.lightweight_distribution(),
+        )?;
+        let dw_i = DeadweightIntensity::builder(shiploads, ship_dimensions).build();
+        let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i)?;
+        let dw = Deadweight::from_shiplods(shiploads);
+        let d_t = DisplacementTonnage::new(lw, dw);
+        Ok(LoadingMasses {
+            lw_i,
+            dw_i,
+            disp_i,
+            dw,
+            d_t,
+            water_density,
+        })
+    }
 }
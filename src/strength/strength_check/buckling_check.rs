@@ -0,0 +1,180 @@
+use serde::Serialize;
+use tracing::instrument;
+
+use crate::{
+    core::{
+        round::Round,
+        system_of_units::{MegaPascals, TonsPerSquareMeter},
+    },
+    cross_section_properties::{cross_sections::CrossSections, stiffened_panel::PanelLocation},
+    strength::ship::spatium_functions::SpatiumFunctions,
+};
+
+///
+/// Напряжения и коэффициенты использования подкрепленной панели в расчетном сечении.
+/// Parameters:
+///     element - индекс элемента сечения, к которому относится панель,
+///     location - расположение панели (палуба, днище),
+///     stress - нормальное напряжение от общего изгиба, положительное при растяжении [МПа],
+///     critical_stress - критическое напряжение панели с поправкой Джонсона-Остенфельда [МПа],
+///     buckling_utilisation - отношение сжимающего напряжения к критическому,
+///     yield_utilisation - отношение модуля напряжения к пределу текучести.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PanelStress {
+    pub element: usize,
+    pub location: PanelLocation,
+    pub stress: f64,
+    pub critical_stress: f64,
+    pub buckling_utilisation: f64,
+    pub yield_utilisation: f64,
+}
+
+///
+/// Проверка панелей в расчетном сечении (на границе шпации).
+/// Parameters:
+///     abscissa - абсцисса расчетного сечения [м],
+///     bending_moment - изгибающий момент [т * м],
+///     panels - напряжения в панелях палубы и днища.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StationBuckling {
+    pub abscissa: f64,
+    pub bending_moment: f64,
+    pub panels: Vec<PanelStress>,
+}
+
+impl StationBuckling {
+    ///
+    /// Наибольший коэффициент использования по устойчивости в сечении.
+    pub fn buckling_utilisation(&self) -> f64 {
+        self.panels
+            .iter()
+            .fold(0.0_f64, |max, panel| max.max(panel.buckling_utilisation))
+    }
+
+    ///
+    /// Наибольший коэффициент использования по текучести в сечении.
+    pub fn yield_utilisation(&self) -> f64 {
+        self.panels
+            .iter()
+            .fold(0.0_f64, |max, panel| max.max(panel.yield_utilisation))
+    }
+}
+
+///
+/// Проверка устойчивости и текучести подкрепленных панелей палубы и днища при общем изгибе
+/// во всех расчетных сечениях по длине судна.
+/// Нормальное напряжение в панели: sigma = M * (z - z_na) / I, где момент инерции I и аппликата
+/// нейтральной оси z_na интерполируются по абсциссе, а панели принимаются по ближайшему
+/// поперечному сечению. Знак изгибающего момента: M > 0 - перегиб, сжаты панели днища.
+/// Parameters:
+///     stations - результаты проверки в расчетных сечениях,
+///     max_buckling_utilisation - наибольший коэффициент использования по устойчивости,
+///     max_yield_utilisation - наибольший коэффициент использования по текучести.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BucklingCheck {
+    stations: Vec<StationBuckling>,
+    max_buckling_utilisation: f64,
+    max_yield_utilisation: f64,
+}
+
+impl BucklingCheck {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     bending_moment - эпюра изгибающих моментов [т * м],
+    ///     cross_sections - поперечные сечения корпуса с заданными панелями палубы и днища.
+    #[instrument(skip_all, err, target = "BucklingCheck::new")]
+    pub fn new(
+        bending_moment: &SpatiumFunctions,
+        cross_sections: &CrossSections,
+    ) -> Result<Self, String> {
+        if !cross_sections.sections().iter().any(|section| {
+            section
+                .elements()
+                .iter()
+                .any(|element| element.panel().is_some())
+        }) {
+            return Err(
+                "В поперечных сечениях корпуса не заданы панели палубы и днища.".to_string(),
+            );
+        }
+        let mut nodes = vec![];
+        for (index, spatium) in bending_moment.as_ref().iter().enumerate() {
+            if index == 0 {
+                nodes.push((spatium.x1(), spatium.f_x1()));
+            }
+            nodes.push((spatium.x2(), spatium.f_x2()));
+        }
+        let mut stations = vec![];
+        for (abscissa, moment) in nodes {
+            let moment_of_inertia = cross_sections.moment_of_inertia(abscissa)?;
+            let neutral_axis = cross_sections.neutral_axis(abscissa)?;
+            let mut panels = vec![];
+            for (element_index, element) in cross_sections
+                .nearest(abscissa)
+                .elements()
+                .iter()
+                .enumerate()
+            {
+                let Some(panel) = element.panel() else {
+                    continue;
+                };
+                let stress = MegaPascals::from(TonsPerSquareMeter(
+                    moment * (element.z() - neutral_axis) / moment_of_inertia,
+                ))
+                .value();
+                let yield_stress = element.yield_stress();
+                let critical_stress = panel.critical_stress(element.thickness(), yield_stress);
+                panels.push(PanelStress {
+                    element: element_index,
+                    location: panel.location(),
+                    stress: stress.my_round(2),
+                    critical_stress: critical_stress.my_round(2),
+                    buckling_utilisation: ((-stress).max(0.0) / critical_stress).my_round(3),
+                    yield_utilisation: (stress.abs() / yield_stress).my_round(3),
+                });
+            }
+            stations.push(StationBuckling {
+                abscissa,
+                bending_moment: moment.my_round(2),
+                panels,
+            });
+        }
+        let max_buckling_utilisation = stations.iter().fold(0.0_f64, |max, station| {
+            max.max(station.buckling_utilisation())
+        });
+        let max_yield_utilisation = stations
+            .iter()
+            .fold(0.0_f64, |max, station| max.max(station.yield_utilisation()));
+        Ok(BucklingCheck {
+            stations,
+            max_buckling_utilisation,
+            max_yield_utilisation,
+        })
+    }
+
+    ///
+    /// Результаты проверки в расчетных сечениях.
+    pub fn stations(&self) -> &Vec<StationBuckling> {
+        &self.stations
+    }
+
+    ///
+    /// Наибольший коэффициент использования по устойчивости.
+    pub fn max_buckling_utilisation(&self) -> f64 {
+        self.max_buckling_utilisation
+    }
+
+    ///
+    /// Наибольший коэффициент использования по текучести.
+    pub fn max_yield_utilisation(&self) -> f64 {
+        self.max_yield_utilisation
+    }
+
+    ///
+    /// Прочность обеспечена, если ни в одном расчетном сечении панели не теряют устойчивость
+    /// и напряжения не превышают предел текучести.
+    pub fn passed(&self) -> bool {
+        self.max_buckling_utilisation <= 1.0 && self.max_yield_utilisation <= 1.0
+    }
+}
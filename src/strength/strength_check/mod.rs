@@ -0,0 +1,5 @@
+pub mod buckling_check;
+pub mod permissible_limits;
+pub mod shear_stress_check;
+pub mod still_water_check;
+pub mod ultimate_strength_check;
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::core::data_file::DataFile;
+
+///
+/// Допускаемые значения внутренних силовых факторов на тихой воде.
+/// Parameters:
+///     permissible_share_force - допускаемая перерезывающая сила (по модулю) [т],
+///     permissible_hogging_moment - допускаемый изгибающий момент на перегиб (M > 0) [т * м],
+///     permissible_sagging_moment - допускаемый изгибающий момент на прогиб (M < 0), задается по модулю [т * м],
+///     permissible_shear_stress - допускаемое касательное напряжение в обшивке борта и продольных переборках [МПа].
+///     Если не задано, принимается DEFAULT_PERMISSIBLE_SHEAR_STRESS.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct PermissibleLimits {
+    permissible_share_force: f64,
+    permissible_hogging_moment: f64,
+    permissible_sagging_moment: f64,
+    #[serde(default)]
+    permissible_shear_stress: Option<f64>,
+}
+
+///
+/// Допускаемое касательное напряжение для судостроительной стали нормальной прочности [МПа].
+pub const DEFAULT_PERMISSIBLE_SHEAR_STRESS: f64 = 110.0;
+
+impl PermissibleLimits {
+    ///
+    /// Основной конструктор.
+    pub fn new(
+        permissible_share_force: f64,
+        permissible_hogging_moment: f64,
+        permissible_sagging_moment: f64,
+    ) -> Result<Self, String> {
+        (PermissibleLimits {
+            permissible_share_force,
+            permissible_hogging_moment,
+            permissible_sagging_moment,
+            permissible_shear_stress: None,
+        })
+        .validate_input_data()
+    }
+
+    ///
+    /// Задает допускаемое касательное напряжение [МПа].
+    pub fn with_shear_stress(mut self, permissible_shear_stress: f64) -> Result<Self, String> {
+        self.permissible_shear_stress = Some(permissible_shear_stress);
+        self.validate_input_data()
+    }
+
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Возвращает None, если в файле не задано ни одно из допускаемых значений
+    /// перерезывающей силы и изгибающего момента.
+    #[instrument(skip_all, err, target = "PermissibleLimits::from_file")]
+    pub fn from_file(file_path: String) -> Result<Option<Self>, String> {
+        let value: serde_json::Value = DataFile::new(file_path).deserialize()?;
+        let is_set = [
+            "permissible_share_force",
+            "permissible_hogging_moment",
+            "permissible_sagging_moment",
+        ]
+        .iter()
+        .any(|field| value.get(field).is_some());
+        if !is_set {
+            return Ok(None);
+        }
+        let limits: PermissibleLimits =
+            serde_json::from_value(value).map_err(|err| err.to_string())?;
+        limits.validate_input_data().map(Some)
+    }
+
+    ///
+    /// Валидация: допускаемые значения должны быть больше нуля.
+    fn validate_input_data(self) -> Result<PermissibleLimits, String> {
+        if self.permissible_share_force <= 0.0
+            || self.permissible_hogging_moment <= 0.0
+            || self.permissible_sagging_moment <= 0.0
+        {
+            return Err("Допускаемые значения перерезывающей силы и изгибающего момента должны быть больше нуля.".to_string());
+        }
+        if let Some(shear_stress) = self.permissible_shear_stress {
+            if shear_stress <= 0.0 {
+                return Err(format!(
+                    "Допускаемое касательное напряжение должно быть больше нуля: {shear_stress} МПа."
+                ));
+            }
+        }
+        Ok(self)
+    }
+
+    ///
+    /// Допускаемая перерезывающая сила [т].
+    pub fn share_force(&self) -> f64 {
+        self.permissible_share_force
+    }
+
+    ///
+    /// Допускаемый изгибающий момент на перегиб [т * м].
+    pub fn hogging_moment(&self) -> f64 {
+        self.permissible_hogging_moment
+    }
+
+    ///
+    /// Допускаемый изгибающий момент на прогиб (по модулю) [т * м].
+    pub fn sagging_moment(&self) -> f64 {
+        self.permissible_sagging_moment
+    }
+
+    ///
+    /// Допускаемое касательное напряжение [МПа].
+    pub fn shear_stress(&self) -> f64 {
+        self.permissible_shear_stress
+            .unwrap_or(DEFAULT_PERMISSIBLE_SHEAR_STRESS)
+    }
+}
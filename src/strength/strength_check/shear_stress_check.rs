@@ -0,0 +1,107 @@
+use serde::Serialize;
+
+use super::permissible_limits::PermissibleLimits;
+use crate::{core::round::Round, strength::internal_forces::shear_stress::ShearStress};
+
+///
+/// Касательное напряжение в расчетном сечении (на границе шпации).
+/// Parameters:
+///     abscissa - абсцисса сечения [м],
+///     shear_stress - касательное напряжение [МПа],
+///     utilisation - отношение модуля касательного напряжения к допускаемому.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct StationShearStress {
+    pub abscissa: f64,
+    pub shear_stress: f64,
+    pub utilisation: f64,
+}
+
+///
+/// Проверка касательных напряжений в обшивке борта и продольных переборках
+/// во всех расчетных сечениях по длине судна.
+/// Parameters:
+///     permissible_shear_stress - допускаемое касательное напряжение [МПа],
+///     max_shear_stress - наибольшее по модулю касательное напряжение [МПа],
+///     max_utilisation - наибольшее отношение касательного напряжения к допускаемому,
+///     stations - касательные напряжения в расчетных сечениях.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ShearStressCheck {
+    permissible_shear_stress: f64,
+    max_shear_stress: f64,
+    max_utilisation: f64,
+    stations: Vec<StationShearStress>,
+}
+
+impl ShearStressCheck {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     shear_stress - касательные напряжения по длине судна,
+    ///     limits - допускаемые значения.
+    pub fn new(shear_stress: &ShearStress, limits: &PermissibleLimits) -> Self {
+        let permissible_shear_stress = limits.shear_stress();
+        let spatiums = shear_stress.shear_stress().as_ref();
+        let mut stations = vec![];
+        for (index, spatium) in spatiums.iter().enumerate() {
+            let mut nodes = vec![(spatium.x2(), spatium.f_x2())];
+            if index == 0 {
+                nodes.insert(0, (spatium.x1(), spatium.f_x1()));
+            }
+            for (abscissa, stress) in nodes {
+                stations.push(StationShearStress {
+                    abscissa,
+                    shear_stress: stress.my_round(2),
+                    utilisation: (stress.abs() / permissible_shear_stress).my_round(3),
+                });
+            }
+        }
+        let max_shear_stress = shear_stress.max().value();
+        ShearStressCheck {
+            permissible_shear_stress,
+            max_shear_stress: max_shear_stress.my_round(2),
+            max_utilisation: (max_shear_stress / permissible_shear_stress).my_round(3),
+            stations,
+        }
+    }
+
+    ///
+    /// Допускаемое касательное напряжение [МПа].
+    pub fn permissible_shear_stress(&self) -> f64 {
+        self.permissible_shear_stress
+    }
+
+    ///
+    /// Наибольшее по модулю касательное напряжение [МПа].
+    pub fn max_shear_stress(&self) -> f64 {
+        self.max_shear_stress
+    }
+
+    ///
+    /// Наибольшее отношение касательного напряжения к допускаемому.
+    pub fn max_utilisation(&self) -> f64 {
+        self.max_utilisation
+    }
+
+    ///
+    /// Касательные напряжения в расчетных сечениях.
+    pub fn stations(&self) -> &Vec<StationShearStress> {
+        &self.stations
+    }
+
+    ///
+    /// Расчетные сечения, в которых касательное напряжение превышает допускаемое.
+    pub fn failed_stations(&self) -> Vec<StationShearStress> {
+        self.stations
+            .iter()
+            .filter(|station| station.utilisation > 1.0)
+            .copied()
+            .collect()
+    }
+
+    ///
+    /// Прочность обеспечена, если ни в одном расчетном сечении касательное напряжение
+    /// не превышает допускаемое.
+    pub fn passed(&self) -> bool {
+        self.failed_stations().is_empty()
+    }
+}
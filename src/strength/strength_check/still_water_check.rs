@@ -0,0 +1,167 @@
+use serde::Serialize;
+
+use super::{permissible_limits::PermissibleLimits, shear_stress_check::ShearStressCheck};
+use crate::{
+    core::{
+        round::Round,
+        system_of_units::{TonMeters, TonsForce},
+        unit_system::UnitSystem,
+    },
+    strength::ship::spatium_functions::SpatiumFunctions,
+};
+
+///
+/// Результаты проверки общей продольной прочности судна на тихой воде.
+/// Знак изгибающего момента: M > 0 - перегиб (hogging), M < 0 - прогиб (sagging).
+/// Силы и моменты приводятся в системе единиц unit_system (по умолчанию тонны-силы).
+/// Parameters:
+///     max_share_force - максимальная по модулю перерезывающая сила [т],
+///     share_force_utilisation - отношение max_share_force к допускаемой перерезывающей силе,
+///     max_hogging_moment - максимальный изгибающий момент на перегиб [т * м],
+///     hogging_utilisation - отношение max_hogging_moment к допускаемому моменту на перегиб,
+///     max_sagging_moment - максимальный по модулю изгибающий момент на прогиб [т * м],
+///     sagging_utilisation - отношение max_sagging_moment к допускаемому моменту на прогиб,
+///     max_shear_stress - наибольшее по модулю касательное напряжение [МПа], если заданы поперечные сечения,
+///     shear_stress_utilisation - отношение max_shear_stress к допускаемому касательному напряжению,
+///     unit_system - система единиц сил и моментов.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct StrengthCheck {
+    max_share_force: f64,
+    share_force_utilisation: f64,
+    max_hogging_moment: f64,
+    hogging_utilisation: f64,
+    max_sagging_moment: f64,
+    sagging_utilisation: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_shear_stress: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shear_stress_utilisation: Option<f64>,
+    unit_system: UnitSystem,
+}
+
+impl StrengthCheck {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     share_force - эпюра перерезывающих сил [т],
+    ///     bending_moment - эпюра изгибающих моментов [т * м],
+    ///     limits - допускаемые значения внутренних силовых факторов.
+    pub fn new(
+        share_force: &SpatiumFunctions,
+        bending_moment: &SpatiumFunctions,
+        limits: &PermissibleLimits,
+    ) -> Self {
+        let max_share_force = share_force.max().unwrap_or(0.0);
+        let mut max_hogging_moment: f64 = 0.0;
+        let mut max_sagging_moment: f64 = 0.0;
+        for s_f in bending_moment.as_ref() {
+            for value in [s_f.f_x1(), s_f.f_x2()] {
+                max_hogging_moment = max_hogging_moment.max(value);
+                max_sagging_moment = max_sagging_moment.max(-value);
+            }
+        }
+        StrengthCheck {
+            max_share_force: max_share_force.my_round(2),
+            share_force_utilisation: (max_share_force / limits.share_force()).my_round(3),
+            max_hogging_moment: max_hogging_moment.my_round(2),
+            hogging_utilisation: (max_hogging_moment / limits.hogging_moment()).my_round(3),
+            max_sagging_moment: max_sagging_moment.my_round(2),
+            sagging_utilisation: (max_sagging_moment / limits.sagging_moment()).my_round(3),
+            max_shear_stress: None,
+            shear_stress_utilisation: None,
+            unit_system: UnitSystem::default(),
+        }
+    }
+
+    ///
+    /// Добавляет к проверке касательные напряжения в обшивке борта и продольных переборках.
+    /// Parameters:
+    ///     check - проверка касательных напряжений по длине судна.
+    pub fn with_shear_stress_check(mut self, check: &ShearStressCheck) -> Self {
+        self.max_shear_stress = Some(check.max_shear_stress());
+        self.shear_stress_utilisation = Some(check.max_utilisation());
+        self
+    }
+
+    ///
+    /// Пересчитывает силы и моменты в заданную систему единиц.
+    /// Отношения к допускаемым значениям не изменяются.
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        let force = unit_system.force(TonsForce(1.0)) / self.unit_system.force(TonsForce(1.0));
+        let moment = unit_system.moment(TonMeters(1.0)) / self.unit_system.moment(TonMeters(1.0));
+        self.max_share_force *= force;
+        self.max_hogging_moment *= moment;
+        self.max_sagging_moment *= moment;
+        self.unit_system = unit_system;
+        self
+    }
+
+    ///
+    /// Система единиц сил и моментов.
+    pub fn unit_system(&self) -> UnitSystem {
+        self.unit_system
+    }
+
+    ///
+    /// Максимальная по модулю перерезывающая сила в системе единиц unit_system().
+    pub fn max_share_force(&self) -> f64 {
+        self.max_share_force
+    }
+
+    ///
+    /// Отношение максимальной перерезывающей силы к допускаемой.
+    pub fn share_force_utilisation(&self) -> f64 {
+        self.share_force_utilisation
+    }
+
+    ///
+    /// Максимальный изгибающий момент на перегиб в системе единиц unit_system().
+    pub fn max_hogging_moment(&self) -> f64 {
+        self.max_hogging_moment
+    }
+
+    ///
+    /// Отношение максимального момента на перегиб к допускаемому.
+    pub fn hogging_utilisation(&self) -> f64 {
+        self.hogging_utilisation
+    }
+
+    ///
+    /// Максимальный по модулю изгибающий момент на прогиб в системе единиц unit_system().
+    pub fn max_sagging_moment(&self) -> f64 {
+        self.max_sagging_moment
+    }
+
+    ///
+    /// Отношение максимального момента на прогиб к допускаемому.
+    pub fn sagging_utilisation(&self) -> f64 {
+        self.sagging_utilisation
+    }
+
+    ///
+    /// Наибольшее по модулю касательное напряжение [МПа], если оно проверялось.
+    pub fn max_shear_stress(&self) -> Option<f64> {
+        self.max_shear_stress
+    }
+
+    ///
+    /// Отношение наибольшего касательного напряжения к допускаемому, если оно проверялось.
+    pub fn shear_stress_utilisation(&self) -> Option<f64> {
+        self.shear_stress_utilisation
+    }
+
+    ///
+    /// Наибольшее из отношений внутренних силовых факторов и касательных напряжений к допускаемым значениям.
+    pub fn max_utilisation(&self) -> f64 {
+        self.share_force_utilisation
+            .max(self.hogging_utilisation)
+            .max(self.sagging_utilisation)
+            .max(self.shear_stress_utilisation.unwrap_or_default())
+    }
+
+    ///
+    /// Прочность обеспечена, если ни один внутренний силовой фактор не превышает допускаемое значение.
+    pub fn passed(&self) -> bool {
+        self.max_utilisation() <= 1.0
+    }
+}
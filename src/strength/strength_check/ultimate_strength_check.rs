@@ -0,0 +1,118 @@
+use serde::Serialize;
+use tracing::instrument;
+
+use crate::{
+    core::round::Round,
+    cross_section_properties::{
+        cross_sections::CrossSections, ultimate_strength::UltimateStrength,
+    },
+    strength::{
+        internal_forces::wave_bending_moment::WaveBendingMoment,
+        ship::spatium_functions::SpatiumFunctions,
+    },
+};
+
+///
+/// Сравнение предельной прочности поперечного сечения с суммарным изгибающим моментом.
+/// Parameters:
+///     abscissa - абсцисса сечения [м],
+///     hogging_capacity - предельный момент на перегиб [т*м],
+///     sagging_capacity - предельный момент на прогиб, по модулю [т*м],
+///     still_water_moment - изгибающий момент на тихой воде [т*м],
+///     total_hogging_moment - суммарный момент на тихой воде и волновой на перегиб [т*м],
+///     total_sagging_moment - суммарный момент на тихой воде и волновой на прогиб [т*м],
+///     hogging_safety_factor - запас предельной прочности на перегиб, не определен,
+///     если суммарный момент не вызывает перегиба,
+///     sagging_safety_factor - запас предельной прочности на прогиб, не определен,
+///     если суммарный момент не вызывает прогиба.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SectionUltimateStrength {
+    pub abscissa: f64,
+    pub hogging_capacity: f64,
+    pub sagging_capacity: f64,
+    pub still_water_moment: f64,
+    pub total_hogging_moment: f64,
+    pub total_sagging_moment: f64,
+    pub hogging_safety_factor: Option<f64>,
+    pub sagging_safety_factor: Option<f64>,
+}
+
+///
+/// Отчет о запасах предельной прочности корпуса при общем изгибе для всех заданных поперечных сечений.
+/// Суммарный момент - сумма изгибающего момента на тихой воде и волнового момента (IACS UR S11),
+/// знак момента: M > 0 - перегиб, M < 0 - прогиб.
+/// Parameters:
+///     sections - результаты для поперечных сечений корпуса.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UltimateStrengthCheck {
+    sections: Vec<SectionUltimateStrength>,
+}
+
+impl UltimateStrengthCheck {
+    ///
+    /// Основной конструктор.
+    /// Parameters:
+    ///     still_water_moment - эпюра изгибающих моментов на тихой воде [т*м],
+    ///     wave_moment - волновой изгибающий момент,
+    ///     cross_sections - поперечные сечения корпуса.
+    #[instrument(skip_all, err, target = "UltimateStrengthCheck::new")]
+    pub fn new(
+        still_water_moment: &SpatiumFunctions,
+        wave_moment: &WaveBendingMoment,
+        cross_sections: &CrossSections,
+    ) -> Result<Self, String> {
+        let mut sections = vec![];
+        for section in cross_sections.sections() {
+            let abscissa = section.abscissa();
+            let value_at = |moment: &SpatiumFunctions| -> Result<f64, String> {
+                moment.value_at(abscissa).ok_or(format!(
+                    "Сечение с абсциссой {abscissa} м находится вне длины судна между перпендикулярами."
+                ))
+            };
+            let still_water = value_at(still_water_moment)?;
+            let total_hogging_moment = (still_water + value_at(wave_moment.hogging())?).my_round(2);
+            let total_sagging_moment = (still_water + value_at(wave_moment.sagging())?).my_round(2);
+            let ultimate_strength = UltimateStrength::new(section);
+            let hogging_capacity = ultimate_strength.hogging_capacity();
+            let sagging_capacity = ultimate_strength.sagging_capacity();
+            sections.push(SectionUltimateStrength {
+                abscissa,
+                hogging_capacity: hogging_capacity.my_round(0),
+                sagging_capacity: sagging_capacity.my_round(0),
+                still_water_moment: still_water.my_round(2),
+                total_hogging_moment,
+                total_sagging_moment,
+                hogging_safety_factor: (total_hogging_moment > 0.0)
+                    .then(|| (hogging_capacity / total_hogging_moment).my_round(3)),
+                sagging_safety_factor: (total_sagging_moment < 0.0)
+                    .then(|| (sagging_capacity / -total_sagging_moment).my_round(3)),
+            });
+        }
+        Ok(UltimateStrengthCheck { sections })
+    }
+
+    ///
+    /// Результаты для поперечных сечений корпуса.
+    pub fn sections(&self) -> &Vec<SectionUltimateStrength> {
+        &self.sections
+    }
+
+    ///
+    /// Наименьший запас предельной прочности на перегиб и прогиб по всем сечениям.
+    /// Не определен, если ни в одном сечении суммарный момент не действует.
+    pub fn min_safety_factor(&self) -> Option<f64> {
+        self.sections
+            .iter()
+            .flat_map(|section| [section.hogging_safety_factor, section.sagging_safety_factor])
+            .flatten()
+            .reduce(f64::min)
+    }
+
+    ///
+    /// Предельная прочность обеспечена, если во всех сечениях предельный момент
+    /// не меньше суммарного изгибающего момента.
+    pub fn passed(&self) -> bool {
+        self.min_safety_factor()
+            .is_none_or(|safety_factor| safety_factor >= 1.0)
+    }
+}
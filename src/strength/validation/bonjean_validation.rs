@@ -0,0 +1,136 @@
+use serde::Serialize;
+
+use super::finding::{Finding, Findings};
+use crate::{
+    core::round::Round,
+    strength::{bonjean_scale::frames::Frames, ship::ship_dimensions::ShipDimensions},
+};
+
+///
+/// Допускаемое отклонение абсцисс крайних шпангоутов от крайних перпендикуляров [м].
+const SPAN_TOLERANCE: f64 = 1e-3;
+
+///
+/// Допускаемое относительное отклонение объемов и масс шпангоутов от значений, вычисленных по площадям.
+const RELATIVE_TOLERANCE: f64 = 0.01;
+
+///
+/// Плотность, при которой в масштабе Бонжана заданы массы погруженной части шпангоутов [т/м^3].
+const MASS_DENSITY: f64 = 1.0;
+
+///
+/// Проверка масштаба Бонжана на согласованность с размерениями судна.
+/// Ошибки:
+///     - крайние шпангоуты не совпадают с крайними перпендикулярами (coordinate_aft, coordinate_nose).
+/// Предупреждения:
+///     - длина векторов объемов или масс шпангоута не совпадает с длиной вектора осадок,
+///     - объем шпангоута не согласуется с площадью, умноженной на длину теоретической шпации,
+///     - масса шпангоута не согласуется с площадью, умноженной на длину теоретической шпации и плотность MASS_DENSITY.
+/// Объемы и массы в расчете не используются, поэтому их несогласованность не препятствует расчету.
+#[derive(Debug, Serialize)]
+pub struct BonjeanValidation {
+    findings: Findings,
+}
+
+impl BonjeanValidation {
+    ///
+    /// Выполняет проверку масштаба Бонжана.
+    /// Parameters:
+    ///     frames - шпангоуты судна,
+    ///     ship_dimensions - размерения судна.
+    pub fn new(frames: &Frames, ship_dimensions: ShipDimensions) -> Self {
+        let mut findings = vec![];
+        let aft = ship_dimensions.coordinate_aft();
+        let nose = ship_dimensions.coordinate_nose();
+        let length_spatium = ship_dimensions.length_spatium().value();
+        let frames = frames.as_ref();
+        if let Some(first) = frames.first() {
+            if (first.abscissa() - aft).abs() > SPAN_TOLERANCE {
+                findings.push(
+                    Finding::error(format!(
+                        "Шпангоут {}, индекс 0: абсцисса кормового шпангоута {} м не совпадает с координатой кормового перпендикуляра {} м.",
+                        first.id(),
+                        first.abscissa(),
+                        aft
+                    ))
+                    .with_position(first.abscissa()),
+                );
+            }
+        }
+        if let Some(last) = frames.last() {
+            if (last.abscissa() - nose).abs() > SPAN_TOLERANCE {
+                findings.push(
+                    Finding::error(format!(
+                        "Шпангоут {}, индекс {}: абсцисса носового шпангоута {} м не совпадает с координатой носового перпендикуляра {} м.",
+                        last.id(),
+                        frames.len() - 1,
+                        last.abscissa(),
+                        nose
+                    ))
+                    .with_position(last.abscissa()),
+                );
+            }
+        }
+        for frame in frames.iter() {
+            let warning = |message: String| {
+                Finding::warning(format!("Шпангоут {}, {message}", frame.id()))
+                    .with_position(frame.abscissa())
+            };
+            let drafts_len = frame.drafts().len();
+            let volumes = frame.volumes();
+            let masses = frame.masses();
+            if !volumes.is_empty() && volumes.len() != drafts_len {
+                findings.push(warning(format!(
+                    "длина вектора объемов {} не совпадает с длиной вектора осадок {drafts_len}.",
+                    volumes.len()
+                )));
+            } else {
+                for (index, (volume, area)) in volumes.iter().zip(frame.areas()).enumerate() {
+                    let expected = area * length_spatium;
+                    if !Self::consistent(*volume, expected, length_spatium) {
+                        findings.push(warning(format!(
+                            "индекс {index}: объем {volume} м^3 не согласуется с площадью {area} м^2 (ожидается {} м^3).",
+                            expected.my_round(2)
+                        )));
+                    }
+                }
+            }
+            if !masses.is_empty() && masses.len() != drafts_len {
+                findings.push(warning(format!(
+                    "длина вектора масс {} не совпадает с длиной вектора осадок {drafts_len}.",
+                    masses.len()
+                )));
+            } else {
+                for (index, (mass, area)) in masses.iter().zip(frame.areas()).enumerate() {
+                    let expected = area * length_spatium * MASS_DENSITY;
+                    if !Self::consistent(*mass, expected, length_spatium * MASS_DENSITY) {
+                        findings.push(warning(format!(
+                            "индекс {index}: масса {mass} т не согласуется с площадью {area} м^2 (ожидается {} т).",
+                            expected.my_round(2)
+                        )));
+                    }
+                }
+            }
+        }
+        BonjeanValidation {
+            findings: Findings::new(findings),
+        }
+    }
+
+    ///
+    /// Значение согласуется с вычисленным с учетом относительного допуска
+    /// и погрешности округления площади до 0.01 м^2.
+    /// Parameters:
+    ///     value - заданное значение,
+    ///     expected - значение, вычисленное по площади,
+    ///     scale - множитель площади при вычислении значения.
+    fn consistent(value: f64, expected: f64, scale: f64) -> bool {
+        (value - expected).abs() <= RELATIVE_TOLERANCE * (expected.abs() + scale)
+    }
+
+    ///
+    /// Все замечания.
+    pub fn findings(&self) -> &Findings {
+        &self.findings
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt::Display;
+
+use log::warn;
+use serde::Serialize;
+
+///
+/// Степень важности замечания.
+///     Error - расчет выполнять нельзя,
+///     Warning - расчет выполняется, но результаты следует проверить.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+///
+/// Замечание по результатам проверки входных данных.
+/// Parameters:
+///     severity - степень важности,
+///     index - индекс нагрузки (элемента входных данных), к которой относится замечание,
+///     name - наименование нагрузки или помещения,
+///     position - абсцисса относительно мидель шпангоута, к которой относится замечание [м],
+///     message - описание замечания.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Finding {
+    severity: Severity,
+    index: Option<usize>,
+    name: Option<String>,
+    position: Option<f64>,
+    message: String,
+}
+
+impl Finding {
+    ///
+    /// Замечание, при котором расчет выполнять нельзя.
+    pub fn error(message: String) -> Self {
+        Finding {
+            severity: Severity::Error,
+            index: None,
+            name: None,
+            position: None,
+            message,
+        }
+    }
+
+    ///
+    /// Замечание, при котором расчет выполняется.
+    pub fn warning(message: String) -> Self {
+        Finding {
+            severity: Severity::Warning,
+            ..Finding::error(message)
+        }
+    }
+
+    ///
+    /// Задает индекс нагрузки.
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    ///
+    /// Задает наименование нагрузки или помещения.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    ///
+    /// Задает абсциссу, к которой относится замечание.
+    pub fn with_position(mut self, position: f64) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    ///
+    /// Замечание не позволяет выполнить расчет.
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "Ошибка",
+            Severity::Warning => "Предупреждение",
+        };
+        write!(f, "{severity}")?;
+        if let Some(index) = self.index {
+            write!(f, " [нагрузка {index}]")?;
+        }
+        if let Some(name) = &self.name {
+            write!(f, " \"{name}\"")?;
+        }
+        if let Some(position) = self.position {
+            write!(f, " (x = {position} м)")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+///
+/// Замечания по результатам проверки входных данных.
+/// Parameters:
+///     findings - все замечания проверки.
+#[derive(Debug, Serialize, Clone, PartialEq, Default)]
+#[serde(transparent)]
+pub struct Findings {
+    findings: Vec<Finding>,
+}
+
+impl Findings {
+    ///
+    /// Основной конструктор.
+    pub fn new(findings: Vec<Finding>) -> Self {
+        Findings { findings }
+    }
+
+    ///
+    /// Есть замечания, при которых расчет выполнять нельзя.
+    pub fn has_errors(&self) -> bool {
+        self.findings.iter().any(|finding| finding.is_error())
+    }
+
+    ///
+    /// Возвращает ошибку с перечнем всех ошибок проверки, если они есть.
+    /// Предупреждения записываются в журнал.
+    pub fn check(&self) -> Result<(), String> {
+        for finding in self.findings.iter().filter(|finding| !finding.is_error()) {
+            warn!("Findings.check | {finding}");
+        }
+        if !self.has_errors() {
+            return Ok(());
+        }
+        Err(self
+            .findings
+            .iter()
+            .filter(|finding| finding.is_error())
+            .map(|finding| finding.to_string())
+            .collect::<Vec<String>>()
+            .join("\n"))
+    }
+}
+
+impl AsRef<Vec<Finding>> for Findings {
+    fn as_ref(&self) -> &Vec<Finding> {
+        &self.findings
+    }
+}
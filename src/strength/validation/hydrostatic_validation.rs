@@ -0,0 +1,80 @@
+use serde::Serialize;
+
+use super::finding::{Finding, Findings};
+use crate::{
+    core::linear_interpolation::LinearInterpolation,
+    strength::hydrostatic_curves::{
+        hydrostatic_curves::HydrostaticCurves, hydrostatic_typedata::HydrostaticTypeData,
+    },
+};
+
+///
+/// Множитель допустимого отклонения точки кривой от прямой, проведенной через соседние точки.
+const OUTLIER_FACTOR: f64 = 5.0;
+
+///
+/// Доля наибольшего по модулю значения кривой, добавляемая к допустимому отклонению точки.
+const OUTLIER_SCALE: f64 = 0.05;
+
+///
+/// Проверка гидростатических кривых на выпадающие точки.
+/// Значение во внутренней точке кривой сравнивается с прямой, проведенной через соседние точки.
+/// Допустимое отклонение пропорционально изменению кривой между соседними точками
+/// и наибольшему по модулю значению кривой.
+/// Предупреждения:
+///     - точка кривой выпадает из кривой. Такие скачки (например, потерянный знак) обычно
+///     являются опечатками во входных данных, но не препятствуют расчету.
+#[derive(Debug, Serialize)]
+pub struct HydrostaticValidation {
+    findings: Findings,
+}
+
+impl HydrostaticValidation {
+    ///
+    /// Выполняет проверку гидростатических кривых.
+    /// Parameters:
+    ///     hydrostatic_curves - гидростатические кривые судна.
+    pub fn new(hydrostatic_curves: &HydrostaticCurves) -> Self {
+        let mut findings = vec![];
+        let drafts = hydrostatic_curves.drafts();
+        for type_data in HydrostaticTypeData::all() {
+            let data = hydrostatic_curves.data(type_data);
+            if data.len() < 3 {
+                continue;
+            }
+            let scale =
+                data.iter().fold(0.0_f64, |max, value| max.max(value.abs())) * OUTLIER_SCALE;
+            for index in 1..data.len() - 1 {
+                let Ok(expected) = LinearInterpolation::new(
+                    data[index - 1],
+                    data[index + 1],
+                    drafts[index - 1],
+                    drafts[index + 1],
+                )
+                .interpolated_value(drafts[index]) else {
+                    continue;
+                };
+                let tolerance =
+                    OUTLIER_FACTOR * ((data[index + 1] - data[index - 1]).abs() + scale);
+                if (data[index] - expected).abs() > tolerance {
+                    findings.push(Finding::warning(format!(
+                        "Гидростатическая кривая `{}`, осадка {} м: значение {} выпадает из кривой, по соседним точкам ожидается около {:.2}.",
+                        type_data.name(),
+                        drafts[index],
+                        data[index],
+                        expected
+                    )));
+                }
+            }
+        }
+        HydrostaticValidation {
+            findings: Findings::new(findings),
+        }
+    }
+
+    ///
+    /// Все замечания.
+    pub fn findings(&self) -> &Findings {
+        &self.findings
+    }
+}
@@ -0,0 +1,131 @@
+use serde::Serialize;
+
+use super::finding::{Finding, Findings};
+use crate::{
+    core::{
+        round::Round,
+        system_of_units::{Meters, Tons},
+    },
+    strength::{load::shiploads::Shiploads, ship::ship_model::Ship},
+};
+
+///
+/// Проверка схемы загрузки судна перед расчетом прочности.
+/// Ошибки:
+///     - отрицательная масса или длина нагрузки,
+///     - некорректный закон распределения нагрузки,
+///     - центр тяжести нагрузки отстоит от крайнего перпендикуляра более чем на длину теоретической шпации,
+///     - масса нагрузок в помещении превышает допускаемую,
+///     - водоизмещение судна превышает максимальное водоизмещение по гидростатическим кривым.
+/// Предупреждения:
+///     - нагрузка частично или полностью расположена за крайними перпендикулярами.
+#[derive(Debug, Serialize)]
+pub struct LoadingValidation {
+    findings: Findings,
+}
+
+impl LoadingValidation {
+    ///
+    /// Выполняет проверку схемы загрузки.
+    /// Parameters:
+    ///     ship - данные судна,
+    ///     shiploads - схема загрузки судна.
+    pub fn new(ship: &Ship, shiploads: &Shiploads) -> Self {
+        let mut findings = vec![];
+        let ship_dimensions = ship.ship_dimensions();
+        let aft = ship_dimensions.coordinate_aft();
+        let nose = ship_dimensions.coordinate_nose();
+        let length_spatium = ship_dimensions.length_spatium().value();
+        for (index, shipload) in shiploads.as_ref().iter().enumerate() {
+            let x = shipload.longitudinal_center_gravity();
+            let finding = |finding: Finding| {
+                let finding = finding.with_index(index).with_position(x);
+                match shipload.name() {
+                    Some(name) => finding.with_name(name),
+                    None => finding,
+                }
+            };
+            if shipload.value() < Tons(0.0) {
+                findings.push(finding(Finding::error(format!(
+                    "Отрицательная масса нагрузки: {} т.",
+                    shipload.value()
+                ))));
+            }
+            if shipload.length() < Meters(0.0) {
+                findings.push(finding(Finding::error(format!(
+                    "Отрицательная длина нагрузки: {} м.",
+                    shipload.length()
+                ))));
+            }
+            if let Some(shape) = shipload.shape() {
+                if let Err(err) = shape.validate() {
+                    findings.push(finding(Finding::error(err)));
+                }
+            }
+            if x < aft - length_spatium || x > nose + length_spatium {
+                findings.push(finding(Finding::error(format!(
+                    "Центр тяжести нагрузки находится за пределами корпуса судна (допускается не далее {} м от крайних перпендикуляров).",
+                    length_spatium.my_round(2)
+                ))));
+            } else if shipload.load_start_coordinate() < aft
+                || shipload.load_end_coordinate() > nose
+            {
+                findings.push(finding(Finding::warning(
+                    "Нагрузка выходит за пределы крайних перпендикуляров.".to_string(),
+                )));
+            }
+        }
+        for compartment in ship.compartments().as_ref().iter() {
+            let Some(max_mass) = compartment.max_mass() else {
+                continue;
+            };
+            let (indexes, mass) = shiploads
+                .as_ref()
+                .iter()
+                .enumerate()
+                .filter(|(_, shipload)| {
+                    let x = shipload.longitudinal_center_gravity();
+                    x >= compartment.start_coordinate() && x < compartment.end_coordinate()
+                })
+                .fold((vec![], 0.0), |(mut indexes, mass), (index, shipload)| {
+                    indexes.push(index.to_string());
+                    (indexes, mass + shipload.value().value())
+                });
+            if mass > max_mass + 1e-6 {
+                findings.push(
+                    Finding::error(format!(
+                        "Масса нагрузок в помещении {} т превышает допускаемую {} т (нагрузки: {}).",
+                        mass.my_round(2),
+                        max_mass.my_round(2),
+                        indexes.join(", ")
+                    ))
+                    .with_name(compartment.name())
+                    .with_position(compartment.center_gravity().x),
+                );
+            }
+        }
+        let displacement_tonnage = ship.lightweight().lightweight().value() + shiploads.sum();
+        // Гидростатические кривые построены для плотности воды из данных судна.
+        let sea_water_density = ship.water_density().water_density();
+        let water_density = shiploads.water_density().unwrap_or(sea_water_density);
+        let max_displacement_tonnage = ship.hydrostatic_curves().max_displacement_tonnage()
+            * water_density
+            / sea_water_density;
+        if displacement_tonnage > max_displacement_tonnage {
+            findings.push(Finding::error(format!(
+                "Водоизмещение судна {} т превышает максимальное водоизмещение по гидростатическим кривым {} т.",
+                displacement_tonnage.my_round(2),
+                max_displacement_tonnage.my_round(2)
+            )));
+        }
+        LoadingValidation {
+            findings: Findings::new(findings),
+        }
+    }
+
+    ///
+    /// Все замечания.
+    pub fn findings(&self) -> &Findings {
+        &self.findings
+    }
+}
@@ -0,0 +1,4 @@
+pub mod bonjean_validation;
+pub mod finding;
+pub mod hydrostatic_validation;
+pub mod loading_validation;
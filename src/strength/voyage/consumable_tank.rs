@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::strength::{
+    compartment::ship_compartment::Compartment,
+    load::{load_category::LoadCategory, shipload::Shipload},
+};
+
+///
+/// Цистерна судовых запасов (топливо, масло, пресная вода, провизия),
+/// расходуемых в течение рейса.
+/// Parameters:
+///     tank - цистерна,
+///     category - категория запасов,
+///     density - плотность запасов [т/м^3],
+///     filling - степень заполнения цистерны при отходе (от 0 до 1),
+///     consumption - суточный расход запасов [т/сут].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ConsumableTank {
+    tank: Compartment,
+    category: LoadCategory,
+    density: f64,
+    filling: f64,
+    consumption: f64,
+}
+
+impl ConsumableTank {
+    ///
+    /// Валидация входных данных.
+    pub(crate) fn validate_input_data(self) -> Result<ConsumableTank, String> {
+        if self.density <= 0.0 {
+            return Err(format!(
+                "Цистерна \"{}\": плотность запасов должна быть больше нуля.",
+                self.tank.name()
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.filling) {
+            return Err(format!(
+                "Цистерна \"{}\": степень заполнения должна находиться в диапазоне от 0 до 1.",
+                self.tank.name()
+            ));
+        }
+        if self.consumption < 0.0 {
+            return Err(format!(
+                "Цистерна \"{}\": суточный расход не может быть отрицательным.",
+                self.tank.name()
+            ));
+        }
+        Ok(self)
+    }
+
+    ///
+    /// Наименование цистерны.
+    pub fn name(&self) -> &str {
+        self.tank.name()
+    }
+
+    ///
+    /// Масса запасов при отходе [т].
+    pub fn departure_mass(&self) -> f64 {
+        self.tank.capacity(self.density) * self.filling
+    }
+
+    ///
+    /// Масса запасов через day суток после отхода [т].
+    pub fn mass(&self, day: f64) -> f64 {
+        (self.departure_mass() - self.consumption * day).max(0.0)
+    }
+
+    ///
+    /// Количество суток, на которое хватает запасов цистерны.
+    /// Если запасы не расходуются, возвращает f64::INFINITY.
+    pub fn endurance(&self) -> f64 {
+        if self.consumption == 0.0 {
+            return f64::INFINITY;
+        }
+        self.departure_mass() / self.consumption
+    }
+
+    ///
+    /// Нагрузка от цистерны через day суток после отхода.
+    pub fn shipload(&self, day: f64) -> Shipload {
+        let capacity = self.tank.capacity(self.density);
+        let filling = if capacity > 0.0 {
+            self.mass(day) / capacity
+        } else {
+            0.0
+        };
+        self.tank.shipload(filling, self.density, self.category)
+    }
+}
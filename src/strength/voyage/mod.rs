@@ -0,0 +1,2 @@
+pub mod consumable_tank;
+pub mod voyage_plan;
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+
+use super::consumable_tank::ConsumableTank;
+use crate::{
+    core::{data_file::DataFile, round::Round, schema_version::SchemaVersion},
+    strength::{
+        load::shiploads::Shiploads, loading_sequence::sequence_report::SequenceReport,
+        ship::ship_model::Ship,
+    },
+};
+
+///
+/// Версии формата файла рейса:
+///     1 - поля tanks, duration и необязательные поля intermediate, departure_water_density, arrival_water_density.
+pub const VOYAGE_SCHEMA: SchemaVersion = SchemaVersion::new("файла рейса", 1, &[]);
+
+///
+/// Рейс судна с расходом судовых запасов.
+/// Для рейса формируются схемы загрузки при отходе, в промежуточных точках рейса
+/// и при приходе, расход запасов принимается равномерным по времени.
+/// Parameters:
+///     tanks - цистерны судовых запасов,
+///     duration - продолжительность рейса [сут],
+///     intermediate - количество промежуточных состояний загрузки между отходом и приходом,
+///     departure_water_density, arrival_water_density - плотность забортной воды в порту отхода
+///     и в порту прихода [т/м^3], если не задана, используется плотность исходной схемы загрузки.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Voyage {
+    tanks: Vec<ConsumableTank>,
+    duration: f64,
+    #[serde(default = "default_intermediate")]
+    intermediate: usize,
+    #[serde(default)]
+    departure_water_density: Option<f64>,
+    #[serde(default)]
+    arrival_water_density: Option<f64>,
+}
+
+fn default_intermediate() -> usize {
+    1
+}
+
+impl Voyage {
+    ///
+    /// Create the object from json, toml or yaml file.
+    /// Версия формата проверяется до разбора содержимого (VOYAGE_SCHEMA).
+    #[instrument(skip_all, err, target = "Voyage::from_file")]
+    pub fn from_file(file_path: String) -> Result<Self, String> {
+        let value: Value = DataFile::new(file_path.clone()).deserialize()?;
+        let value = VOYAGE_SCHEMA
+            .upgrade(value)
+            .map_err(|err| format!("Файл {file_path}: {err}"))?;
+        let voyage: Voyage = serde_json::from_value(value).map_err(|err| err.to_string())?;
+        voyage.validate_input_data()
+    }
+
+    ///
+    /// Валидация входных данных.
+    fn validate_input_data(self) -> Result<Voyage, String> {
+        if self.tanks.is_empty() {
+            return Err("Цистерны судовых запасов не заданы.".to_string());
+        }
+        if self.duration <= 0.0 {
+            return Err("Продолжительность рейса должна быть больше нуля.".to_string());
+        }
+        for water_density in [self.departure_water_density, self.arrival_water_density]
+            .into_iter()
+            .flatten()
+        {
+            if water_density <= 0.0 {
+                return Err(format!(
+                    "Плотность забортной воды должна быть больше нуля: {water_density} т/м^3."
+                ));
+            }
+        }
+        let tanks = self
+            .tanks
+            .into_iter()
+            .map(|tank| tank.validate_input_data())
+            .collect::<Result<Vec<ConsumableTank>, String>>()?;
+        for tank in tanks.iter() {
+            if tank.endurance() < self.duration {
+                return Err(format!(
+                    "Запасов цистерны \"{}\" хватает на {} сут. Продолжительность рейса: {} сут.",
+                    tank.name(),
+                    tank.endurance().my_round(1),
+                    self.duration
+                ));
+            }
+        }
+        Ok(Voyage {
+            tanks,
+            duration: self.duration,
+            intermediate: self.intermediate,
+            departure_water_density: self.departure_water_density,
+            arrival_water_density: self.arrival_water_density,
+        })
+    }
+
+    ///
+    /// Время от начала рейса для каждого состояния загрузки [сут]:
+    /// отход, промежуточные состояния, приход.
+    pub fn days(&self) -> Vec<f64> {
+        let intervals = self.intermediate + 1;
+        (0..=intervals)
+            .map(|i| (self.duration * i as f64 / intervals as f64).my_round(2))
+            .collect()
+    }
+
+    ///
+    /// Возвращает описания и схемы загрузки судна для каждого состояния рейса.
+    /// Parameters:
+    ///     base - схема загрузки судна без судовых запасов.
+    pub fn conditions(&self, base: &Shiploads) -> Vec<(String, Shiploads)> {
+        let days = self.days();
+        let last = days.len() - 1;
+        days.into_iter()
+            .enumerate()
+            .map(|(i, day)| {
+                let (name, water_density) = match i {
+                    0 => ("Отход".to_string(), self.departure_water_density),
+                    i if i == last => (format!("Приход ({day} сут)"), self.arrival_water_density),
+                    _ => (format!("В рейсе ({day} сут)"), None),
+                };
+                let mut shiploads = match water_density {
+                    Some(water_density) => base.clone().with_water_density(water_density),
+                    None => base.clone(),
+                };
+                for tank in self.tanks.iter() {
+                    shiploads.add(tank.shipload(day));
+                }
+                (name, shiploads)
+            })
+            .collect()
+    }
+
+    ///
+    /// Выполняет расчет прочности, осадок и дифферента для всех состояний рейса.
+    /// Parameters:
+    ///     ship - данные судна,
+    ///     base - схема загрузки судна без судовых запасов.
+    pub fn run(&self, ship: &Ship, base: &Shiploads) -> SequenceReport {
+        let conditions = self.conditions(base);
+        SequenceReport::new(
+            ship,
+            conditions
+                .iter()
+                .map(|(name, shiploads)| (name.as_str(), shiploads)),
+        )
+    }
+}
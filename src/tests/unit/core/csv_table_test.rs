@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use crate::core::csv_table::CsvTable;
+    use std::{env, sync::Once};
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = env_logger::try_init();
+        })
+    }
+
+    #[test]
+    fn parse_comma_test() {
+        call_once();
+        let table = CsvTable::parse("a, b\n1.5, 2\n\n3, -4.25\n").unwrap();
+        assert_eq!(&vec!["a".to_string(), "b".to_string()], table.header());
+        assert_eq!(Some(1), table.column_index("b"));
+        assert_eq!(vec![2.0, -4.25], table.column(1, 0).unwrap());
+        assert_eq!(vec![3.0], table.column(0, 1).unwrap());
+    }
+
+    #[test]
+    fn parse_semicolon_test() {
+        call_once();
+        let table = CsvTable::parse("a;b\n1,5;2\n").unwrap();
+        assert_eq!(vec![1.5], table.column(0, 0).unwrap());
+    }
+
+    #[test]
+    fn parse_error_test() {
+        call_once();
+        assert!(CsvTable::parse("").is_err());
+        assert_eq!(
+            Err("Строка 3: количество столбцов 1 не совпадает с заголовком (2).".to_string()),
+            CsvTable::parse("a,b\n1,2\n3\n")
+        );
+        let table = CsvTable::parse("a,b\n\n1,x\n").unwrap();
+        assert_eq!(
+            Err("Строка 3: значение \"x\" не является числом.".to_string()),
+            table.column(1, 0)
+        );
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            data_file::{DataFile, DataFormat},
+            system_of_units::{Meters, Tons},
+            unit_system::UnitSystem,
+        },
+        strength::{
+            lightweight::{
+                lightweight::Lightweight, lightweight_distribution::LightweightDistribution,
+            },
+            ship::ship_dimensions::ShipDimensions,
+            strength_check::permissible_limits::PermissibleLimits,
+        },
+    };
+    use std::{env, sync::Once};
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = env_logger::try_init();
+        })
+    }
+
+    #[test]
+    fn format_test() {
+        call_once();
+        assert_eq!(Ok(DataFormat::Json), DataFormat::from_path("a/ship.json"));
+        assert_eq!(Ok(DataFormat::Json), DataFormat::from_path("a/ship"));
+        assert_eq!(Ok(DataFormat::Toml), DataFormat::from_path("ship.TOML"));
+        assert_eq!(Ok(DataFormat::Yaml), DataFormat::from_path("ship.yml"));
+        assert_eq!(Ok(DataFormat::Csv), DataFormat::from_path("frames.csv"));
+        assert!(DataFormat::from_path("ship.xlsx").is_err());
+    }
+
+    #[test]
+    fn particulars_test() {
+        call_once();
+        for file_path in [
+            "input_data/input_data.json",
+            "input_data/input_data.toml",
+            "input_data/input_data.yaml",
+        ] {
+            let ship_dimensions = ShipDimensions::from_file(file_path.to_string()).unwrap();
+            assert_eq!(Meters(235.0), ship_dimensions.lbp());
+            assert_eq!(Some(Meters(32.2)), ship_dimensions.breadth());
+            let lw = Lightweight::from_file(file_path.to_string()).unwrap();
+            assert_eq!(Tons(13550.0), lw.lightweight());
+            assert_eq!(Some(10.67), lw.vcg());
+            assert_eq!(
+                LightweightDistribution::Trapezoidal,
+                LightweightDistribution::from_file(file_path.to_string()).unwrap()
+            );
+            assert!(PermissibleLimits::from_file(file_path.to_string())
+                .unwrap()
+                .is_some());
+            assert_eq!(
+                Ok(UnitSystem::TonnesForce),
+                UnitSystem::from_file(file_path.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn csv_deserialize_test() {
+        call_once();
+        let result: Result<ShipDimensions, String> =
+            DataFile::new("input_data/frames.csv".to_string()).deserialize();
+        assert!(result.is_err());
+    }
+}
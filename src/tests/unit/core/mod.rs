@@ -1,4 +1,9 @@
 mod binary_search_test;
+mod csv_table_test;
+mod data_file_test;
 mod linear_intapolation_test;
 mod round;
+mod schema_version_test;
+mod system_of_units_test;
+mod unit_system_test;
 mod water_density_test;
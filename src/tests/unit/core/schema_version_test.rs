@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use crate::core::schema_version::{Migration, SchemaVersion};
+    use serde_json::{json, Value};
+    use std::{env, sync::Once};
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = env_logger::try_init();
+        })
+    }
+
+    fn rename_value(mut value: Value) -> Result<Value, String> {
+        let value_map = value.as_object_mut().unwrap();
+        let old = value_map
+            .remove("value")
+            .ok_or("Нет поля value.".to_string())?;
+        value_map.insert("mass".to_string(), old);
+        Ok(value)
+    }
+
+    fn add_vcg(mut value: Value) -> Result<Value, String> {
+        value
+            .as_object_mut()
+            .unwrap()
+            .insert("vcg".to_string(), json!(0.0));
+        Ok(value)
+    }
+
+    const SCHEMA: SchemaVersion = SchemaVersion::new(
+        "тестового файла",
+        3,
+        &[
+            Migration {
+                from: 1,
+                description: "поле value переименовано в mass",
+                migrate: rename_value,
+            },
+            Migration {
+                from: 2,
+                description: "добавлено поле vcg",
+                migrate: add_vcg,
+            },
+        ],
+    );
+
+    #[test]
+    fn version_test() {
+        call_once();
+        assert_eq!(Ok(1), SCHEMA.version(&json!({"value": 1.0})));
+        assert_eq!(Ok(2), SCHEMA.version(&json!({"version": 2})));
+        assert!(SCHEMA.version(&json!({"version": 0})).is_err());
+        assert!(SCHEMA.version(&json!({"version": "2"})).is_err());
+    }
+
+    #[test]
+    fn upgrade_test() {
+        call_once();
+        assert_eq!(
+            Ok(json!({"version": 3, "mass": 1.0, "vcg": 0.0})),
+            SCHEMA.upgrade(json!({"value": 1.0}))
+        );
+        assert_eq!(
+            Ok(json!({"version": 3, "mass": 1.0, "vcg": 0.0})),
+            SCHEMA.upgrade(json!({"version": 2, "mass": 1.0}))
+        );
+        assert_eq!(
+            Ok(json!({"version": 3, "mass": 1.0, "vcg": 2.0})),
+            SCHEMA.upgrade(json!({"version": 3, "mass": 1.0, "vcg": 2.0}))
+        );
+    }
+
+    #[test]
+    fn upgrade_error_test() {
+        call_once();
+        assert_eq!(
+            Err("Версия формата тестового файла 4 не поддерживается, поддерживаемая версия: 3. Обновите программу.".to_string()),
+            SCHEMA.upgrade(json!({"version": 4, "unknown": []}))
+        );
+        assert_eq!(
+            Err("Нет поля value.".to_string()),
+            SCHEMA.upgrade(json!({"mass": 1.0}))
+        );
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{
+            point::Point,
+            round::Round,
+            system_of_units::{
+                CubicMeters, KiloNewtonMeters, KiloNewtons, KiloNewtonsPerMeter,
+                KilogramsPerCubicMeter, MassMoment, MegaPascals, Meters, Newton, TonMeters, Tons,
+                TonsForce, TonsPerCubicMeter, TonsPerMeter, TonsPerSquareMeter,
+            },
+        },
+        strength::{
+            load::{shipload::Shipload, shiploads::Shiploads},
+            ship::{ship_dimensions::ShipDimensions, ship_model::Ship},
+            strength::Strength,
+        },
+    };
+    use std::{env, sync::Once};
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = env_logger::try_init();
+        })
+    }
+
+    #[test]
+    fn si_conversion_test() {
+        call_once();
+        let round = |value: f64| value.my_round(6);
+        assert_eq!(98.1, round(KiloNewtons::from(TonsForce(10.0)).value()));
+        assert_eq!(10.0, round(TonsForce::from(KiloNewtons(98.1)).value()));
+        assert_eq!(
+            9.81,
+            round(KiloNewtonsPerMeter::from(TonsPerMeter(1.0)).value())
+        );
+        assert_eq!(
+            981000.0,
+            round(KiloNewtonMeters::from(TonMeters(100000.0)).value())
+        );
+        assert_eq!(
+            1025.0,
+            round(KilogramsPerCubicMeter::from(TonsPerCubicMeter(1.025)).value())
+        );
+        assert_eq!(
+            98.1,
+            round(MegaPascals::from(TonsPerSquareMeter(10000.0)).value())
+        );
+        assert_eq!(TonsForce(5.0), TonsForce::from(Tons(5.0)));
+        assert_eq!(1.0, round(Tons::from(Newton(9810.0)).value()));
+        assert_eq!(9.81, round(KiloNewtons::from(Newton(9810.0)).value()));
+        assert_eq!(9810.0, round(Newton::from(TonsForce(1.0)).value()));
+        assert_eq!(9810.0, round(Newton::from(1.0).value()));
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        call_once();
+        assert_eq!(Meters(3.0), Meters(1.0) + Meters(2.0));
+        assert_eq!(Tons(-1.0), Tons(1.0) - Tons(2.0));
+        assert_eq!(TonMeters(-4.0), -TonMeters(2.0) * 2.0);
+        assert_eq!(TonsForce(20.0), TonsPerMeter(2.0) * Meters(10.0));
+        assert_eq!(TonMeters(50.0), TonsForce(5.0) * Meters(10.0));
+        assert_eq!(MassMoment(50.0), Tons(5.0) * Meters(10.0));
+        assert_eq!("1.50 кН*м", format!("{:.2}", KiloNewtonMeters(1.5)));
+        assert_eq!("т/м", TonsPerMeter::suffix());
+    }
+
+    #[test]
+    fn typed_api_test() {
+        call_once();
+        let ship_dimensions = ShipDimensions::new(235.0, 20, 0.8);
+        assert_eq!(None, ship_dimensions.breadth());
+        assert_eq!(
+            Some(Meters(32.2)),
+            ship_dimensions.with_breadth(32.2).breadth()
+        );
+        assert_eq!(Meters(235.0), ship_dimensions.lbp());
+        assert_eq!(Meters(11.75), ship_dimensions.length_spatium());
+        let shipload = Shipload::new(100.0, Point::new(2.0, 0.0, 0.0), 4.0);
+        assert_eq!(Tons(100.0), shipload.value());
+        assert_eq!(Meters(4.0), shipload.length());
+        assert_eq!(MassMoment(200.0), shipload.moment());
+        let ship = Ship::from_files(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let shiploads = Shiploads::new(vec![Shipload::new(
+            4000.0,
+            Point::new(20.0, 0.0, 8.0),
+            20.0,
+        )]);
+        let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+        assert_eq!(Tons(13550.0), strength.lightweight());
+        assert_eq!(Tons(4000.0), strength.deadweight());
+        assert_eq!(
+            strength.lightweight() + strength.deadweight(),
+            strength.displacemnt_tonnage()
+        );
+        assert_eq!(TonsPerCubicMeter(1.025), strength.water_density());
+        assert!(strength.max_share_force().unwrap() > TonsForce(0.0));
+        assert!(strength.max_bending_moment().unwrap() > TonMeters(0.0));
+        let (aft_draft, nose_draft) = strength.draft().unwrap();
+        assert!(aft_draft > Meters(0.0) && nose_draft > Meters(0.0));
+        assert!(strength.displacement().unwrap() > CubicMeters(0.0));
+        assert!((strength.lcg() - strength.lcb().unwrap()).value().abs() < 0.001 * 235.0);
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use crate::core::{
+        physical_constants::EART_GRAVITY,
+        round::Round,
+        system_of_units::{MegaPascals, TonMeters, TonsForce, TonsPerMeter},
+        unit_system::UnitSystem,
+        visualisation::DiagrammType,
+    };
+    use std::{env, sync::Once};
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = env_logger::try_init();
+        })
+    }
+
+    #[test]
+    fn convert_test() {
+        call_once();
+        assert_eq!(10.0, UnitSystem::TonnesForce.force(TonsForce(10.0)));
+        assert_eq!(
+            (10.0 * EART_GRAVITY).my_round(6),
+            UnitSystem::KiloNewtons.force(TonsForce(10.0)).my_round(6)
+        );
+        assert_eq!(
+            (10.0 * EART_GRAVITY).my_round(6),
+            UnitSystem::KiloNewtons
+                .intensity(TonsPerMeter(10.0))
+                .my_round(6)
+        );
+        assert_eq!(
+            (1000.0 * EART_GRAVITY / 1000.0).my_round(6),
+            UnitSystem::MegaNewtons
+                .moment(TonMeters(1000.0))
+                .my_round(6)
+        );
+        assert_eq!(
+            (100.0 * 1000.0 / EART_GRAVITY).my_round(6),
+            UnitSystem::TonnesForce
+                .stress(MegaPascals(100.0))
+                .my_round(6)
+        );
+        assert_eq!(100000.0, UnitSystem::KiloNewtons.stress(MegaPascals(100.0)));
+        assert_eq!("кПа", UnitSystem::KiloNewtons.stress_unit());
+    }
+
+    #[test]
+    fn label_test() {
+        call_once();
+        assert_eq!(
+            "Share force, [тс]",
+            DiagrammType::ShareForce.label(UnitSystem::TonnesForce)
+        );
+        assert_eq!(
+            "Bending moment, [кН*м]",
+            DiagrammType::BendingMoment.label(UnitSystem::KiloNewtons)
+        );
+        assert_eq!(
+            "Total shipload, [МН/м]",
+            DiagrammType::TotalShipload.label(UnitSystem::MegaNewtons)
+        );
+        assert_eq!(
+            DiagrammType::ShareForce.label(UnitSystem::default()),
+            DiagrammType::ShareForce.to_string()
+        );
+    }
+
+    #[test]
+    fn from_str_test() {
+        call_once();
+        assert_eq!(Ok(UnitSystem::KiloNewtons), "kN".parse());
+        assert_eq!(Ok(UnitSystem::MegaNewtons), "mn".parse());
+        assert_eq!(Ok(UnitSystem::TonnesForce), "t".parse());
+        assert!("lb".parse::<UnitSystem>().is_err());
+    }
+
+    #[test]
+    fn from_file_test() {
+        call_once();
+        assert_eq!(
+            Ok(UnitSystem::TonnesForce),
+            UnitSystem::from_file("./input_data/input_data.json".to_string())
+        );
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::round::Round,
+        cross_section_properties::{
+            cross_section::CrossSection, cross_sections::CrossSections, plate_element::PlateElement,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn cross_sections(file: &str) -> Result<CrossSections, String> {
+        CrossSections::from_file(format!(
+            "src/tests/unit/strength/test_data/cross_sections/{file}"
+        ))
+    }
+
+    ///
+    /// Коробчатое сечение 10 x 10 м с толщиной стенок 10 мм.
+    fn box_section() -> CrossSection {
+        cross_sections("box.json").unwrap().sections()[0].clone()
+    }
+
+    ///
+    /// Элементы сечения из файла elements.json.
+    fn element(index: usize) -> PlateElement {
+        cross_sections("elements.json").unwrap().sections()[0].elements()[index]
+    }
+
+    #[test]
+    fn plate_element_test() {
+        call_once();
+        let element = element(0);
+        assert_eq!(5.0, element.length());
+        assert_eq!(0.06, element.area().my_round(6));
+        assert_eq!(2.0, element.z());
+        assert_eq!(0.08, element.own_moment_of_inertia().my_round(6));
+        assert!(cross_sections("zero_thickness.json")
+            .unwrap_err()
+            .contains("Толщина элемента сечения должна быть больше нуля: 0 мм."));
+        assert!(cross_sections("zero_length.json")
+            .unwrap_err()
+            .contains("Элемент сечения (1, 2) - (1, 2) имеет нулевую длину."));
+    }
+
+    #[test]
+    fn box_section_test() {
+        call_once();
+        let section = box_section();
+        assert_eq!(0.4, section.area().my_round(6));
+        assert_eq!(5.0, section.neutral_axis().my_round(6));
+        // I = 2 * 0.1 * 5^2 + 2 * 0.1 * 10^2 / 12.
+        assert_eq!(6.666667, section.moment_of_inertia().my_round(6));
+        assert_eq!((0.0, 10.0), section.z_range());
+    }
+
+    #[test]
+    fn empty_section_test() {
+        call_once();
+        assert!(cross_sections("empty_section.json")
+            .unwrap_err()
+            .contains("Сечение с абсциссой 10 м не содержит элементов."));
+    }
+
+    #[test]
+    fn shear_properties_test() {
+        call_once();
+        let section = box_section();
+        // Палуба 0.1 м^2 * 5 м и две половины бортов 2 * 0.05 м^2 * 2.5 м.
+        assert_eq!(0.75, section.first_moment_of_area().my_round(6));
+        assert_eq!(20.0, section.shear_thickness());
+        assert_eq!(5.625, section.shear_stress_factor().unwrap().my_round(6));
+        let element = element(1);
+        assert!(element.crosses(1.0));
+        assert!(!element.crosses(3.0));
+        // Выше оси z = 1 м находятся 2/3 элемента с центром тяжести на z = 2 м.
+        assert_eq!(
+            (0.05 * 2.0 / 3.0).my_round(6),
+            element.first_moment_above(1.0).my_round(6)
+        );
+        let deck_only = cross_sections("two_plates.json").unwrap().sections()[0].clone();
+        assert_eq!(
+            Err("Сечение с абсциссой 5 м не содержит элементов, пересекающих нейтральную ось (обшивка борта, продольная переборка).".to_string()),
+            deck_only.shear_stress_factor()
+        );
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{core::round::Round, cross_section_properties::cross_sections::CrossSections};
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn cross_sections(file: &str) -> Result<CrossSections, String> {
+        CrossSections::from_file(format!(
+            "src/tests/unit/strength/test_data/cross_sections/{file}"
+        ))
+    }
+
+    #[test]
+    fn moment_of_inertia_test() {
+        call_once();
+        // Сечения из двух горизонтальных пластин шириной 10 м на расстоянии 10 м друг от друга.
+        let sections = cross_sections("two_plates_sections.json").unwrap();
+        // I = 2 * A * 5^2, A = 10 м * t.
+        assert_eq!(5.0, sections.moment_of_inertia(-50.0).unwrap().my_round(6));
+        assert_eq!(7.5, sections.moment_of_inertia(0.0).unwrap().my_round(6));
+        assert_eq!(10.0, sections.moment_of_inertia(50.0).unwrap().my_round(6));
+        // За пределами крайних сечений.
+        assert_eq!(5.0, sections.moment_of_inertia(-60.0).unwrap().my_round(6));
+        assert_eq!(10.0, sections.moment_of_inertia(60.0).unwrap().my_round(6));
+        assert_eq!(5.0, sections.neutral_axis(20.0).unwrap().my_round(6));
+    }
+
+    #[test]
+    fn validate_test() {
+        call_once();
+        assert_eq!(
+            Err("Поперечные сечения корпуса не заданы.".to_string()),
+            CrossSections::new(vec![])
+        );
+        assert!(cross_sections("without_sections.json")
+            .unwrap_err()
+            .contains("Поперечные сечения корпуса не заданы."));
+        assert!(cross_sections("unsorted.json").unwrap_err().contains(
+            "Абсцисса сечения 0 м не больше абсциссы предыдущего сечения 0 м. Сечения должны быть отсортированы по абсциссе."
+        ));
+    }
+
+    #[test]
+    fn from_file_test() {
+        call_once();
+        let sections =
+            CrossSections::from_file("input_data/cross_sections.json".to_string()).unwrap();
+        assert_eq!(4, sections.sections().len());
+        let midship = sections.moment_of_inertia(0.0).unwrap();
+        assert_eq!(149.536, midship.my_round(3));
+        assert!(sections.moment_of_inertia(-117.5).unwrap() < midship);
+        assert!(sections.shear_stress_factor(0.0).unwrap() > 0.0);
+        assert!(
+            sections.shear_stress_factor(-117.5).unwrap()
+                > sections.shear_stress_factor(0.0).unwrap()
+        );
+        assert!(cross_sections("two_plates.json")
+            .unwrap()
+            .shear_stress_factor(0.0)
+            .is_err());
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::round::Round,
+        cross_section_properties::{
+            cross_sections::CrossSections, load_shortening::LoadShortening,
+            plate_element::PlateElement,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    ///
+    /// Элементы сечения из файла elements.json.
+    fn element(index: usize) -> PlateElement {
+        CrossSections::from_file(
+            "src/tests/unit/strength/test_data/cross_sections/elements.json".to_string(),
+        )
+        .unwrap()
+        .sections()[0]
+            .elements()[index]
+    }
+
+    #[test]
+    fn hard_corner_test() {
+        call_once();
+        let element = element(2);
+        let law = LoadShortening::from_element(&element);
+        assert_eq!(
+            LoadShortening::HardCorner {
+                yield_stress: 235.0
+            },
+            law
+        );
+        assert_eq!(103.0, law.stress(0.0005).my_round(6));
+        assert_eq!(-103.0, law.stress(-0.0005).my_round(6));
+        assert_eq!(235.0, law.stress(0.01));
+        assert_eq!(-235.0, law.stress(-0.01));
+    }
+
+    #[test]
+    fn stiffened_panel_test() {
+        call_once();
+        let element = element(3);
+        let panel = element.panel().unwrap();
+        let critical_stress = panel.critical_stress(8.0, 235.0);
+        assert_eq!(
+            LoadShortening::StiffenedPanel {
+                yield_stress: 235.0,
+                critical_stress
+            },
+            LoadShortening::from_element(&element)
+        );
+        let law = LoadShortening::from_element(&element);
+        let critical_strain = critical_stress / 2.06e5;
+        // Растяжение - до предела текучести.
+        assert_eq!(235.0, law.stress(0.01));
+        // Сжатие - упругая работа до критического напряжения и снижение после потери устойчивости.
+        assert_eq!(
+            (-critical_stress / 2.0).my_round(6),
+            law.stress(-critical_strain / 2.0).my_round(6)
+        );
+        assert_eq!(
+            (-critical_stress).my_round(6),
+            law.stress(-critical_strain).my_round(6)
+        );
+        assert_eq!(
+            (-critical_stress / 2.0).my_round(6),
+            law.stress(-4.0 * critical_strain).my_round(6)
+        );
+    }
+}
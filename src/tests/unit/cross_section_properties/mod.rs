@@ -1 +1,5 @@
-
+mod cross_section_test;
+mod cross_sections_test;
+mod load_shortening_test;
+mod stiffened_panel_test;
+mod ultimate_strength_test;
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::round::Round,
+        cross_section_properties::{
+            cross_sections::CrossSections,
+            plate_element::PlateElement,
+            stiffened_panel::{johnson_ostenfeld, PanelLocation, StiffenedPanel},
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn cross_sections(file: &str) -> Result<CrossSections, String> {
+        CrossSections::from_file(format!(
+            "src/tests/unit/strength/test_data/cross_sections/{file}"
+        ))
+    }
+
+    ///
+    /// Элементы сечения из файла elements.json.
+    fn element(index: usize) -> PlateElement {
+        cross_sections("elements.json").unwrap().sections()[0].elements()[index]
+    }
+
+    fn bottom_panel() -> StiffenedPanel {
+        *element(3).panel().unwrap()
+    }
+
+    #[test]
+    fn johnson_ostenfeld_test() {
+        call_once();
+        assert_eq!(100.0, johnson_ostenfeld(100.0, 235.0));
+        assert_eq!(117.5, johnson_ostenfeld(117.5, 235.0));
+        assert_eq!(176.25, johnson_ostenfeld(235.0, 235.0));
+        assert!(johnson_ostenfeld(1.0e6, 235.0) < 235.0);
+    }
+
+    #[test]
+    fn euler_stress_test() {
+        call_once();
+        let panel = bottom_panel();
+        // 3.6 * E * (24 / 800)^2.
+        assert_eq!(667.44, panel.plate_euler_stress(24.0).my_round(2));
+        // pi^2 * E * 15000e-8 / ((46e-4 + 0.8 * 0.024) * 3.2^2).
+        assert_eq!(1251.36, panel.column_euler_stress(24.0).my_round(2));
+        assert_eq!(214.31, panel.critical_stress(24.0, 235.0).my_round(2));
+        // Тонкая пластина теряет устойчивость в упругой области.
+        assert_eq!(
+            panel.plate_euler_stress(8.0),
+            panel.critical_stress(8.0, 235.0)
+        );
+    }
+
+    #[test]
+    fn validate_test() {
+        call_once();
+        assert!(cross_sections("zero_stiffener_spacing.json").unwrap_err().contains(
+            "Панель (палуба): расстояние между ребрами, пролет и момент инерции ребра должны быть больше нуля."
+        ));
+        assert!(cross_sections("negative_stiffener_area.json")
+            .unwrap_err()
+            .contains("Панель (днище): площадь ребра не может быть отрицательной: -1 см^2."));
+        let plain = element(4);
+        assert_eq!(235.0, plain.yield_stress());
+        assert!(plain.panel().is_none());
+        let stiffened = element(5);
+        assert_eq!(315.0, stiffened.yield_stress());
+        assert_eq!(PanelLocation::Bottom, stiffened.panel().unwrap().location());
+        assert!(cross_sections("zero_yield_stress.json")
+            .unwrap_err()
+            .contains("Предел текучести материала элемента должен быть больше нуля: 0 МПа."));
+    }
+}
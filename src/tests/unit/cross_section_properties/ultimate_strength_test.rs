@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::{physical_constants::EART_GRAVITY, round::Round},
+        cross_section_properties::{
+            cross_section::CrossSection, cross_sections::CrossSections,
+            ultimate_strength::UltimateStrength,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    ///
+    /// Коробчатое сечение 10 x 10 м с толщиной стенок 10 мм: I = 6.6667 м^4, z_na = 5 м.
+    /// В файле box_deck_panel.json палуба подкреплена продольными ребрами жесткости.
+    fn box_section(file: &str) -> CrossSection {
+        CrossSections::from_file(format!(
+            "src/tests/unit/strength/test_data/cross_sections/{file}"
+        ))
+        .unwrap()
+        .sections()[0]
+            .clone()
+    }
+
+    #[test]
+    fn hard_corner_box_test() {
+        call_once();
+        let ultimate_strength = UltimateStrength::new(&box_section("box.json"));
+        // Момент начала текучести sigma_y * I / 5 и пластический момент sigma_y * (0.1 * 5 * 2 + 0.05 * 2.5 * 4) [т*м].
+        let yield_moment = 235.0e3 * 6.666667 / 5.0 / EART_GRAVITY;
+        let plastic_moment = 235.0e3 * 1.5 / EART_GRAVITY;
+        let hogging_capacity = ultimate_strength.hogging_capacity();
+        assert!(yield_moment < hogging_capacity && hogging_capacity < plastic_moment);
+        assert_eq!(
+            hogging_capacity.my_round(3),
+            ultimate_strength.sagging_capacity().my_round(3)
+        );
+        // В упругой области M = E * I * kappa, нейтральная ось на середине высоты. Борта разбиты
+        // на 10 частей, собственный момент инерции бортов учитывается с множителем 1 - 1 / 10^2.
+        let point = ultimate_strength
+            .curve()
+            .iter()
+            .find(|point| point.curvature > 0.0)
+            .unwrap();
+        assert_eq!(5.0, point.neutral_axis.my_round(6));
+        assert_eq!(
+            (2.06e8 * 6.65 * point.curvature / EART_GRAVITY).my_round(0),
+            point.moment.my_round(0)
+        );
+    }
+
+    #[test]
+    fn stiffened_deck_box_test() {
+        call_once();
+        let ultimate_strength = UltimateStrength::new(&box_section("box_deck_panel.json"));
+        // При прогибе палуба сжата и теряет устойчивость раньше текучести.
+        assert!(ultimate_strength.sagging_capacity() < ultimate_strength.hogging_capacity());
+        let sagging = ultimate_strength
+            .curve()
+            .iter()
+            .rfind(|point| point.curvature < 0.0)
+            .unwrap();
+        // После потери устойчивости палубы нейтральная ось опускается к днищу.
+        assert!(sagging.neutral_axis < 5.0);
+    }
+
+    #[test]
+    fn input_data_test() {
+        call_once();
+        let cross_sections =
+            CrossSections::from_file("input_data/cross_sections.json".to_string()).unwrap();
+        let ends = UltimateStrength::new(&cross_sections.sections()[0]);
+        let middle = UltimateStrength::new(&cross_sections.sections()[1]);
+        assert!(ends.hogging_capacity() < middle.hogging_capacity());
+        assert!(middle.sagging_capacity() > 0.0);
+    }
+}
@@ -1,3 +1,4 @@
 mod core;
 mod cross_section_properties;
+mod server;
 mod strength;
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        io::{BufReader, Error, ErrorKind, Read},
+        sync::Once,
+    };
+
+    use crate::server::http::{HttpRequest, HttpResponse};
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    #[test]
+    fn from_reader_ok_test() {
+        call_once();
+        let raw = "POST /strength?verbose=1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: 13\r\n\r\n{\"shiploads\"}";
+        let mut reader = BufReader::new(raw.as_bytes());
+        let request = HttpRequest::from_reader(&mut reader).unwrap();
+        assert_eq!(
+            HttpRequest::new(
+                "POST".to_string(),
+                "/strength".to_string(),
+                "{\"shiploads\"}".to_string()
+            ),
+            request
+        );
+    }
+
+    #[test]
+    fn from_reader_without_body_ok_test() {
+        call_once();
+        let raw = "get /state HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        let mut reader = BufReader::new(raw.as_bytes());
+        let request = HttpRequest::from_reader(&mut reader).unwrap();
+        assert_eq!("GET", request.method());
+        assert_eq!("/state", request.path());
+        assert_eq!("", request.body());
+    }
+
+    #[test]
+    fn from_reader_err_test() {
+        call_once();
+        let mut reader = BufReader::new("\r\n".as_bytes());
+        assert!(HttpRequest::from_reader(&mut reader).is_err());
+        let raw = "POST /strength HTTP/1.1\r\nContent-Length: abc\r\n\r\n";
+        let mut reader = BufReader::new(raw.as_bytes());
+        assert!(HttpRequest::from_reader(&mut reader).is_err());
+    }
+
+    ///
+    /// Поток, который передает заголовки запроса, а затем перестает отвечать.
+    struct StalledStream {
+        data: &'static [u8],
+    }
+
+    impl Read for StalledStream {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.data.is_empty() {
+                return Err(Error::new(ErrorKind::WouldBlock, "timeout"));
+            }
+            self.data.read(buf)
+        }
+    }
+
+    #[test]
+    fn from_reader_short_body_test() {
+        call_once();
+        let raw = "POST /strength HTTP/1.1\r\nContent-Length: 100\r\n\r\n{}";
+        let mut reader = BufReader::new(raw.as_bytes());
+        assert_eq!(
+            400,
+            HttpRequest::from_reader(&mut reader).unwrap_err().status()
+        );
+        let mut reader = BufReader::new(StalledStream {
+            data: b"POST /strength HTTP/1.1\r\nContent-Length: 100\r\n\r\n{}",
+        });
+        assert_eq!(
+            408,
+            HttpRequest::from_reader(&mut reader).unwrap_err().status()
+        );
+    }
+
+    #[test]
+    fn write_to_ok_test() {
+        call_once();
+        let response = HttpResponse::error(404, "Маршрут /abc не найден.".to_string());
+        let mut buffer = vec![];
+        response.write_to(&mut buffer).unwrap();
+        let written = String::from_utf8(buffer).unwrap();
+        assert!(written.starts_with("HTTP/1.1 404 Not Found\r\n"));
+        assert!(written.contains(&format!("Content-Length: {}\r\n", response.body().len())));
+        assert!(written.ends_with("{\"error\":\"Маршрут /abc не найден.\"}"));
+    }
+}
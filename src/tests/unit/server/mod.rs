@@ -0,0 +1,2 @@
+mod http_test;
+mod strength_server_test;
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, sync::Once};
+
+    use crate::{
+        core::unit_system::UnitSystem,
+        server::{http::HttpRequest, strength_server::Server},
+        strength::{load::shiploads::Shiploads, ship::ship_model::Ship},
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn server() -> Server {
+        let ship = Ship::from_files(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let shiploads =
+            Shiploads::from_file("src/tests/unit/strength/test_data/empty_ship.json".to_string())
+                .unwrap();
+        Server::new(ship, &shiploads)
+    }
+
+    fn request(method: &str, path: &str, body: &str) -> HttpRequest {
+        HttpRequest::new(method.to_string(), path.to_string(), body.to_string())
+    }
+
+    #[test]
+    fn post_strength_ok_test() {
+        call_once();
+        let mut server = server();
+        let body = fs::read_to_string("src/tests/unit/strength/test_data/full_ship.json").unwrap();
+        let response = server.handle(&request("POST", "/strength", &body));
+        assert_eq!(200, response.status());
+        let result: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(13.09, result["aft_draft"]);
+        assert_eq!(13.23, result["nose_draft"]);
+        assert!(result["diagrams"]["bending_moment"]["spatium_functions"].is_array());
+        assert!(result["strength_check"]["hogging_utilisation"].is_number());
+        assert!(result["draft_comparison"]["trim_difference"].is_number());
+
+        // Контролируемое состояние не изменилось.
+        let response = server.handle(&request("GET", "/state", ""));
+        let state: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(2.34, state["result"]["aft_draft"]);
+    }
+
+    #[test]
+    fn unit_system_test() {
+        call_once();
+        let mut si_server = server().with_unit_system(UnitSystem::KiloNewtons);
+        let response = si_server.handle(&request("GET", "/state", ""));
+        let state: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(
+            "kilo_newtons",
+            state["result"]["strength_check"]["unit_system"]
+        );
+        let body = fs::read_to_string("src/tests/unit/strength/test_data/full_ship.json").unwrap();
+        let response = si_server.handle(&request("POST", "/strength", &body));
+        let si: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        let response = server().handle(&request("POST", "/strength", &body));
+        let tf: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        let max_share_force = |result: &serde_json::Value| {
+            result["strength_check"]["max_share_force"]
+                .as_f64()
+                .unwrap()
+        };
+        assert_eq!("kilo_newtons", si["strength_check"]["unit_system"]);
+        assert!((max_share_force(&si) - max_share_force(&tf) * 9.81).abs() < 0.1);
+        assert_eq!(
+            tf["strength_check"]["hogging_utilisation"],
+            si["strength_check"]["hogging_utilisation"]
+        );
+        let first_moment = |result: &serde_json::Value| {
+            result["diagrams"]["bending_moment"]["spatium_functions"][10]["f_x1"]
+                .as_f64()
+                .unwrap()
+        };
+        assert!((first_moment(&si) - first_moment(&tf) * 9.81).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn post_state_ok_test() {
+        call_once();
+        let mut server = server();
+        let body = r#"{"shiploads": [{"value": 90000.0, "center_gravity": {"x": 0.0, "y": 0.0, "z": 0.0}, "length": 11.75}]}"#;
+        let response = server.handle(&request("POST", "/state", body));
+        assert_eq!(200, response.status());
+        let response = server.handle(&request("GET", "/alarms", ""));
+        assert_eq!(200, response.status());
+        let alarms: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(1, alarms.as_array().unwrap().len());
+        assert_eq!("Alarm", alarms[0]["level"]);
+    }
+
+    #[test]
+    fn bad_request_test() {
+        call_once();
+        let mut server = server();
+        let response = server.handle(&request("POST", "/strength", "{"));
+        assert_eq!(400, response.status());
+        // Тело запроса проверяется так же, как файл схемы загрузки текущей версии.
+        let body = r#"{"version": 2, "shiploads": [{"value": 10.0, "center_gravity": {"x": 0.0, "y": 0.0, "z": 0.0}, "length": 10.0}]}"#;
+        let response = server.handle(&request("POST", "/strength", body));
+        assert_eq!(400, response.status());
+        assert!(response.body().contains("не задано название `name`"));
+        let response = server.handle(&request("DELETE", "/state", ""));
+        assert_eq!(405, response.status());
+        let response = server.handle(&request("GET", "/unknown", ""));
+        assert_eq!(404, response.status());
+    }
+
+    #[test]
+    fn post_draft_ok_test() {
+        call_once();
+        let mut server = server();
+        let body = fs::read_to_string("src/tests/unit/strength/test_data/full_ship.json").unwrap();
+        let response = server.handle(&request("POST", "/draft", &body));
+        assert_eq!(200, response.status());
+        let result: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert!(result["aft_draft"].is_number());
+        assert!(result["nose_draft"].is_number());
+        assert!(result["mct1cm"].is_number());
+        let response = server.handle(&request("GET", "/draft", ""));
+        assert_eq!(405, response.status());
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::strength::{
+        ballast::ballast_optimizer::BallastOptimizer, load::shiploads::Shiploads,
+        ship::ship_model::Ship,
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn optimizer(file: &str) -> Result<BallastOptimizer, String> {
+        BallastOptimizer::from_file(format!("src/tests/unit/strength/test_data/ballast/{file}"))
+    }
+
+    #[test]
+    fn optimize_ok_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let cargo =
+            Shiploads::from_file("src/tests/unit/strength/test_data/empty_ship.json".to_string())
+                .unwrap();
+        // Судно порожнем имеет дифферент на нос 1.73 м.
+        let optimizer = optimizer("ballast.json").unwrap();
+        let solution = serde_json::to_value(optimizer.optimize(&ship, &cargo).unwrap()).unwrap();
+        // Заданные осадка и дифферент достигнуты, прочность обеспечена.
+        assert_eq!(true, solution["targets_met"]);
+        assert!(solution["trim"].as_f64().unwrap().abs() <= 0.5);
+        assert_eq!(0.0, solution["fillings"][1]["filling"]);
+        assert!(solution["fillings"][0]["filling"].as_f64().unwrap() > 0.0);
+        assert_eq!(
+            solution["fillings"][0]["ballast"],
+            solution["total_ballast"]
+        );
+    }
+
+    #[test]
+    fn from_file_err_test() {
+        call_once();
+        assert!(optimizer("without_tanks.json").is_err());
+        assert!(optimizer("initial_step_err.json").is_err());
+        assert!(optimizer("min_step_err.json").is_err());
+    }
+
+    #[test]
+    fn from_file_ok_test() {
+        call_once();
+        let optimizer = BallastOptimizer::from_file("input_data/ballast.json".to_string()).unwrap();
+        let tanks = &serde_json::to_value(&optimizer).unwrap()["tanks"];
+        assert_eq!(6, tanks.as_array().unwrap().len());
+        assert_eq!("Форпик", tanks[5]["name"]);
+    }
+}
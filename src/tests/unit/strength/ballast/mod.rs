@@ -0,0 +1 @@
+mod ballast_optimizer_test;
@@ -119,4 +119,27 @@ mod tests {
         assert!(frame.is_err());
         assert_eq!("Длины векторов, содержащих данные масштаба Бонжана для шпангоута, должны быть одинаковыми".to_string(), frame.unwrap_err())
     }
+
+    #[test]
+    fn drafts_not_increasing_err_test() {
+        call_once();
+        let frame = Frame::new(3, vec![1.0, 2.0, 2.0], vec![1.0, 2.0, 3.0], 0.0);
+        assert_eq!(
+            Err("Шпангоут 3, индекс 2: осадка 2 м не больше предыдущей осадки 2 м. Осадки должны строго возрастать.".to_string()),
+            frame
+        );
+    }
+
+    #[test]
+    fn areas_decreasing_err_test() {
+        call_once();
+        let frame = Frame::new(4, vec![1.0, 2.0, 3.0], vec![1.0, 2.5, 2.0], 0.0);
+        assert_eq!(
+            Err(
+                "Шпангоут 4, индекс 2: площадь 2 м^2 меньше площади при предыдущей осадке 2.5 м^2."
+                    .to_string()
+            ),
+            frame
+        );
+    }
 }
@@ -102,4 +102,62 @@ mod tests {
         .unwrap();
         assert_eq!(&first_frame, frames.last());
     }
+
+    #[test]
+    fn from_csv_file_test() {
+        call_once();
+        let json = Frames::from_json_file("./input_data/frames.json".to_string()).unwrap();
+        let csv = Frames::from_file("./input_data/frames.csv".to_string()).unwrap();
+        assert_eq!(json, csv);
+    }
+
+    #[test]
+    fn from_csv_file_without_abscissa_test() {
+        call_once();
+        let err = Frames::from_csv_file(
+            "src/tests/unit/strength/test_data/frames_without_abscissa.csv".to_string(),
+        )
+        .unwrap_err();
+        assert!(err.contains("abscissa"));
+    }
+
+    #[test]
+    fn from_json_file_migration_test() {
+        call_once();
+        let legacy =
+            Frames::from_json_file("src/tests/unit/strength/test_data/frames.json".to_string())
+                .unwrap();
+        let current = Frames::from_json_file("./input_data/frames.json".to_string()).unwrap();
+        assert_eq!(current.as_ref().len(), legacy.as_ref().len());
+        assert_eq!(current.first(), legacy.first());
+    }
+
+    #[test]
+    fn frames_not_sorted_err_test() {
+        call_once();
+        let frame = |id: u64, abscissa: f64| {
+            Frame::new(id, vec![1.0, 2.0], vec![1.0, 2.0], abscissa).unwrap()
+        };
+        assert_eq!(
+            Err("Шпангоут 2, индекс 2: абсцисса 5 м не больше абсциссы предыдущего шпангоута 10 м. Шпангоуты должны быть отсортированы по абсциссе.".to_string()),
+            Frames::new(vec![frame(0, -10.0), frame(1, 10.0), frame(2, 5.0)])
+        );
+        assert_eq!(
+            Err(
+                "Шпангоут 0, индекс 2: номер шпангоута совпадает с номером шпангоута с индексом 0."
+                    .to_string()
+            ),
+            Frames::new(vec![frame(0, -10.0), frame(1, 0.0), frame(0, 10.0)])
+        );
+    }
+
+    #[test]
+    fn frames_from_file_validation_test() {
+        call_once();
+        let err = Frames::from_json_file(
+            "src/tests/unit/strength/test_data/frames_not_increasing.json".to_string(),
+        )
+        .unwrap_err();
+        assert!(err.contains("Шпангоут 1, индекс 1"));
+    }
 }
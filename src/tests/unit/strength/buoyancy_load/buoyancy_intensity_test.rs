@@ -40,8 +40,8 @@ mod tests {
             "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string();
         let lw = Lightweight::new(13550.0);
         let ship_dimensions = ShipDimensions::new(235.0, 20, 0.8);
-        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions.clone(), lw);
-        let shiploads = Shiploads::from_json_file(shiploads_file).unwrap();
+        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions, lw);
+        let shiploads = Shiploads::from_file(shiploads_file).unwrap();
         let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
         let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i).unwrap();
         let dw = Deadweight::from_shiplods(&shiploads);
@@ -62,7 +62,7 @@ mod tests {
         let b_i =
             BuoyancyIntensity::constructor(ship_dimensions, &draft, &bonjean_scale, water_density)
                 .unwrap();
-        let total_buoyancy = b_i.buoyancy_intensity().integral();
+        let total_buoyancy = b_i.buoyancy_intensity().integral().value();
         let d_t_v = d_t.displacement_tonnage();
         let error = (((total_buoyancy.abs() - d_t_v).abs() / d_t_v.min(total_buoyancy.abs()))
             * 100.0)
@@ -83,8 +83,8 @@ mod tests {
             "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string();
         let lw = Lightweight::new(13550.0);
         let ship_dimensions = ShipDimensions::new(235.0, 20, 0.8);
-        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions.clone(), lw);
-        let shiploads = Shiploads::from_json_file(shiploads_file).unwrap();
+        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions, lw);
+        let shiploads = Shiploads::from_file(shiploads_file).unwrap();
         let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
         let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i).unwrap();
         let dw = Deadweight::from_shiplods(&shiploads);
@@ -105,7 +105,7 @@ mod tests {
         let b_i =
             BuoyancyIntensity::constructor(ship_dimensions, &draft, &bonjean_scale, water_density)
                 .unwrap();
-        let total_buoyancy = b_i.buoyancy_intensity().integral();
+        let total_buoyancy = b_i.buoyancy_intensity().integral().value();
         let d_t_v = d_t.displacement_tonnage();
         let error = (((total_buoyancy.abs() - d_t_v).abs() / d_t_v.min(total_buoyancy.abs()))
             * 100.0)
@@ -39,7 +39,7 @@ mod tests {
         ]);
         let ship_dimensions = ShipDimensions::new(235.0, 20, 0.6);
         let lightweight_intensity = Rc::new(LightweightIntensity::from_ship_input_data(
-            ship_dimensions.clone(),
+            ship_dimensions,
             lightweight,
         ));
         let deadweight_intensity =
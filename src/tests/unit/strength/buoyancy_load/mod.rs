@@ -1,3 +1,4 @@
 mod buoyancy_intensity_test;
 mod lcg_test;
+mod quick_draft_test;
 mod ship_trimming_test;
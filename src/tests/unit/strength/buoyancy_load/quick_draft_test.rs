@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::{round::Round, system_of_units::Meters},
+        strength::{
+            buoyancy_intensity::quick_draft::{DraftComparison, QuickDraft},
+            hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
+            load::shiploads::Shiploads,
+            ship::ship_model::Ship,
+            strength::Strength,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    #[test]
+    fn quick_draft_by_lmr_test() {
+        call_once();
+        // Кривая mct1cm не задана, момент определяется по продольному метацентрическому радиусу.
+        let hydrostatic_curves = HydrostaticCurves::from_json_file(
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let quick =
+            QuickDraft::new(0.0, 23758.4, 1.025, 1.025, &hydrostatic_curves, 235.0).unwrap();
+        assert_eq!(4.0, quick.mean_draft().my_round(2));
+        assert_eq!(-11.6, quick.lcb());
+        assert_eq!(778.82, quick.mct1cm().my_round(2));
+        assert_eq!(3.54, quick.trim().my_round(2));
+        let (aft_draft, nose_draft) = quick.drafts();
+        assert_eq!(
+            (2.41, 5.94),
+            (aft_draft.my_round(2), nose_draft.my_round(2))
+        );
+    }
+
+    #[test]
+    fn quick_draft_by_mct1cm_test() {
+        call_once();
+        let hydrostatic_curves =
+            HydrostaticCurves::from_json_file("input_data/hydrostatic_curves.json".to_string())
+                .unwrap();
+        let quick =
+            QuickDraft::new(-11.6, 23758.4, 1.025, 1.025, &hydrostatic_curves, 235.0).unwrap();
+        assert_eq!(778.8, quick.mct1cm());
+        // Центр тяжести над центром величины - посадка на ровный киль.
+        assert_eq!(0.0, quick.trim());
+        assert_eq!((4.0, 4.0), quick.drafts());
+    }
+
+    #[test]
+    fn quick_draft_water_density_test() {
+        call_once();
+        // В пресной воде судно сидит так же, как в морской воде с водоизмещением,
+        // увеличенным пропорционально отношению плотностей.
+        let hydrostatic_curves =
+            HydrostaticCurves::from_json_file("input_data/hydrostatic_curves.json".to_string())
+                .unwrap();
+        let fresh = QuickDraft::new(0.0, 23758.4, 1.0, 1.025, &hydrostatic_curves, 235.0).unwrap();
+        let sea = QuickDraft::new(
+            0.0,
+            23758.4 * 1.025,
+            1.025,
+            1.025,
+            &hydrostatic_curves,
+            235.0,
+        )
+        .unwrap();
+        assert_eq!(sea.mean_draft(), fresh.mean_draft());
+        assert_eq!(sea.lcb(), fresh.lcb());
+        assert_eq!(sea.trim().my_round(6), fresh.trim().my_round(6));
+        assert!(fresh.mean_draft() > 4.0);
+    }
+
+    #[test]
+    fn quick_draft_out_of_range_test() {
+        call_once();
+        let hydrostatic_curves =
+            HydrostaticCurves::from_json_file("input_data/hydrostatic_curves.json".to_string())
+                .unwrap();
+        assert_eq!(
+            Err(
+                "Весовое водоизмещение 90000 тонн вне диапазона гидростатических кривых."
+                    .to_string()
+            ),
+            QuickDraft::new(0.0, 90000.0, 1.025, 1.025, &hydrostatic_curves, 235.0)
+        );
+    }
+
+    #[test]
+    fn draft_comparison_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let shiploads =
+            Shiploads::from_file("src/tests/unit/strength/test_data/empty_ship.json".to_string())
+                .unwrap();
+        let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+        let quick = QuickDraft::from_ship(&ship, &shiploads).unwrap();
+        assert_eq!(quick, strength.quick_draft().unwrap());
+        let (Meters(aft_draft), Meters(nose_draft)) = strength.draft().unwrap();
+        let comparison =
+            serde_json::to_value(DraftComparison::new(&quick, (aft_draft, nose_draft))).unwrap();
+        let difference = |name: &str| comparison[name].as_f64().unwrap();
+        let (quick_aft_draft, quick_nose_draft) = quick.drafts();
+        assert_eq!(quick_aft_draft - aft_draft, difference("aft_difference"));
+        assert_eq!(quick_nose_draft - nose_draft, difference("nose_difference"));
+        assert_eq!(
+            (quick_nose_draft - quick_aft_draft - (nose_draft - aft_draft)).my_round(6),
+            difference("trim_difference").my_round(6)
+        );
+        // Быстрая оценка дает посадку, близкую к результату удифферентовки.
+        assert!(difference("aft_difference").abs() < 0.5);
+        assert!(difference("nose_difference").abs() < 0.5);
+    }
+}
@@ -10,7 +10,9 @@ mod tests {
                 displacement::Displacement, displacement_intensity::DisplacementIntensity,
                 displacement_tonnage::DisplacementTonnage,
             },
-            hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
+            hydrostatic_curves::{
+                hydrostatic_curves::HydrostaticCurves, hydrostatic_typedata::HydrostaticTypeData,
+            },
             lightweight::{lightweight::Lightweight, lightweight_intensity::LightweightIntensity},
             load::shiploads::Shiploads,
             ship::ship_dimensions::ShipDimensions,
@@ -40,8 +42,8 @@ mod tests {
             "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string();
         let lw = Lightweight::new(13550.0);
         let ship_dimensions = ShipDimensions::new(235.0, 20, 0.8);
-        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions.clone(), lw);
-        let shiploads = Shiploads::from_json_file(shiploads_file).unwrap();
+        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions, lw);
+        let shiploads = Shiploads::from_file(shiploads_file).unwrap();
         let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
         let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i).unwrap();
         let dw = Deadweight::from_shiplods(&shiploads);
@@ -77,8 +79,8 @@ mod tests {
             "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string();
         let lw = Lightweight::new(13550.0);
         let ship_dimensions = ShipDimensions::new(235.0, 20, 0.8);
-        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions.clone(), lw);
-        let shiploads = Shiploads::from_json_file(shiploads_file).unwrap();
+        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions, lw);
+        let shiploads = Shiploads::from_file(shiploads_file).unwrap();
         let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
         let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i).unwrap();
         let dw = Deadweight::from_shiplods(&shiploads);
@@ -102,4 +104,86 @@ mod tests {
             (aft_draft.my_round(2), nose_draft.my_round(2))
         );
     }
+
+    ///
+    /// Удифферентовка с начальным приближением по быстрой оценке посадки и от посадки на ровный киль.
+    /// Возвращает дифферент быстрой оценки и осадки кормы и носа для обоих начальных приближений.
+    fn seeded_and_even_keel_trimming(shiploads_file: &str) -> (f64, (f64, f64), (f64, f64)) {
+        let frames_file = "src/tests/unit/strength/test_data/frames.json".to_string();
+        let hydrostatic_curves_file =
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string();
+        let lw = Lightweight::new(13550.0);
+        let ship_dimensions = ShipDimensions::new(235.0, 20, 0.8);
+        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions, lw);
+        let shiploads = Shiploads::from_file(shiploads_file.to_string()).unwrap();
+        let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
+        let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i).unwrap();
+        let dw = Deadweight::from_shiplods(&shiploads);
+        let d_t = DisplacementTonnage::new(lw, dw);
+        let water_density = WaterDensity::new(1.025);
+        let frames = Frames::from_json_file(frames_file).unwrap();
+        let bonjean_scale = Rc::new(BonjeanScale::new(frames, ship_dimensions));
+        let disp = Rc::new(Displacement::new(
+            bonjean_scale.clone(),
+            ship_dimensions,
+            water_density,
+        ));
+        let lcb = Rc::new(LCB::new(bonjean_scale.clone(), ship_dimensions));
+        let lcg = LCG::from_disp_i(&disp_i);
+        let hydrostatic_curves =
+            HydrostaticCurves::from_json_file(hydrostatic_curves_file).unwrap();
+        let draft = Draft::new(lcb.clone(), disp, lcg, d_t, hydrostatic_curves.clone());
+        let quick = draft.quick_draft(ship_dimensions).unwrap();
+        let lcf = hydrostatic_curves
+            .get_data_by_draft(quick.mean_draft(), HydrostaticTypeData::LCF)
+            .unwrap()
+            .unwrap();
+        let lbp = ship_dimensions.lbp().value();
+        let even_keel = draft.trimming(lbp, quick.mean_draft(), lcf, 0.0).unwrap();
+        let seeded = draft
+            .trimming(lbp, quick.mean_draft(), lcf, quick.trim())
+            .unwrap();
+        for (aft_draft, nose_draft) in [even_keel, seeded] {
+            let lcb = lcb.lcb(aft_draft, nose_draft).unwrap();
+            assert!((lcg.lcg().abs() - lcb.abs()).abs() <= 0.001 * lbp);
+        }
+        (quick.trim(), even_keel, seeded)
+    }
+
+    #[test]
+    fn trimming_initial_guess_bow_trim_test() {
+        call_once();
+        // Начальное приближение с дифферентом на нос приводит к той же посадке, что и ровный киль.
+        let (trim, even_keel, seeded) =
+            seeded_and_even_keel_trimming("src/tests/unit/strength/test_data/empty_ship.json");
+        assert!(trim > 0.0, "{trim}");
+        assert!(seeded.1 > seeded.0, "{seeded:?}");
+        assert!(
+            (seeded.0 - even_keel.0).abs() <= 0.05,
+            "{seeded:?} {even_keel:?}"
+        );
+        assert!(
+            (seeded.1 - even_keel.1).abs() <= 0.05,
+            "{seeded:?} {even_keel:?}"
+        );
+    }
+
+    #[test]
+    fn trimming_initial_guess_stern_trim_test() {
+        call_once();
+        // Начальное приближение с дифферентом на корму приводит к той же посадке, что и ровный киль.
+        let (trim, even_keel, seeded) = seeded_and_even_keel_trimming(
+            "src/tests/unit/strength/test_data/stern_trimmed_ship.json",
+        );
+        assert!(trim < 0.0, "{trim}");
+        assert!(seeded.0 > seeded.1, "{seeded:?}");
+        assert!(
+            (seeded.0 - even_keel.0).abs() <= 0.05,
+            "{seeded:?} {even_keel:?}"
+        );
+        assert!(
+            (seeded.1 - even_keel.1).abs() <= 0.05,
+            "{seeded:?} {even_keel:?}"
+        );
+    }
 }
@@ -1,3 +1,4 @@
 mod displacement_intensity_test;
 mod displacement_test;
 mod displacement_tonnage_test;
+mod water_allowance_test;
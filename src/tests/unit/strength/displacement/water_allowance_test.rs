@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::{
+            round::Round,
+            system_of_units::{Meters, TonsPerCubicMeter},
+        },
+        strength::{
+            displacement::water_allowance::WaterAllowance,
+            hydrostatic_curves::hydrostatic_curves::HydrostaticCurves, load::shiploads::Shiploads,
+            ship::ship_model::Ship, strength::Strength,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn hydrostatic_curves() -> HydrostaticCurves {
+        HydrostaticCurves::from_json_file(
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn fwa_dwa_test() {
+        call_once();
+        // Площадь ватерлинии при осадке 2 м: 5658.69 м^2, TPC = 5658.69 * 1.025 / 100 = 58.0016 т/см.
+        let allowance = WaterAllowance::new(&hydrostatic_curves(), 1.025, 11510.4, 2.0).unwrap();
+        assert_eq!(0.04961, allowance.fwa().my_round(5));
+        assert_eq!(allowance.fwa(), allowance.dwa(1.0));
+        assert_eq!(0.0, allowance.dwa(1.025));
+        assert_eq!(0.0198, allowance.dwa(1.015).my_round(4));
+    }
+
+    #[test]
+    fn water_allowance_err_test() {
+        call_once();
+        assert!(WaterAllowance::new(&hydrostatic_curves(), 1.0, 11510.4, 2.0).is_err());
+        assert!(WaterAllowance::new(&hydrostatic_curves(), 1.025, 11510.4, 20.0).is_err());
+    }
+
+    #[test]
+    fn condition_water_density_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let shiploads =
+            Shiploads::from_file("src/tests/unit/strength/test_data/empty_ship.json".to_string())
+                .unwrap();
+        let sea_water = Strength::from_ship(&ship, &shiploads).unwrap();
+        let fresh_water =
+            Strength::from_ship(&ship, &shiploads.clone().with_water_density(1.0)).unwrap();
+        assert_eq!(TonsPerCubicMeter(1.025), sea_water.water_density());
+        assert_eq!(TonsPerCubicMeter(1.0), fresh_water.water_density());
+        let mean_draft = |strength: &Strength| {
+            let (Meters(aft_draft), Meters(nose_draft)) = strength.draft().unwrap();
+            (aft_draft + nose_draft) / 2.0
+        };
+        assert!(mean_draft(&fresh_water) > mean_draft(&sea_water));
+        assert!(Shiploads::new(vec![])
+            .with_water_density(0.0)
+            .validate_input_data()
+            .is_err());
+    }
+}
@@ -3,6 +3,9 @@ mod tests {
     use std::{env, sync::Once};
 
     use crate::core::round::Round;
+    use crate::core::system_of_units::{
+        Meters, SquareMeters, TonMetersPerCentimeter, Tons, TonsPerCentimeter,
+    };
     use crate::strength::hydrostatic_curves::hydrostatic_curves::HydrostaticCurves;
     use crate::strength::hydrostatic_curves::hydrostatic_typedata::HydrostaticTypeData;
 
@@ -81,4 +84,212 @@ mod tests {
         let value = hidrostatic_curves.mean_draft(89365.01).unwrap();
         assert!(value.is_none());
     }
+
+    #[test]
+    fn get_km_by_draft_test() {
+        call_once();
+        let file_path = "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string();
+        let hidrostatic_curves = HydrostaticCurves::from_json_file(file_path).unwrap();
+        // Кривая аппликаты поперечного метацентра не задана.
+        let value = hidrostatic_curves
+            .get_data_by_draft(2.0, HydrostaticTypeData::KM)
+            .unwrap();
+        assert!(value.is_none());
+        let hidrostatic_curves =
+            HydrostaticCurves::from_json_file("input_data/hydrostatic_curves.json".to_string())
+                .unwrap();
+        let value = hidrostatic_curves
+            .get_data_by_draft(2.5, HydrostaticTypeData::KM)
+            .unwrap();
+        assert_eq!(Some(34.03), value.map(|km| km.my_round(2)));
+    }
+
+    #[test]
+    fn from_csv_file_test() {
+        call_once();
+        let json =
+            HydrostaticCurves::from_json_file("input_data/hydrostatic_curves.json".to_string())
+                .unwrap();
+        let csv =
+            HydrostaticCurves::from_file("input_data/hydrostatic_curves.csv".to_string()).unwrap();
+        assert_eq!(
+            json.get_data_by_draft(6.5, HydrostaticTypeData::LCF),
+            csv.get_data_by_draft(6.5, HydrostaticTypeData::LCF)
+        );
+        assert_eq!(
+            json.get_data_by_draft(6.5, HydrostaticTypeData::WaterlineArea),
+            csv.get_data_by_draft(6.5, HydrostaticTypeData::WaterlineArea)
+        );
+        assert_eq!(
+            json.get_data_by_draft(6.5, HydrostaticTypeData::LMR),
+            csv.get_data_by_draft(6.5, HydrostaticTypeData::LMR)
+        );
+        assert_eq!(
+            Some(15.12),
+            csv.get_data_by_draft(7.0, HydrostaticTypeData::KM).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_yaml_file_test() {
+        call_once();
+        let json = HydrostaticCurves::from_file(
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let yaml = HydrostaticCurves::from_file(
+            "src/tests/unit/strength/test_data/hydrostatic_curves.yaml".to_string(),
+        )
+        .unwrap();
+        for type_data in [
+            HydrostaticTypeData::DisplacementTonnage,
+            HydrostaticTypeData::LCB,
+            HydrostaticTypeData::WaterlineArea,
+        ] {
+            assert_eq!(
+                json.get_data_by_draft(6.5, type_data),
+                yaml.get_data_by_draft(6.5, type_data)
+            );
+        }
+        assert!(HydrostaticCurves::from_file("input_data/input_data.toml".to_string()).is_err());
+    }
+
+    #[test]
+    fn from_csv_file_unknown_column_test() {
+        call_once();
+        let err = HydrostaticCurves::from_csv_file(
+            "src/tests/unit/strength/test_data/hydrostatic_curves_unknown_column.csv".to_string(),
+        )
+        .unwrap_err();
+        assert!(err.contains("Неизвестный столбец \"gm\""));
+    }
+
+    #[test]
+    fn get_data_by_draft_all_types_test() {
+        call_once();
+        let json =
+            HydrostaticCurves::from_json_file("input_data/hydrostatic_curves.json".to_string())
+                .unwrap();
+        let csv =
+            HydrostaticCurves::from_file("input_data/hydrostatic_curves.csv".to_string()).unwrap();
+        let expected = [
+            (HydrostaticTypeData::DisplacementTonnage, 11510.4),
+            (HydrostaticTypeData::LCB, -11.3),
+            (HydrostaticTypeData::LCF, -12.04),
+            (HydrostaticTypeData::WaterlineArea, 5658.69),
+            (HydrostaticTypeData::LMR, 1446.689),
+            (HydrostaticTypeData::KB, 1.01),
+            (HydrostaticTypeData::KM, 40.25),
+            (HydrostaticTypeData::MCT1cm, 708.6),
+            (HydrostaticTypeData::TPC, 58.0),
+            (HydrostaticTypeData::CB, 0.742),
+        ];
+        for (type_data, value) in expected {
+            assert_eq!(
+                Some(value),
+                json.get_data_by_draft(2.0, type_data)
+                    .unwrap()
+                    .map(|value| value.my_round(3)),
+                "{:?}",
+                type_data
+            );
+            assert_eq!(
+                json.get_data_by_draft(6.5, type_data).unwrap(),
+                csv.get_data_by_draft(6.5, type_data).unwrap(),
+                "{:?}",
+                type_data
+            );
+        }
+        // Необязательные кривые не заданы.
+        let curves = HydrostaticCurves::from_json_file(
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        for type_data in HydrostaticTypeData::all() {
+            assert_eq!(
+                type_data.is_required(),
+                curves.get_data_by_draft(2.0, type_data).unwrap().is_some()
+            );
+        }
+    }
+
+    #[test]
+    fn drafts_not_increasing_err_test() {
+        call_once();
+        let curves = HydrostaticCurves::new(
+            vec![1.0, 2.0, 2.0],
+            vec![100.0, 200.0, 300.0],
+            vec![0.0, 0.0, 0.0],
+            vec![50.0, 60.0, 70.0],
+            vec![0.0, 0.0, 0.0],
+            vec![10.0, 9.0, 8.0],
+        );
+        assert_eq!(
+            Err("Осадка `drafts` должна быть отсортирована по возрастанию и не содержать повторяющихся значений.".to_string()),
+            curves.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn displacement_tonnage_not_increasing_err_test() {
+        call_once();
+        let curves = HydrostaticCurves::new(
+            vec![1.0, 2.0, 3.0],
+            vec![100.0, 200.0, 200.0],
+            vec![0.0, 0.0, 0.0],
+            vec![50.0, 60.0, 70.0],
+            vec![0.0, 0.0, 0.0],
+            vec![10.0, 9.0, 8.0],
+        );
+        assert_eq!(
+            Err("Весовое водоизмещение `displacement_tonnage` должно строго возрастать с осадкой: при осадке 3 м водоизмещение 200 т не больше предыдущего значения 200 т.".to_string()),
+            curves.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn block_coefficient_err_test() {
+        call_once();
+        let curves = HydrostaticCurves::new(
+            vec![1.0, 2.0],
+            vec![100.0, 200.0],
+            vec![0.0, 0.0],
+            vec![50.0, 60.0],
+            vec![0.0, 0.0],
+            vec![10.0, 9.0],
+        )
+        .unwrap();
+        assert_eq!(
+            Err("Коэффициент общей полноты `cb` должен быть больше нуля и не больше единицы: при осадке 2 м задано значение 1.2.".to_string()),
+            curves.with_cb(vec![0.7, 1.2]).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn typed_lookup_test() {
+        call_once();
+        let curves =
+            HydrostaticCurves::from_json_file("input_data/hydrostatic_curves.json".to_string())
+                .unwrap();
+        assert_eq!(
+            Some(Tons(11510.4)),
+            curves.displacement_tonnage(2.0).unwrap()
+        );
+        assert_eq!(Some(Meters(-11.3)), curves.lcb(2.0).unwrap());
+        assert_eq!(Some(Meters(-12.04)), curves.lcf(2.0).unwrap());
+        assert_eq!(
+            Some(SquareMeters(5658.69)),
+            curves.waterline_area(2.0).unwrap()
+        );
+        assert_eq!(Some(Meters(1446.689)), curves.lmr(2.0).unwrap());
+        assert_eq!(Some(Meters(1.01)), curves.kb(2.0).unwrap());
+        assert_eq!(Some(Meters(40.25)), curves.km(2.0).unwrap());
+        assert_eq!(
+            Some(TonMetersPerCentimeter(708.6)),
+            curves.mct1cm(2.0).unwrap()
+        );
+        assert_eq!(Some(TonsPerCentimeter(58.0)), curves.tpc(2.0).unwrap());
+        assert_eq!(Some(0.742), curves.cb(2.0).unwrap());
+        assert_eq!(None, curves.km(20.1).unwrap());
+    }
 }
@@ -42,8 +42,8 @@ mod tests {
             "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string();
         let lw = Lightweight::new(13550.0);
         let ship_dimensions = ShipDimensions::new(235.0, 20, 0.74);
-        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions.clone(), lw);
-        let shiploads = Shiploads::from_json_file(shiploads_file).unwrap();
+        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions, lw);
+        let shiploads = Shiploads::from_file(shiploads_file).unwrap();
         let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
         let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i).unwrap();
         let dw = Deadweight::from_shiplods(&shiploads);
@@ -85,8 +85,8 @@ mod tests {
             "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string();
         let lw = Lightweight::new(13550.0);
         let ship_dimensions = ShipDimensions::new(235.0, 20, 0.74);
-        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions.clone(), lw);
-        let shiploads = Shiploads::from_json_file(shiploads_file).unwrap();
+        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions, lw);
+        let shiploads = Shiploads::from_file(shiploads_file).unwrap();
         let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
         let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i).unwrap();
         let dw = Deadweight::from_shiplods(&shiploads);
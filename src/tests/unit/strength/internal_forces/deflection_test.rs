@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::{
+            physical_constants::{EART_GRAVITY, STEEL_YOUNG_MODULUS},
+            round::Round,
+        },
+        cross_section_properties::cross_sections::CrossSections,
+        strength::{
+            internal_forces::deflection::Deflection,
+            load::shiploads::Shiploads,
+            ship::{
+                ship_model::Ship, spatium_function::SpatiumFunction,
+                spatium_functions::SpatiumFunctions,
+            },
+            strength::Strength,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    ///
+    /// Сечение постоянное по длине, I = 2 * 0.1 * 5^2 = 5 м^4.
+    fn cross_sections() -> CrossSections {
+        CrossSections::from_file(
+            "src/tests/unit/strength/test_data/cross_sections/two_plates.json".to_string(),
+        )
+        .unwrap()
+    }
+
+    ///
+    /// Постоянный изгибающий момент на длине 100 м, 10 шпаций.
+    fn constant_moment(moment: f64) -> SpatiumFunctions {
+        SpatiumFunctions::new(
+            (0..10)
+                .map(|id| {
+                    let x1 = -50.0 + 10.0 * id as f64;
+                    SpatiumFunction::new(id, x1, x1 + 10.0, moment, moment)
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn constant_moment_test() {
+        call_once();
+        // Балка на двух опорах под действием постоянного момента: w = M * L^2 / (8 * E * I).
+        let moment = 10000.0;
+        let expected = moment * EART_GRAVITY * 1000.0 * 100.0_f64.powi(2)
+            / (8.0 * STEEL_YOUNG_MODULUS * 5.0)
+            * 1000.0;
+        let hogging = Deflection::new(&constant_moment(moment), &cross_sections()).unwrap();
+        assert_eq!(
+            expected.my_round(6),
+            hogging.midship_deflection().unwrap().my_round(6)
+        );
+        assert_eq!(expected.my_round(6), hogging.max_hogging().my_round(6));
+        assert_eq!(0.0, hogging.max_sagging().my_round(9));
+        assert_eq!(0.0, hogging.deflection_at(-50.0).unwrap().my_round(9));
+        assert_eq!(0.0, hogging.deflection_at(50.0).unwrap().my_round(9));
+
+        let sagging = Deflection::new(&constant_moment(-moment), &cross_sections()).unwrap();
+        assert_eq!(
+            (-expected).my_round(6),
+            sagging.midship_deflection().unwrap().my_round(6)
+        );
+        assert_eq!(expected.my_round(6), sagging.max_sagging().my_round(6));
+        assert!(sagging.deflection_at(60.0).is_err());
+    }
+
+    #[test]
+    fn draft_marks_test() {
+        call_once();
+        let deflection = Deflection::new(&constant_moment(10000.0), &cross_sections()).unwrap();
+        let w = deflection.midship_deflection().unwrap() / 1000.0;
+        let marks = deflection.draft_marks(5.0, 7.0).unwrap();
+        assert_eq!((5.0, 7.0), (marks.aft, marks.nose));
+        assert_eq!((6.0 - w).my_round(3), marks.mid);
+        assert_eq!(
+            (6.0 - 0.75 * w).my_round(6),
+            deflection.quarter_mean_draft(5.0, 7.0).unwrap().my_round(6)
+        );
+    }
+
+    #[test]
+    fn full_ship_deflection_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "input_data/input_data.json".to_string(),
+            "input_data/frames.json".to_string(),
+            "input_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let cross_sections =
+            CrossSections::from_file("input_data/cross_sections.json".to_string()).unwrap();
+        let shiploads = Shiploads::from_file("input_data/full_ship.json".to_string()).unwrap();
+        let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+        let deflection = strength.deflection(&cross_sections).unwrap();
+        // Эпюра изгибающих моментов с поправкой - перегиб по всей длине.
+        assert!(strength
+            .bending_moment_with_correction()
+            .unwrap()
+            .as_ref()
+            .iter()
+            .all(|spatium| spatium.f_x1() >= 0.0 && spatium.f_x2() >= 0.0));
+        assert_eq!(0.0, deflection.max_sagging().my_round(9));
+        assert!(deflection.max_hogging() > 0.0);
+        assert!(
+            deflection.max_hogging() - deflection.midship_deflection().unwrap()
+                < 0.05 * deflection.max_hogging()
+        );
+    }
+}
@@ -1,2 +1,5 @@
 mod bending_moment_test;
+mod deflection_test;
 mod share_force_test;
+mod shear_stress_test;
+mod wave_bending_moment_test;
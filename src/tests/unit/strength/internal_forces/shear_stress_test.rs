@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::{round::Round, system_of_units::MegaPascals},
+        cross_section_properties::cross_sections::CrossSections,
+        strength::{
+            internal_forces::shear_stress::ShearStress,
+            load::shiploads::Shiploads,
+            ship::{
+                ship_model::Ship, spatium_function::SpatiumFunction,
+                spatium_functions::SpatiumFunctions,
+            },
+            strength::Strength,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    ///
+    /// Коробчатое сечение 10 x 10 м с толщиной стенок 10 мм, S / (I * t) = 5.625 1/м^2.
+    fn cross_sections() -> CrossSections {
+        CrossSections::from_file(
+            "src/tests/unit/strength/test_data/cross_sections/box.json".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn box_section_shear_stress_test() {
+        call_once();
+        let share_force = SpatiumFunctions::new(vec![
+            SpatiumFunction::new(0, -10.0, 0.0, 0.0, 100.0),
+            SpatiumFunction::new(1, 0.0, 10.0, 100.0, -200.0),
+        ]);
+        let shear_stress = ShearStress::new(&share_force, &cross_sections()).unwrap();
+        // tau = 100 т * 5.625 1/м^2 = 562.5 т/м^2 = 5.518125 МПа.
+        let spatiums = shear_stress.shear_stress().as_ref();
+        assert_eq!(0.0, spatiums[0].f_x1());
+        assert_eq!(5.518125, spatiums[0].f_x2().my_round(6));
+        assert_eq!(-11.03625, spatiums[1].f_x2().my_round(6));
+        assert_eq!(11.03625, shear_stress.max().value().my_round(6));
+    }
+
+    #[test]
+    fn full_ship_shear_stress_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "input_data/input_data.json".to_string(),
+            "input_data/frames.json".to_string(),
+            "input_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let cross_sections =
+            CrossSections::from_file("input_data/cross_sections.json".to_string()).unwrap();
+        let shiploads = Shiploads::from_file("input_data/full_ship.json".to_string()).unwrap();
+        let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+        let shear_stress = strength.shear_stress(&cross_sections).unwrap();
+        let share_force = strength.share_force_with_correction().unwrap().as_ref();
+        // Знак касательного напряжения совпадает со знаком перерезывающей силы.
+        for (stress, force) in shear_stress.shear_stress().as_ref().iter().zip(share_force) {
+            assert!(stress.f_x2() * force.f_x2() >= 0.0);
+        }
+        assert!(shear_stress.max() > MegaPascals(0.0));
+    }
+}
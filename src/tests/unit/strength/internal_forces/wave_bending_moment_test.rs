@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::round::Round,
+        strength::{
+            internal_forces::wave_bending_moment::WaveBendingMoment,
+            ship::ship_dimensions::ShipDimensions,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    #[test]
+    fn wave_coefficient_test() {
+        call_once();
+        assert_eq!(10.75, WaveBendingMoment::wave_coefficient(300.0).unwrap());
+        assert_eq!(10.75, WaveBendingMoment::wave_coefficient(350.0).unwrap());
+        assert_eq!(
+            10.226,
+            WaveBendingMoment::wave_coefficient(235.0)
+                .unwrap()
+                .my_round(3)
+        );
+        assert_eq!(
+            10.558,
+            WaveBendingMoment::wave_coefficient(400.0)
+                .unwrap()
+                .my_round(3)
+        );
+        assert_eq!(
+            Err("Волновой изгибающий момент определяется для судов длиной от 90 до 500 м, длина судна 80 м.".to_string()),
+            WaveBendingMoment::wave_coefficient(80.0)
+        );
+    }
+
+    #[test]
+    fn distribution_factor_test() {
+        call_once();
+        assert_eq!(0.0, WaveBendingMoment::distribution_factor(0.0));
+        assert_eq!(0.5, WaveBendingMoment::distribution_factor(0.2));
+        assert_eq!(1.0, WaveBendingMoment::distribution_factor(0.5));
+        assert_eq!(
+            0.5,
+            WaveBendingMoment::distribution_factor(0.825).my_round(6)
+        );
+        assert_eq!(0.0, WaveBendingMoment::distribution_factor(1.0));
+    }
+
+    #[test]
+    fn wave_bending_moment_test() {
+        call_once();
+        assert_eq!(
+            Err("Для расчета волнового изгибающего момента необходимо задать ширину судна (breadth).".to_string()),
+            WaveBendingMoment::new(ShipDimensions::new(235.0, 20, 0.74))
+        );
+        let wave = WaveBendingMoment::new(ShipDimensions::new(235.0, 20, 0.74).with_breadth(32.2))
+            .unwrap();
+        let base = WaveBendingMoment::wave_coefficient(235.0).unwrap() * 235.0_f64.powi(2) * 32.2
+            / 1000.0
+            / 9.81;
+        assert_eq!(
+            (190.0 * base * 0.74).my_round(2),
+            wave.hogging().value_at(0.0).unwrap().my_round(2)
+        );
+        assert_eq!(
+            (-110.0 * base * 1.44).my_round(2),
+            wave.sagging().value_at(0.0).unwrap().my_round(2)
+        );
+        assert_eq!(0.0, wave.hogging().value_at(-117.5).unwrap());
+        assert_eq!(0.0, wave.sagging().value_at(117.5).unwrap().my_round(9));
+        assert_eq!(20, wave.hogging().as_ref().len());
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::system_of_units::Tons,
+        strength::lightweight::inclining_experiment::IncliningExperiment,
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn experiment(file: &str) -> Result<IncliningExperiment, String> {
+        IncliningExperiment::from_file(format!(
+            "src/tests/unit/strength/test_data/inclining_experiment/{file}"
+        ))
+    }
+
+    #[test]
+    fn inclining_experiment_ok_test() {
+        call_once();
+        // tg(θ) = 0.1 / 5.0 = 0.02, GM = 10 * 10 / (1000 * 0.02) = 5.0 м,
+        // KG = 8.0 - 5.0 - 100 / 1000 = 2.9 м.
+        let experiment = experiment("experiment.json").unwrap();
+        assert!((experiment.metacentric_height() - 5.0).abs() < 1e-9);
+        assert!((experiment.vcg() - 2.9).abs() < 1e-9);
+        let lightweight = experiment.lightweight();
+        assert_eq!(Tons(900.0), lightweight.lightweight());
+        assert_eq!(Some(3.0), lightweight.vcg());
+        assert_eq!(None, lightweight.lcg());
+    }
+
+    #[test]
+    fn inclining_experiment_err_test() {
+        call_once();
+        assert!(experiment("displacement_err.json").is_err());
+        assert!(experiment("pendulum_length_err.json").is_err());
+        assert!(experiment("without_shifts.json").is_err());
+        assert!(experiment("zero_heel_err.json").is_err());
+        assert!(experiment("corrections_err.json").is_err());
+    }
+
+    #[test]
+    fn from_file_ok_test() {
+        call_once();
+        let experiment =
+            IncliningExperiment::from_file("input_data/inclining_experiment.json".to_string())
+                .unwrap();
+        assert!((experiment.metacentric_height() - 2.0).abs() < 0.01);
+        assert_eq!(Tons(13600.0), experiment.lightweight().lightweight());
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::strength::{
+        lightweight::{
+            lightweight::Lightweight, lightweight_distribution::LightweightDistribution,
+            lightweight_intensity::LightweightIntensity,
+        },
+        ship::ship_dimensions::ShipDimensions,
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    ///
+    /// Масса и абсцисса центра тяжести распределения массы судна порожнем.
+    fn weight_and_lcg(lw_i: &LightweightIntensity) -> (f64, f64) {
+        let mut weight = 0.0;
+        let mut moment = 0.0;
+        for spatium in lw_i.lightweight_intensity().as_ref() {
+            weight += spatium.integral();
+            moment += spatium.integral() * (spatium.x1() + spatium.x2()) / 2.0;
+        }
+        (weight, moment / weight)
+    }
+
+    #[test]
+    fn distribution_methods_ok_test() {
+        call_once();
+        let ship_dimensions = ShipDimensions::new(235.0, 20, 0.74);
+        let lw = Lightweight::new(13550.0).with_lcg(-6.0);
+        let mut coefficients = vec![1.0; 20];
+        coefficients[0] = 0.5;
+        coefficients[19] = 0.5;
+        for distribution in [
+            LightweightDistribution::Trapezoidal,
+            LightweightDistribution::ParabolicRectangular,
+            LightweightDistribution::Biles,
+            LightweightDistribution::CoefficientTable { coefficients },
+        ] {
+            let lw_i = LightweightIntensity::from_distribution(ship_dimensions, lw, &distribution)
+                .unwrap();
+            let (weight, lcg) = weight_and_lcg(&lw_i);
+            assert!((weight - 13550.0).abs() < 1.0, "{distribution:?}: {weight}");
+            assert!((lcg + 6.0).abs() < 0.01, "{distribution:?}: {lcg}");
+        }
+    }
+
+    #[test]
+    fn parabolic_rectangular_ok_test() {
+        call_once();
+        let ship_dimensions = ShipDimensions::new(100.0, 2, 0.74);
+        let lw_i = LightweightIntensity::from_distribution(
+            ship_dimensions,
+            Lightweight::new(1000.0),
+            &LightweightDistribution::ParabolicRectangular,
+        )
+        .unwrap();
+        let (weight, lcg) = weight_and_lcg(&lw_i);
+        assert_eq!(1000.0, weight);
+        assert_eq!(0.0, lcg);
+    }
+
+    #[test]
+    fn trapezoidal_without_lcg_matches_legacy_test() {
+        call_once();
+        let ship_dimensions = ShipDimensions::new(235.0, 20, 0.74);
+        let lw = Lightweight::new(13550.0);
+        assert_eq!(
+            LightweightIntensity::from_ship_input_data(ship_dimensions, lw).lightweight_intensity(),
+            LightweightIntensity::from_distribution(
+                ship_dimensions,
+                lw,
+                &LightweightDistribution::Trapezoidal
+            )
+            .unwrap()
+            .lightweight_intensity()
+        );
+    }
+
+    #[test]
+    fn items_ok_test() {
+        call_once();
+        let ship_dimensions = ShipDimensions::new(200.0, 20, 0.74);
+        let distribution = LightweightDistribution::from_file(
+            "src/tests/unit/strength/test_data/lightweight/items.json".to_string(),
+        )
+        .unwrap();
+        let lw_i = LightweightIntensity::from_distribution(
+            ship_dimensions,
+            Lightweight::new(10000.0),
+            &distribution,
+        )
+        .unwrap();
+        let (weight, lcg) = weight_and_lcg(&lw_i);
+        assert!((weight - 10000.0).abs() < 1.0);
+        assert!((lcg + 15.5).abs() < 0.01, "{lcg}");
+    }
+
+    #[test]
+    fn distribution_err_test() {
+        call_once();
+        let ship_dimensions = ShipDimensions::new(200.0, 20, 0.74);
+        let lw = Lightweight::new(10000.0);
+        let table = LightweightDistribution::CoefficientTable {
+            coefficients: vec![1.0; 10],
+        };
+        assert!(LightweightIntensity::from_distribution(ship_dimensions, lw, &table).is_err());
+        let outside = LightweightDistribution::from_file(
+            "src/tests/unit/strength/test_data/lightweight/items_outside.json".to_string(),
+        )
+        .unwrap();
+        assert!(LightweightIntensity::from_distribution(ship_dimensions, lw, &outside).is_err());
+        // Центр тяжести в корме недостижим без отрицательной интенсивности.
+        assert!(LightweightIntensity::from_distribution(
+            ship_dimensions,
+            lw.with_lcg(-90.0),
+            &LightweightDistribution::Biles
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn from_file_default_test() {
+        call_once();
+        assert_eq!(
+            LightweightDistribution::Trapezoidal,
+            LightweightDistribution::from_file(
+                "src/tests/unit/strength/test_data/input_data.json".to_string()
+            )
+            .unwrap()
+        );
+    }
+}
@@ -1 +1,3 @@
+mod inclining_experiment_test;
+mod lightweight_distribution_test;
 mod lightweight_intensity_test;
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use serde_json::{json, Value};
+
+    use crate::{
+        core::point::Point,
+        strength::load::{
+            load_category::{CategorySummary, LoadCategory},
+            shipload::Shipload,
+            shiploads::Shiploads,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn shiploads() -> Shiploads {
+        Shiploads::new(vec![
+            Shipload::new(1000.0, Point::new(10.0, 0.0, 8.0), 20.0)
+                .with_name("Трюм 1", Some("Уголь"))
+                .with_category(LoadCategory::Cargo),
+            Shipload::new(3000.0, Point::new(-30.0, 0.0, 6.0), 20.0)
+                .with_name("Трюм 2", None)
+                .with_category(LoadCategory::Cargo),
+            Shipload::new(200.0, Point::new(-90.0, 0.0, 4.0), 10.0)
+                .with_category(LoadCategory::FuelOil),
+            Shipload::new(50.0, Point::new(0.0, 0.0, 10.0), 10.0),
+        ])
+    }
+
+    #[test]
+    fn category_summary_test() {
+        call_once();
+        let summary = shiploads().category_summary();
+        let json = serde_json::to_value(&summary).unwrap();
+        let categories: Vec<&Value> = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| &item["category"])
+            .collect();
+        assert_eq!(
+            vec![&json!("cargo"), &json!("fuel_oil"), &Value::Null],
+            categories
+        );
+        assert_eq!(4000.0, json[0]["weight"]);
+        assert_eq!(-20.0, json[0]["lcg"]);
+        assert_eq!(6.5, json[0]["vcg"]);
+        assert_eq!(-90.0, json[1]["lcg"]);
+        assert_eq!(50.0, json[2]["weight"]);
+        assert_eq!(summary, CategorySummary::from_shiploads(&shiploads()));
+    }
+
+    #[test]
+    fn with_categories_test() {
+        call_once();
+        let shiploads = shiploads();
+        assert_eq!(
+            4000.0,
+            shiploads.with_categories(&[LoadCategory::Cargo]).sum()
+        );
+        assert_eq!(
+            4200.0,
+            shiploads
+                .with_categories(&[LoadCategory::Cargo, LoadCategory::FuelOil])
+                .sum()
+        );
+        assert_eq!(0.0, shiploads.with_categories(&[]).sum());
+    }
+
+    #[test]
+    fn named_shipload_from_json_test() {
+        call_once();
+        let shipload: Shipload = serde_json::from_str(
+            r#"{"name": "ПВ 1", "description": "Пресная вода", "category": "fresh_water",
+                "value": 300.0, "center_gravity": {"x": -106.0, "y": 0.0, "z": 10.0}, "length": 8.0}"#,
+        )
+        .unwrap();
+        assert_eq!(Some("ПВ 1"), shipload.name());
+        assert_eq!(
+            "Пресная вода",
+            serde_json::to_value(&shipload).unwrap()["description"]
+        );
+        assert_eq!(Some(LoadCategory::FreshWater), shipload.category());
+    }
+
+    #[test]
+    fn category_from_str_test() {
+        call_once();
+        assert_eq!(Ok(LoadCategory::FuelOil), "fuel_oil".parse());
+        assert!("fuel".parse::<LoadCategory>().is_err());
+    }
+
+    #[test]
+    fn departure_condition_from_file_test() {
+        call_once();
+        let shiploads =
+            Shiploads::from_file("input_data/departure_condition.json".to_string()).unwrap();
+        assert_eq!(7, shiploads.category_summary().len());
+    }
+}
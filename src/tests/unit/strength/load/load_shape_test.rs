@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::strength::{
+        deadweight::deadweight_intensity::DeadweightIntensity,
+        load::{load_shape::LoadShape, shipload::Shipload, shiploads::Shiploads},
+        ship::ship_dimensions::ShipDimensions,
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    ///
+    /// Масса и статический момент относительно мидель шпангоута интенсивности дедвейта.
+    fn force_and_moment(shipload: Shipload, ship_dimensions: ShipDimensions) -> (f64, f64) {
+        let shiploads = Shiploads::new(vec![shipload]);
+        let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
+        let mut force = 0.0;
+        let mut moment = 0.0;
+        for spatium in dw_i.deadweight_intensity().as_ref() {
+            let h = spatium.x2() - spatium.x1();
+            force += spatium.integral();
+            moment += h
+                * (spatium.x1() * (spatium.f_x1() + spatium.f_x2()) / 2.0
+                    + h * (spatium.f_x1() + 2.0 * spatium.f_x2()) / 6.0);
+        }
+        (force, moment)
+    }
+
+    #[test]
+    fn center_gravity_test() {
+        call_once();
+        assert!((LoadShape::Uniform.center_gravity(0.0, 10.0) - 5.0).abs() < 1e-9);
+        assert_eq!(5.0, LoadShape::Point.center_gravity(0.0, 10.0));
+        let trapezoid = LoadShape::Trapezoid {
+            aft: 0.0,
+            nose: 1.0,
+        };
+        assert!((trapezoid.center_gravity(0.0, 9.0) - 6.0).abs() < 1e-9);
+        let curve = LoadShape::Curve {
+            points: vec![(0.0, 0.0), (0.5, 1.0), (1.0, 0.0)],
+        };
+        assert!((curve.center_gravity(-10.0, 10.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn shapes_preserve_force_and_moment_test() {
+        call_once();
+        let ship_dimensions = ShipDimensions::new(235.0, 20, 0.74);
+        // Для каждого закона распределения - нагрузки в пределах одной шпации,
+        // на нескольких шпациях и за кормовым перпендикуляром.
+        let shiploads = Shiploads::from_file(
+            "src/tests/unit/strength/test_data/load_shape/shapes.json".to_string(),
+        )
+        .unwrap();
+        for shipload in shiploads {
+            let (shape, x) = (
+                shipload.shape().cloned(),
+                shipload.longitudinal_center_gravity(),
+            );
+            let expected_moment = shipload.moment().value();
+            let (force, moment) = force_and_moment(shipload, ship_dimensions);
+            assert!((force - 500.0).abs() < 0.5, "{shape:?}, x = {x}: {force}");
+            assert!(
+                (moment - expected_moment).abs() < 0.001 * 500.0 * 117.5,
+                "{shape:?}, x = {x}: {moment} != {expected_moment}"
+            );
+        }
+    }
+
+    #[test]
+    fn shape_from_json_test() {
+        call_once();
+        let shipload: Shipload = serde_json::from_str(
+            r#"{"value": 100.0, "center_gravity": {"x": 0.0, "y": 0.0, "z": 0.0}, "length": 12.0,
+                "shape": {"type": "trapezoid", "aft": 1.0, "nose": 2.0}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            Some(&LoadShape::Trapezoid {
+                aft: 1.0,
+                nose: 2.0
+            }),
+            shipload.shape()
+        );
+        assert!((shipload.moment().value() - 100.0 * 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn shape_validate_err_test() {
+        call_once();
+        assert!(LoadShape::Trapezoid {
+            aft: -1.0,
+            nose: 2.0
+        }
+        .validate()
+        .is_err());
+        assert!(LoadShape::Curve {
+            points: vec![(0.0, 1.0)]
+        }
+        .validate()
+        .is_err());
+        assert!(LoadShape::Curve {
+            points: vec![(0.0, 1.0), (0.5, 1.0)]
+        }
+        .validate()
+        .is_err());
+        assert!(LoadShape::Curve {
+            points: vec![(0.0, 0.0), (1.0, 0.0)]
+        }
+        .validate()
+        .is_err());
+        assert!(Shiploads::from_file(
+            "src/tests/unit/strength/test_data/load_shape/zero_length_err.json".to_string()
+        )
+        .is_err());
+    }
+}
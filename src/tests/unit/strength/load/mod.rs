@@ -1,3 +1,5 @@
+mod load_category_test;
+mod load_shape_test;
 mod shipload_test;
 mod shiploads_test;
 mod total_shipload_test;
@@ -4,7 +4,10 @@ mod tests {
 
     use crate::{
         core::point::Point,
-        strength::{load::shipload::Shipload, ship::ship_dimensions::ShipDimensions},
+        strength::{
+            load::{load_category::LoadCategory, load_shape::LoadShape, shipload::Shipload},
+            ship::ship_dimensions::ShipDimensions,
+        },
     };
 
     static INIT: Once = Once::new();
@@ -158,4 +161,50 @@ mod tests {
         let shared_loads = shipload.shared_shiploads(&ship_dimensions);
         assert_eq!(test_shared_loads, shared_loads);
     }
+
+    #[test]
+    fn shared_shiploads_keep_name_and_category_test() {
+        call_once();
+        let shipload = Shipload::new(10.0, Point::new(5.0, 0.0, 0.0), 10.0)
+            .with_name("Трюм 1", Some("Генеральный груз"))
+            .with_category(LoadCategory::Cargo);
+        let ship_dimensions = ShipDimensions::new(125.0, 20, 0.6);
+        let shared_loads = shipload.shared_shiploads(&ship_dimensions);
+        assert_eq!(2, shared_loads.len());
+        for shared_load in shared_loads {
+            assert_eq!(Some("Трюм 1"), shared_load.name());
+            assert_eq!(Some(LoadCategory::Cargo), shared_load.category());
+        }
+    }
+
+    #[test]
+    fn shared_shiploads_keep_shape_test() {
+        call_once();
+        let shipload: Shipload = serde_json::from_str(
+            r#"{"value": 100.0, "center_gravity": {"x": 5.0, "y": 0.0, "z": 0.0}, "length": 10.0,
+                "shape": {"type": "trapezoid", "aft": 0.0, "nose": 1.0}}"#,
+        )
+        .unwrap();
+        let ship_dimensions = ShipDimensions::new(125.0, 20, 0.6);
+        let shared_loads = shipload.shared_shiploads(&ship_dimensions);
+        assert_eq!(2, shared_loads.len());
+        let value: f64 = shared_loads.iter().map(|load| load.value().value()).sum();
+        assert!((value - 100.0).abs() < 0.05);
+        let moment: f64 = shared_loads.iter().map(|load| load.moment().value()).sum();
+        assert!((moment - shipload.moment().value()).abs() < 1.0);
+        assert!(shared_loads[0].value() < shared_loads[1].value());
+        assert!(matches!(
+            shared_loads[0].shape(),
+            Some(LoadShape::Trapezoid { .. })
+        ));
+        let point: Shipload = serde_json::from_str(
+            r#"{"value": 100.0, "center_gravity": {"x": 5.0, "y": 0.0, "z": 0.0}, "length": 10.0,
+                "shape": {"type": "point"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![point.clone()],
+            point.shared_shiploads(&ship_dimensions)
+        );
+    }
 }
@@ -184,4 +184,36 @@ mod tests {
                 .collect::<Vec<Shipload>>()
         );
     }
+
+    #[test]
+    fn from_file_migration_test() {
+        call_once();
+        let shiploads =
+            Shiploads::from_file("src/tests/unit/strength/test_data/full_ship.json".to_string())
+                .unwrap();
+        assert_eq!(Some("Нагрузка 0"), shiploads.as_ref()[0].name());
+        assert_eq!(Some("Нагрузка 19"), shiploads.as_ref()[19].name());
+        let current = Shiploads::from_file("input_data/full_ship.json".to_string()).unwrap();
+        assert_eq!(current.as_ref().len(), shiploads.as_ref().len());
+    }
+
+    #[test]
+    fn from_file_newer_version_test() {
+        call_once();
+        let err = Shiploads::from_file(
+            "src/tests/unit/strength/test_data/shiploads_newer_version.json".to_string(),
+        )
+        .unwrap_err();
+        assert!(err.contains("Версия формата файла схемы загрузки 3 не поддерживается"));
+    }
+
+    #[test]
+    fn from_file_without_name_test() {
+        call_once();
+        let err = Shiploads::from_file(
+            "src/tests/unit/strength/test_data/shiploads_without_name.json".to_string(),
+        )
+        .unwrap_err();
+        assert!(err.contains("Нагрузка с индексом 1: не задано название `name`."));
+    }
 }
@@ -43,8 +43,8 @@ mod tests {
             "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string();
         let lw = Lightweight::new(13550.0);
         let ship_dimensions = ShipDimensions::new(235.0, 200, 0.74);
-        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions.clone(), lw);
-        let shiploads = Shiploads::from_json_file(shiploads_file).unwrap();
+        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions, lw);
+        let shiploads = Shiploads::from_file(shiploads_file).unwrap();
         let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
         let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i).unwrap();
         let dw = Deadweight::from_shiplods(&shiploads);
@@ -67,9 +67,9 @@ mod tests {
                 .unwrap();
         let total_shipload = TotalShipload::from_disp_i_and_b_i(&disp_i, &b_i).unwrap();
 
-        let tested_integral_total_shipload = total_shipload.total_shipload().integral();
+        let tested_integral_total_shipload = total_shipload.total_shipload().integral().value();
         let integral_total_shipload =
-            (b_i.buoyancy_intensity().integral().abs() - d_t.displacement_tonnage()).abs();
+            (b_i.buoyancy_intensity().integral().value().abs() - d_t.displacement_tonnage()).abs();
         let error = ((integral_total_shipload - tested_integral_total_shipload.abs()).abs()
             / integral_total_shipload.min(tested_integral_total_shipload.abs()))
             * 100.0;
@@ -89,8 +89,8 @@ mod tests {
             "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string();
         let lw = Lightweight::new(13550.0);
         let ship_dimensions = ShipDimensions::new(235.0, 200, 0.74);
-        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions.clone(), lw);
-        let shiploads = Shiploads::from_json_file(shiploads_file).unwrap();
+        let lw_i = LightweightIntensity::from_ship_input_data(ship_dimensions, lw);
+        let shiploads = Shiploads::from_file(shiploads_file).unwrap();
         let dw_i = DeadweightIntensity::builder(&shiploads, ship_dimensions).build();
         let disp_i = DisplacementIntensity::from_dw_i_and_lw_i(&dw_i, &lw_i).unwrap();
         let dw = Deadweight::from_shiplods(&shiploads);
@@ -113,9 +113,9 @@ mod tests {
                 .unwrap();
         let total_shipload = TotalShipload::from_disp_i_and_b_i(&disp_i, &b_i).unwrap();
 
-        let tested_integral_total_shipload = total_shipload.total_shipload().integral();
+        let tested_integral_total_shipload = total_shipload.total_shipload().integral().value();
         let integral_total_shipload =
-            (b_i.buoyancy_intensity().integral().abs() - d_t.displacement_tonnage()).abs();
+            (b_i.buoyancy_intensity().integral().value().abs() - d_t.displacement_tonnage()).abs();
         let error = ((integral_total_shipload - tested_integral_total_shipload.abs()).abs()
             / integral_total_shipload.min(tested_integral_total_shipload.abs()))
             * 100.0;
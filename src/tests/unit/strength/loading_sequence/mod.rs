@@ -0,0 +1 @@
+mod sequence_test;
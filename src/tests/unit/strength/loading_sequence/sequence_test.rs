@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::point::Point,
+        strength::{
+            load::{shipload::Shipload, shiploads::Shiploads},
+            loading_sequence::sequence::LoadingSequence,
+            ship::ship_model::Ship,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn sequence(file: &str) -> LoadingSequence {
+        LoadingSequence::from_file(format!(
+            "src/tests/unit/strength/test_data/loading_sequence/{file}"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn conditions_ok_test() {
+        call_once();
+        let initial = Shiploads::new(vec![Shipload::new(
+            100.0,
+            Point::new(-80.0, 0.0, 0.0),
+            10.0,
+        )]);
+        let conditions = sequence("sequence.json").conditions(&initial).unwrap();
+        let sums: Vec<f64> = conditions.iter().map(|shiploads| shiploads.sum()).collect();
+        assert_eq!(vec![100.0, 8100.0, 14100.0, 14000.0], sums);
+    }
+
+    #[test]
+    fn conditions_err_test() {
+        call_once();
+        let sequence = sequence("remove_err.json");
+        assert!(sequence.conditions(&Shiploads::new(vec![])).is_err());
+    }
+
+    #[test]
+    fn from_file_ok_test() {
+        call_once();
+        let sequence =
+            LoadingSequence::from_file("input_data/loading_sequence.json".to_string()).unwrap();
+        let steps = &serde_json::to_value(&sequence).unwrap()["steps"];
+        assert_eq!(4, steps.as_array().unwrap().len());
+        assert_eq!("Порция 1 в трюм 3", steps[0]["name"]);
+    }
+
+    #[test]
+    fn from_yaml_file_test() {
+        call_once();
+        assert_eq!(
+            serde_json::to_value(sequence("one_step.json")).unwrap(),
+            serde_json::to_value(sequence("one_step.yaml")).unwrap()
+        );
+    }
+
+    #[test]
+    fn run_ok_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let initial =
+            Shiploads::from_file("src/tests/unit/strength/test_data/empty_ship.json".to_string())
+                .unwrap();
+        let report = sequence("one_step.json").run(&ship, &initial).unwrap();
+        assert_eq!(2, report.steps().len());
+        assert_eq!(Some((2.34, 4.07)), report.steps()[0].drafts());
+        let critical_step = report.critical_step().unwrap();
+        assert!(report
+            .steps()
+            .iter()
+            .all(|step| step.max_utilisation() <= critical_step.max_utilisation()));
+        assert!(report.passed());
+    }
+}
@@ -1,3 +1,4 @@
+mod ballast;
 mod bonjean_scale;
 mod buoyancy_load;
 mod deadweight;
@@ -6,4 +7,12 @@ mod hydrostatic_curves;
 mod internal_forces;
 mod lightweight;
 mod load;
+mod loading_sequence;
+mod project;
+mod report;
 mod ship;
+mod stability;
+mod stowage;
+mod strength_check;
+mod validation;
+mod voyage;
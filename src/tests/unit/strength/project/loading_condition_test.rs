@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::strength::{
+        load::shiploads::Shiploads, project::loading_condition::LoadingCondition,
+        ship::ship_model::Ship, strength::Strength,
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    #[test]
+    fn from_file_test() {
+        call_once();
+        let condition = LoadingCondition::from_file(
+            "src/tests/unit/strength/test_data/project/condition.json".to_string(),
+        )
+        .unwrap();
+        assert_eq!("Тестовое судно", condition.project().ship_name());
+        assert_eq!(1, condition.shiploads().as_ref().len());
+        assert_eq!(Some(1.0), condition.shiploads().water_density());
+    }
+
+    #[test]
+    fn unknown_field_test() {
+        call_once();
+        let err = LoadingCondition::from_file(
+            "src/tests/unit/strength/test_data/project/condition_unknown_field.json".to_string(),
+        )
+        .unwrap_err();
+        assert!(err.contains("unknown field `loads`"));
+    }
+
+    #[test]
+    fn strength_test() {
+        call_once();
+        let condition =
+            LoadingCondition::from_file("input_data/project_condition.json".to_string()).unwrap();
+        let strength =
+            Strength::from_ship(condition.project().ship(), condition.shiploads()).unwrap();
+        let ship = Ship::from_files(
+            "input_data/input_data.json".to_string(),
+            "input_data/frames.json".to_string(),
+            "input_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let shiploads =
+            Shiploads::from_file("input_data/departure_condition.json".to_string()).unwrap();
+        let expected = Strength::from_ship(&ship, &shiploads).unwrap();
+        assert_eq!(
+            expected.displacemnt_tonnage(),
+            strength.displacemnt_tonnage()
+        );
+        assert_eq!(expected.max_bending_moment(), strength.max_bending_moment());
+    }
+}
@@ -0,0 +1,2 @@
+mod loading_condition_test;
+mod ship_project_test;
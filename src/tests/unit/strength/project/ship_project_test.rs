@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::{
+            system_of_units::{Meters, Tons},
+            unit_system::UnitSystem,
+        },
+        strength::{
+            lightweight::lightweight_distribution::LightweightDistribution,
+            project::ship_project::ShipProject,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn project_file(name: &str) -> String {
+        format!("src/tests/unit/strength/test_data/project/{name}")
+    }
+
+    #[test]
+    fn from_file_test() {
+        call_once();
+        let project = ShipProject::from_file(project_file("ship_project.json")).unwrap();
+        assert_eq!("Тестовое судно", project.ship_name());
+        assert_eq!(UnitSystem::TonnesForce, project.unit_system());
+        let ship = project.ship();
+        assert_eq!(Meters(20.0), ship.ship_dimensions().lbp());
+        assert_eq!(Meters(10.0), ship.ship_dimensions().length_spatium());
+        assert_eq!(None, ship.ship_dimensions().breadth());
+        assert_eq!(Tons(100.0), ship.lightweight().lightweight());
+        assert_eq!(Some(-0.5), ship.lightweight().lcg());
+        assert_eq!(
+            &LightweightDistribution::Trapezoidal,
+            ship.lightweight_distribution()
+        );
+        assert_eq!(1.025, ship.water_density().water_density());
+        assert_eq!(3, ship.frames().as_ref().len());
+        assert!(ship.compartments().as_ref().is_empty());
+    }
+
+    #[test]
+    fn input_data_project_test() {
+        call_once();
+        let project = ShipProject::from_file("input_data/ship_project.json".to_string()).unwrap();
+        assert_eq!("Сухогруз ТКЗ", project.ship_name());
+        assert_eq!(Meters(235.0), project.ship().ship_dimensions().lbp());
+        assert_eq!(
+            Some(Meters(32.2)),
+            project.ship().ship_dimensions().breadth()
+        );
+        assert_eq!(21, project.ship().frames().as_ref().len());
+    }
+
+    #[test]
+    fn unsupported_version_test() {
+        call_once();
+        let err = ShipProject::from_file(project_file("unsupported_version.json")).unwrap_err();
+        assert!(err.contains("Версия формата файла проекта судна 2 не поддерживается"));
+    }
+
+    #[test]
+    fn unknown_field_test() {
+        call_once();
+        let err = ShipProject::from_file(project_file("unknown_field.json")).unwrap_err();
+        assert!(err.contains("unknown_field.json"));
+        assert!(err.contains("unknown field `draft`"));
+    }
+
+    #[test]
+    fn missing_field_test() {
+        call_once();
+        let err = ShipProject::from_file(project_file("missing_field.json")).unwrap_err();
+        assert!(err.contains("missing field `lbp`"));
+    }
+
+    #[test]
+    fn missing_file_test() {
+        call_once();
+        assert!(ShipProject::from_file(project_file("not_exists.json")).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::{point::Point, unit_system::UnitSystem, water_density::WaterDensity},
+        strength::{
+            bonjean_scale::frames::Frames,
+            hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
+            lightweight::{
+                lightweight::Lightweight, lightweight_distribution::LightweightDistribution,
+            },
+            load::{shipload::Shipload, shiploads::Shiploads},
+            report::{loading_condition_table::LoadingConditionTable, report_format::ReportFormat},
+            ship::{ship_dimensions::ShipDimensions, ship_model::Ship},
+            strength_check::permissible_limits::PermissibleLimits,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn ship() -> Ship {
+        Ship::from_files(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn shiploads() -> Shiploads {
+        Shiploads::new(vec![
+            Shipload::new(4000.0, Point::new(20.0, 0.0, 8.0), 20.0).with_name("Трюм 1", None),
+            Shipload::new(1000.0, Point::new(-40.0, 0.0, 2.0), 10.0),
+        ])
+    }
+
+    #[test]
+    fn totals_test() {
+        call_once();
+        let table = LoadingConditionTable::new(&ship(), &shiploads()).unwrap();
+        let json = serde_json::to_value(&table).unwrap();
+        assert_eq!(json["items"].as_array().unwrap().len(), 2);
+        assert_eq!(json["items"][0]["lcg"], 20.0);
+        assert_eq!(json["items"][1]["vcg"], 2.0);
+        let deadweight = &json["deadweight"];
+        assert_eq!(deadweight["weight"], 5000.0);
+        assert_eq!(deadweight["lcg"], 8.0);
+        assert_eq!(deadweight["vcg"], 6.8);
+        // Аппликата центра тяжести судна порожнем не задана, поэтому не задается и для водоизмещения.
+        assert_eq!(json["lightweight"]["weight"], 13550.0);
+        assert!(json["lightweight"]["vcg"].is_null());
+        let displacement = &json["displacement"];
+        assert_eq!(displacement["weight"], 18550.0);
+        assert!(displacement["vcg"].is_null());
+        let draft = |name: &str| json[name].as_f64().unwrap();
+        assert!(
+            (draft("mean_draft") - (draft("aft_draft") + draft("nose_draft")) / 2.0).abs() < 0.01
+        );
+        assert_eq!(json["water_density"], 1.025);
+        let (fwa, dwa) = table.water_allowance().unwrap();
+        assert!(fwa > 0.0);
+        assert_eq!(dwa, 0.0);
+    }
+
+    #[test]
+    fn without_water_allowance_test() {
+        call_once();
+        // Для судна, гидростатические кривые которого построены для пресной воды,
+        // поправки к осадке не определяются и не выводятся в таблице.
+        let input_path = "src/tests/unit/strength/test_data/input_data.json".to_string();
+        let ship = Ship::new(
+            Lightweight::from_file(input_path.clone()).unwrap(),
+            LightweightDistribution::Trapezoidal,
+            ShipDimensions::from_file(input_path.clone()).unwrap(),
+            WaterDensity::new(1.0),
+            Frames::from_json_file("src/tests/unit/strength/test_data/frames.json".to_string())
+                .unwrap(),
+            HydrostaticCurves::from_json_file(
+                "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+            )
+            .unwrap(),
+        )
+        .with_permissible_limits(PermissibleLimits::from_file(input_path).unwrap().unwrap());
+        let table = LoadingConditionTable::new(&ship, &shiploads()).unwrap();
+        assert_eq!(table.water_allowance(), None);
+        let markdown = table.render(ReportFormat::Markdown);
+        assert!(markdown.contains("Плотность забортной воды"));
+        assert!(!markdown.contains("FWA"));
+        assert!(!markdown.contains("DWA"));
+    }
+
+    #[test]
+    fn render_test() {
+        call_once();
+        let table = LoadingConditionTable::new(&ship(), &shiploads()).unwrap();
+        let markdown = table.render(ReportFormat::Markdown);
+        assert!(markdown.contains("### Статьи дедвейта"));
+        assert!(markdown.contains("| Трюм 1 | 4000.00 | 20.000 | 80000.00 | 8.00 | 32000.00 |"));
+        assert!(markdown.contains("| Нагрузка 1 | 1000.00 |"));
+        assert!(markdown.contains("| Дедвейт | 5000.00 | 8.000 | 40000.00 | 6.80 | 34000.00 |"));
+        let html = table.render(ReportFormat::Html);
+        assert!(html.contains("<h3>Посадка</h3>"));
+        assert!(html.contains("<td>Водоизмещение</td><td>18550.00</td>"));
+        let csv = table.render(ReportFormat::Csv);
+        assert!(csv.contains("Трюм 1,4000.00,20.000,80000.00,8.00,32000.00"));
+        assert!(csv.contains("Судно порожнем,13550.00,"));
+    }
+
+    #[test]
+    fn render_unit_system_test() {
+        call_once();
+        let table = LoadingConditionTable::new(&ship(), &shiploads()).unwrap();
+        assert!(table.render(ReportFormat::Csv).contains(" т*м,"));
+        let table = table.with_unit_system(UnitSystem::KiloNewtons);
+        let csv = table.render(ReportFormat::Csv);
+        assert!(csv.contains(" кН,"));
+        assert!(csv.contains(" кН*м,"));
+    }
+
+    #[test]
+    fn report_format_test() {
+        call_once();
+        assert_eq!("md".parse::<ReportFormat>(), Ok(ReportFormat::Markdown));
+        assert_eq!("HTML".parse::<ReportFormat>(), Ok(ReportFormat::Html));
+        assert!("pdf".parse::<ReportFormat>().is_err());
+        let csv =
+            ReportFormat::Csv.table(&["a", "b"], &[vec!["1,5".to_string(), "\"x\"".to_string()]]);
+        assert_eq!(csv, "a,b\n\"1,5\",\"\"\"x\"\"\"\n");
+    }
+}
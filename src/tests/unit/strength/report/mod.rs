@@ -0,0 +1 @@
+mod loading_condition_table_test;
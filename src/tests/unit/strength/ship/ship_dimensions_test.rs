@@ -1,11 +1,11 @@
 #[cfg(test)]
 mod tests {
-    use crate::strength::ship::ship_dimensions::ShipDimensions;
+    use crate::{core::system_of_units::Meters, strength::ship::ship_dimensions::ShipDimensions};
 
     #[test]
     fn length_spatium_test() {
         let ship_dimensions = ShipDimensions::new(125.0, 20, 0.6);
-        let test_length_spatium = 6.25;
+        let test_length_spatium = Meters(6.25);
         assert_eq!(test_length_spatium, ship_dimensions.length_spatium());
     }
 
@@ -44,7 +44,7 @@ mod tests {
     #[test]
     fn spatium_index_by_coordinate_test() {
         let ship_dimensions = ShipDimensions::new(125.0, 20, 0.6);
-        let length_spatium = ship_dimensions.length_spatium();
+        let length_spatium = ship_dimensions.length_spatium().value();
         let mut current_coordinate = -62.0;
         for index in 0..ship_dimensions.number_spatiums() {
             assert_eq!(
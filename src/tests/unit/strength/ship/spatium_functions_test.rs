@@ -121,4 +121,16 @@ mod tests {
         let s_fs = SpatiumFunctions::new(vec![]);
         assert_eq!(None, s_fs.max());
     }
+
+    #[test]
+    fn value_at_test() {
+        let s_fs = SpatiumFunctions::new(vec![
+            SpatiumFunction::new(0, -10.0, 0.0, 0.0, 4.0),
+            SpatiumFunction::new(1, 0.0, 10.0, 4.0, -6.0),
+        ]);
+        assert_eq!(Some(2.0), s_fs.value_at(-5.0));
+        assert_eq!(Some(4.0), s_fs.value_at(0.0));
+        assert_eq!(Some(-1.0), s_fs.value_at(5.0));
+        assert_eq!(None, s_fs.value_at(10.5));
+    }
 }
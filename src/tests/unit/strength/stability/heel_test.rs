@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::{point::Point, water_density::WaterDensity},
+        cross_section_properties::cross_sections::CrossSections,
+        strength::{
+            bonjean_scale::frames::Frames,
+            hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
+            lightweight::{
+                lightweight::Lightweight, lightweight_distribution::LightweightDistribution,
+            },
+            load::{shipload::Shipload, shiploads::Shiploads},
+            ship::{ship_dimensions::ShipDimensions, ship_model::Ship},
+            stability::heel::Heel,
+            strength::Strength,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn ship() -> Ship {
+        Ship::from_files(
+            "input_data/input_data.json".to_string(),
+            "input_data/frames.json".to_string(),
+            "input_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn shiploads(y: f64) -> Shiploads {
+        Shiploads::new(vec![
+            Shipload::new(4000.0, Point::new(10.0, 0.0, 8.0), 30.0),
+            Shipload::new(1000.0, Point::new(-40.0, y, 4.0), 10.0),
+        ])
+    }
+
+    #[test]
+    fn upright_test() {
+        call_once();
+        let heel = Heel::new(&ship(), &shiploads(0.0)).unwrap();
+        assert_eq!(heel.tcg(), 0.0);
+        assert_eq!(heel.heeling_moment(), 0.0);
+        assert_eq!(heel.angle().unwrap(), 0.0);
+        assert!(heel.metacentric_height() > 0.0);
+        assert_eq!(
+            heel.port_drafts().unwrap(),
+            heel.starboard_drafts().unwrap()
+        );
+    }
+
+    #[test]
+    fn starboard_heel_test() {
+        call_once();
+        let heel = Heel::new(&ship(), &shiploads(5.0)).unwrap();
+        // Кренящий момент 1000 т * 5 м, водоизмещение 13550 + 5000 т.
+        assert!((heel.heeling_moment() - 5000.0).abs() < 1e-6);
+        assert!((heel.tcg() - 5000.0 / 18550.0).abs() < 1e-9);
+        let angle = heel.angle().unwrap();
+        assert!(angle > 0.0);
+        assert!(
+            (angle - (heel.tcg() / heel.metacentric_height()).atan().to_degrees()).abs() < 1e-9
+        );
+        let port = heel.port_drafts().unwrap().unwrap();
+        let starboard = heel.starboard_drafts().unwrap().unwrap();
+        assert!(starboard.mid > port.mid);
+        assert!(starboard.aft > port.aft);
+        assert!(starboard.nose > port.nose);
+        // Спрямление грузом, принятым у левого борта.
+        assert_eq!(heel.correction_mass(-10.0).unwrap(), 500.0);
+        assert!(heel.correction_mass(0.0).is_err());
+    }
+
+    #[test]
+    fn heel_without_vcg_test() {
+        call_once();
+        let input_path = "input_data/input_data.json".to_string();
+        let ship = Ship::new(
+            Lightweight::new(13550.0),
+            LightweightDistribution::Trapezoidal,
+            ShipDimensions::from_file(input_path.clone()).unwrap(),
+            WaterDensity::from_file(input_path).unwrap(),
+            Frames::from_json_file("input_data/frames.json".to_string()).unwrap(),
+            HydrostaticCurves::from_json_file("input_data/hydrostatic_curves.json".to_string())
+                .unwrap(),
+        );
+        assert!(Heel::new(&ship, &shiploads(5.0)).is_err());
+    }
+
+    #[test]
+    fn deflection_draft_marks_test() {
+        call_once();
+        let rigid = Heel::new(&ship(), &shiploads(0.0)).unwrap();
+        let ship = ship().with_cross_sections(
+            CrossSections::from_file("input_data/cross_sections.json".to_string()).unwrap(),
+        );
+        let deflection = Strength::from_ship(&ship, &shiploads(0.0))
+            .unwrap()
+            .deflection(ship.cross_sections().unwrap())
+            .unwrap()
+            .midship_deflection()
+            .unwrap()
+            / 1000.0;
+        assert!(deflection > 0.0);
+        let heel = Heel::new(&ship, &shiploads(0.0)).unwrap();
+        let rigid_drafts = rigid.port_drafts().unwrap().unwrap();
+        let drafts = heel.port_drafts().unwrap().unwrap();
+        // Прогиб не изменяет осадки на перпендикулярах, осадка на миделе при перегибе уменьшается.
+        assert_eq!(rigid_drafts.aft, drafts.aft);
+        assert_eq!(rigid_drafts.nose, drafts.nose);
+        assert!((rigid_drafts.mid - deflection - drafts.mid).abs() <= 0.01);
+    }
+}
@@ -0,0 +1 @@
+mod heel_test;
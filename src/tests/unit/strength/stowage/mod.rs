@@ -0,0 +1 @@
+mod stowage_planner_test;
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use serde_json::Value;
+
+    use crate::strength::{
+        load::shiploads::Shiploads, ship::ship_model::Ship,
+        stowage::stowage_planner::StowagePlanner,
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn planner(file: &str) -> StowagePlanner {
+        StowagePlanner::from_file(format!("src/tests/unit/strength/test_data/stowage/{file}"))
+            .unwrap()
+    }
+
+    fn cargo(candidate: &Value) -> Vec<f64> {
+        candidate["hold_loads"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|hold_load| hold_load["cargo"].as_f64().unwrap())
+            .collect()
+    }
+
+    fn ship() -> Ship {
+        Ship::from_files(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn homogeneous_plan_ok_test() {
+        call_once();
+        // Трюм 3 по объему вмещает 20000 т, но нагрузка на настил второго дна ограничивает массу
+        // груза 10000 т, остаток распределяется между трюмами 1, 2 и 4.
+        let planner = planner("homogeneous.json");
+        let candidates = planner.plan(&ship(), &Shiploads::new(vec![]));
+        assert_eq!(1, candidates.len());
+        let candidate = serde_json::to_value(&candidates[0]).unwrap();
+        assert_eq!(vec![8000.0, 8000.0, 10000.0, 8000.0], cargo(&candidate));
+        assert_eq!(0.5, candidate["hold_loads"][2]["filling"]);
+        assert!(!candidate["strength_check"].is_null());
+    }
+
+    #[test]
+    fn plan_ranking_ok_test() {
+        call_once();
+        let planner = planner("ranking.json");
+        let candidates = planner.plan(&ship(), &Shiploads::new(vec![]));
+        assert_eq!(3, candidates.len());
+        for pair in candidates.windows(2) {
+            assert!(pair[0].max_utilisation() <= pair[1].max_utilisation());
+        }
+        let alternate_hold = serde_json::to_value(&candidates)
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|candidate| candidate["strategy"] == "alternate_hold")
+            .cloned()
+            .unwrap();
+        assert_eq!(vec![10000.0, 0.0, 10000.0, 0.0], cargo(&alternate_hold));
+    }
+
+    #[test]
+    fn plan_rejected_test() {
+        call_once();
+        // Груз не размещается в трюмах 1 и 3 из-за ограничения нагрузки на настил второго дна.
+        let planner = planner("rejected.json");
+        let candidates = planner.plan(&ship(), &Shiploads::new(vec![]));
+        assert!(serde_json::to_value(&candidates[0]).unwrap()["error"].is_string());
+        assert_eq!(f64::INFINITY, candidates[0].max_utilisation());
+    }
+
+    #[test]
+    fn from_file_err_test() {
+        call_once();
+        for file in [
+            "without_holds.json",
+            "cargo_mass_err.json",
+            "without_strategies.json",
+        ] {
+            assert!(StowagePlanner::from_file(format!(
+                "src/tests/unit/strength/test_data/stowage/{file}"
+            ))
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn from_file_ok_test() {
+        call_once();
+        let planner = StowagePlanner::from_file("input_data/stowage.json".to_string()).unwrap();
+        let holds = &serde_json::to_value(&planner).unwrap()["holds"];
+        assert_eq!(5, holds.as_array().unwrap().len());
+        assert_eq!(600.0, holds[4]["tank_top_area"]);
+    }
+}
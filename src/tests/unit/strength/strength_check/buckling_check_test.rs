@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        cross_section_properties::{cross_sections::CrossSections, stiffened_panel::PanelLocation},
+        strength::{
+            load::shiploads::Shiploads,
+            ship::{
+                ship_model::Ship, spatium_function::SpatiumFunction,
+                spatium_functions::SpatiumFunctions,
+            },
+            strength::Strength,
+            strength_check::buckling_check::BucklingCheck,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    ///
+    /// Коробчатое сечение 10 x 10 м с толщиной стенок 10 мм: I = 6.6667 м^4, z_na = 5 м.
+    /// В файле box_panels.json палуба и днище подкреплены продольными ребрами жесткости.
+    fn cross_sections(file: &str) -> CrossSections {
+        CrossSections::from_file(format!(
+            "src/tests/unit/strength/test_data/cross_sections/{file}"
+        ))
+        .unwrap()
+    }
+
+    fn constant_moment(moment: f64) -> SpatiumFunctions {
+        SpatiumFunctions::new(vec![
+            SpatiumFunction::new(0, -10.0, 0.0, moment, moment),
+            SpatiumFunction::new(1, 0.0, 10.0, moment, moment),
+        ])
+    }
+
+    #[test]
+    fn hogging_test() {
+        call_once();
+        let check =
+            BucklingCheck::new(&constant_moment(1000.0), &cross_sections("box_panels.json"))
+                .unwrap();
+        assert_eq!(3, check.stations().len());
+        let station = &check.stations()[1];
+        assert_eq!(0.0, station.abscissa);
+        assert_eq!(1000.0, station.bending_moment);
+        // sigma = 1000 т*м * 5 м / 6.6667 м^4 = 750 т/м^2 = 7.3575 МПа.
+        let bottom = station.panels[0];
+        let deck = station.panels[1];
+        assert_eq!(
+            (0, PanelLocation::Bottom),
+            (bottom.element, bottom.location)
+        );
+        assert_eq!(-7.36, bottom.stress);
+        assert_eq!(7.36, deck.stress);
+        // При перегибе сжаты панели днища, палуба растянута.
+        assert!(bottom.buckling_utilisation > 0.0);
+        assert_eq!(0.0, deck.buckling_utilisation);
+        assert_eq!(deck.yield_utilisation, bottom.yield_utilisation);
+        assert_eq!(0.031, bottom.yield_utilisation);
+        assert_eq!(
+            bottom.buckling_utilisation,
+            check.max_buckling_utilisation()
+        );
+        assert!(check.passed());
+    }
+
+    #[test]
+    fn sagging_test() {
+        call_once();
+        let check = BucklingCheck::new(
+            &constant_moment(-50000.0),
+            &cross_sections("box_panels.json"),
+        )
+        .unwrap();
+        let station = &check.stations()[0];
+        assert!(station.panels[1].stress < 0.0);
+        assert_eq!(0.0, station.panels[0].buckling_utilisation);
+        assert!(station.panels[1].buckling_utilisation > 1.0);
+        assert_eq!(
+            station.panels[1].buckling_utilisation,
+            station.buckling_utilisation()
+        );
+        assert!(!check.passed());
+    }
+
+    #[test]
+    fn without_panels_test() {
+        call_once();
+        assert_eq!(
+            Err("В поперечных сечениях корпуса не заданы панели палубы и днища.".to_string()),
+            BucklingCheck::new(&constant_moment(1000.0), &cross_sections("box.json"))
+        );
+    }
+
+    #[test]
+    fn full_ship_buckling_check_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "input_data/input_data.json".to_string(),
+            "input_data/frames.json".to_string(),
+            "input_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let cross_sections =
+            CrossSections::from_file("input_data/cross_sections.json".to_string()).unwrap();
+        let shiploads = Shiploads::from_file("input_data/full_ship.json".to_string()).unwrap();
+        let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+        let check = strength.buckling_check(&cross_sections).unwrap();
+        assert_eq!(
+            ship.ship_dimensions().number_spatiums() as usize + 1,
+            check.stations().len()
+        );
+        // Перегиб по всей длине: сжаты только панели днища.
+        for station in check.stations() {
+            for panel in &station.panels {
+                if panel.location == PanelLocation::Deck {
+                    assert_eq!(0.0, panel.buckling_utilisation);
+                }
+            }
+        }
+        assert!(check.max_buckling_utilisation() > 0.0);
+        assert!(check.passed());
+    }
+}
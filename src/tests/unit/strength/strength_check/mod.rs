@@ -0,0 +1,5 @@
+mod buckling_check_test;
+mod permissible_limits_test;
+mod shear_stress_check_test;
+mod still_water_check_test;
+mod ultimate_strength_check_test;
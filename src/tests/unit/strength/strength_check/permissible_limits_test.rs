@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::strength::{
+        ship::ship_model::Ship,
+        strength::Strength,
+        strength_check::permissible_limits::{PermissibleLimits, DEFAULT_PERMISSIBLE_SHEAR_STRESS},
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    #[test]
+    fn from_file_ok_test() {
+        call_once();
+        let file_path = "src/tests/unit/strength/test_data/input_data.json".to_string();
+        let limits = PermissibleLimits::from_file(file_path).unwrap().unwrap();
+        assert_eq!(6000.0, limits.share_force());
+        assert_eq!(250000.0, limits.hogging_moment());
+        assert_eq!(200000.0, limits.sagging_moment());
+        assert_eq!(DEFAULT_PERMISSIBLE_SHEAR_STRESS, limits.shear_stress());
+    }
+
+    #[test]
+    fn shear_stress_test() {
+        call_once();
+        let limits = PermissibleLimits::new(6000.0, 250000.0, 200000.0).unwrap();
+        assert_eq!(
+            120.0,
+            limits.with_shear_stress(120.0).unwrap().shear_stress()
+        );
+        assert_eq!(
+            "Допускаемое касательное напряжение должно быть больше нуля: 0 МПа.",
+            limits.with_shear_stress(0.0).unwrap_err()
+        );
+        let limits = PermissibleLimits::from_file("input_data/input_data.yaml".to_string())
+            .unwrap()
+            .unwrap();
+        assert_eq!(110.0, limits.shear_stress());
+    }
+
+    #[test]
+    fn new_err_test() {
+        call_once();
+        assert!(PermissibleLimits::new(6000.0, 250000.0, 0.0).is_err());
+        assert!(PermissibleLimits::new(-1.0, 250000.0, 200000.0).is_err());
+    }
+
+    #[test]
+    fn without_limits_test() {
+        call_once();
+        let input_path =
+            "src/tests/unit/strength/test_data/input_data_without_limits.json".to_string();
+        assert!(PermissibleLimits::from_file(input_path.clone())
+            .unwrap()
+            .is_none());
+        // Файл данных судна без допускаемых значений пригоден для расчета прочности,
+        // но не для проверки прочности.
+        let ship = Ship::from_files(
+            input_path.clone(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        assert!(ship.permissible_limits().is_err());
+        assert!(Strength::new_project(
+            input_path,
+            "src/tests/unit/strength/test_data/empty_ship.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        cross_section_properties::cross_sections::CrossSections,
+        strength::{
+            internal_forces::shear_stress::ShearStress,
+            load::shiploads::Shiploads,
+            ship::{
+                ship_model::Ship, spatium_function::SpatiumFunction,
+                spatium_functions::SpatiumFunctions,
+            },
+            strength::Strength,
+            strength_check::{
+                permissible_limits::PermissibleLimits, shear_stress_check::ShearStressCheck,
+            },
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    #[test]
+    fn every_station_test() {
+        call_once();
+        // Коробчатое сечение 10 x 10 м с толщиной стенок 10 мм, S / (I * t) = 5.625 1/м^2.
+        let cross_sections = CrossSections::from_file(
+            "src/tests/unit/strength/test_data/cross_sections/box.json".to_string(),
+        )
+        .unwrap();
+        let share_force = SpatiumFunctions::new(vec![
+            SpatiumFunction::new(0, -20.0, -10.0, 0.0, -2000.0),
+            SpatiumFunction::new(1, -10.0, 0.0, -2000.0, 1000.0),
+            SpatiumFunction::new(2, 0.0, 10.0, 1000.0, 2000.0),
+            SpatiumFunction::new(3, 10.0, 20.0, 2000.0, 0.0),
+        ]);
+        let shear_stress = ShearStress::new(&share_force, &cross_sections).unwrap();
+        let limits = PermissibleLimits::new(6000.0, 250000.0, 200000.0)
+            .unwrap()
+            .with_shear_stress(100.0)
+            .unwrap();
+        let check = ShearStressCheck::new(&shear_stress, &limits);
+        assert_eq!(5, check.stations().len());
+        assert_eq!(
+            vec![-20.0, -10.0, 0.0, 10.0, 20.0],
+            check
+                .stations()
+                .iter()
+                .map(|station| station.abscissa)
+                .collect::<Vec<_>>()
+        );
+        // tau = 2000 т * 5.625 1/м^2 = 11250 т/м^2 = 110.36 МПа.
+        assert_eq!(110.36, check.max_shear_stress());
+        assert_eq!(1.104, check.max_utilisation());
+        let failed = check.failed_stations();
+        assert_eq!(
+            vec![-10.0, 10.0],
+            failed
+                .iter()
+                .map(|station| station.abscissa)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(-110.36, failed[0].shear_stress);
+        assert_eq!(0.552, check.stations()[2].utilisation);
+        assert!(!check.passed());
+    }
+
+    #[test]
+    fn full_ship_shear_stress_check_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "input_data/input_data.json".to_string(),
+            "input_data/frames.json".to_string(),
+            "input_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let cross_sections =
+            CrossSections::from_file("input_data/cross_sections.json".to_string()).unwrap();
+        let shiploads = Shiploads::from_file("input_data/full_ship.json".to_string()).unwrap();
+        let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+        let check = strength
+            .shear_stress_check(&cross_sections, &ship.permissible_limits().unwrap())
+            .unwrap();
+        assert_eq!(110.0, check.permissible_shear_stress());
+        assert_eq!(
+            ship.ship_dimensions().number_spatiums() as usize + 1,
+            check.stations().len()
+        );
+        assert!(check.passed());
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::core::{round::Round, unit_system::UnitSystem};
+    use crate::cross_section_properties::cross_sections::CrossSections;
+    use crate::strength::{
+        load::shiploads::Shiploads,
+        ship::{
+            ship_model::Ship, spatium_function::SpatiumFunction,
+            spatium_functions::SpatiumFunctions,
+        },
+        strength::Strength,
+        strength_check::{permissible_limits::PermissibleLimits, still_water_check::StrengthCheck},
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    #[test]
+    fn strength_check_ok_test() {
+        call_once();
+        let share_force = SpatiumFunctions::new(vec![
+            SpatiumFunction::new(0, -10.0, 0.0, 0.0, -500.0),
+            SpatiumFunction::new(1, 0.0, 10.0, -500.0, 0.0),
+        ]);
+        let bending_moment = SpatiumFunctions::new(vec![
+            SpatiumFunction::new(0, -10.0, 0.0, 0.0, 3000.0),
+            SpatiumFunction::new(1, 0.0, 10.0, -1500.0, 0.0),
+        ]);
+        let limits = PermissibleLimits::new(1000.0, 2000.0, 3000.0).unwrap();
+        let check = StrengthCheck::new(&share_force, &bending_moment, &limits);
+        assert_eq!(500.0, check.max_share_force());
+        assert_eq!(0.5, check.share_force_utilisation());
+        assert_eq!(3000.0, check.max_hogging_moment());
+        assert_eq!(1.5, check.hogging_utilisation());
+        assert_eq!(1500.0, check.max_sagging_moment());
+        assert_eq!(0.5, check.sagging_utilisation());
+        assert_eq!(1.5, check.max_utilisation());
+        assert!(!check.passed());
+    }
+
+    #[test]
+    fn unit_system_test() {
+        call_once();
+        let share_force =
+            SpatiumFunctions::new(vec![SpatiumFunction::new(0, -10.0, 0.0, 0.0, -500.0)]);
+        let bending_moment =
+            SpatiumFunctions::new(vec![SpatiumFunction::new(0, -10.0, 0.0, -1000.0, 3000.0)]);
+        let limits = PermissibleLimits::new(1000.0, 2000.0, 3000.0).unwrap();
+        let check = StrengthCheck::new(&share_force, &bending_moment, &limits);
+        assert_eq!(UnitSystem::TonnesForce, check.unit_system());
+        let si = check.with_unit_system(UnitSystem::KiloNewtons);
+        assert_eq!(UnitSystem::KiloNewtons, si.unit_system());
+        assert_eq!(4905.0, si.max_share_force().my_round(6));
+        assert_eq!(29430.0, si.max_hogging_moment().my_round(6));
+        assert_eq!(9810.0, si.max_sagging_moment().my_round(6));
+        assert_eq!(check.max_utilisation(), si.max_utilisation());
+        // Пересчет выполняется из текущей системы единиц, а не из тонн-силы.
+        let mega = si.with_unit_system(UnitSystem::MegaNewtons);
+        assert_eq!(4.905, mega.max_share_force().my_round(6));
+        let tf = mega.with_unit_system(UnitSystem::TonnesForce);
+        assert_eq!(500.0, tf.max_share_force().my_round(6));
+        assert_eq!(3000.0, tf.max_hogging_moment().my_round(6));
+    }
+
+    #[test]
+    fn full_ship_strength_check_ok_test() {
+        call_once();
+        let strength = Strength::new_project(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/full_ship.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let limits = PermissibleLimits::new(6000.0, 250000.0, 200000.0).unwrap();
+        let check = strength.strength_check(&limits).unwrap();
+        assert!(check.max_hogging_moment() > 0.0);
+        assert_eq!(
+            check.max_hogging_moment(),
+            strength
+                .bending_moment_with_correction()
+                .unwrap()
+                .max()
+                .unwrap()
+                .my_round(2)
+        );
+        assert!(check.passed());
+    }
+
+    #[test]
+    fn shear_stress_strength_check_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let shiploads =
+            Shiploads::from_file("src/tests/unit/strength/test_data/full_ship.json".to_string())
+                .unwrap();
+        let limits = PermissibleLimits::new(6000.0, 250000.0, 200000.0)
+            .unwrap()
+            .with_shear_stress(30.0)
+            .unwrap();
+        // Без поперечных сечений касательные напряжения не проверяются.
+        let rigid = Strength::from_ship(&ship, &shiploads)
+            .unwrap()
+            .strength_check(&limits)
+            .unwrap();
+        assert_eq!(None, rigid.shear_stress_utilisation());
+        let cross_sections =
+            CrossSections::from_file("input_data/cross_sections.json".to_string()).unwrap();
+        let strength = Strength::from_ship(
+            &ship.with_cross_sections(cross_sections.clone()),
+            &shiploads,
+        )
+        .unwrap();
+        let check = strength.strength_check(&limits).unwrap();
+        let shear_stress_check = strength
+            .shear_stress_check(&cross_sections, &limits)
+            .unwrap();
+        assert_eq!(
+            Some(shear_stress_check.max_shear_stress()),
+            check.max_shear_stress()
+        );
+        assert_eq!(
+            Some(shear_stress_check.max_utilisation()),
+            check.shear_stress_utilisation()
+        );
+        assert_eq!(
+            rigid
+                .max_utilisation()
+                .max(shear_stress_check.max_utilisation()),
+            check.max_utilisation()
+        );
+        assert_eq!(
+            check.passed(),
+            rigid.passed() && shear_stress_check.passed()
+        );
+    }
+}
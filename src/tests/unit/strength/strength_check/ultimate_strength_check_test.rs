@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        cross_section_properties::{
+            cross_sections::CrossSections, ultimate_strength::UltimateStrength,
+        },
+        strength::{
+            internal_forces::wave_bending_moment::WaveBendingMoment,
+            load::shiploads::Shiploads,
+            ship::{
+                ship_dimensions::ShipDimensions, ship_model::Ship,
+                spatium_function::SpatiumFunction, spatium_functions::SpatiumFunctions,
+            },
+            strength::Strength,
+            strength_check::ultimate_strength_check::UltimateStrengthCheck,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn cross_sections(file: &str) -> Result<CrossSections, String> {
+        CrossSections::from_file(format!(
+            "src/tests/unit/strength/test_data/cross_sections/{file}"
+        ))
+    }
+
+    #[test]
+    fn safety_factor_test() {
+        call_once();
+        // Коробчатые сечения 10 x 10 м с толщиной стенок 10 мм на перпендикуляре кормы и миделе.
+        let cross_sections = cross_sections("two_box_sections.json").unwrap();
+        let ship_dimensions = ShipDimensions::new(100.0, 2, 0.7).with_breadth(10.0);
+        let still_water = SpatiumFunctions::new(vec![
+            SpatiumFunction::new(0, -50.0, 0.0, 0.0, 20000.0),
+            SpatiumFunction::new(1, 0.0, 50.0, 20000.0, 0.0),
+        ]);
+        let wave = WaveBendingMoment::new(ship_dimensions).unwrap();
+        let check = UltimateStrengthCheck::new(&still_water, &wave, &cross_sections).unwrap();
+        let capacity = UltimateStrength::new(&cross_sections.sections()[1]);
+        let [aft, midship] = check.sections().as_slice() else {
+            panic!("Ожидалось два сечения");
+        };
+        // На перпендикуляре изгибающие моменты равны нулю, запас не определен.
+        assert_eq!(
+            (None, None),
+            (aft.hogging_safety_factor, aft.sagging_safety_factor)
+        );
+        assert_eq!(20000.0, midship.still_water_moment);
+        let total_hogging = 20000.0 + wave.hogging().value_at(0.0).unwrap();
+        assert!(
+            (midship.hogging_safety_factor.unwrap() - capacity.hogging_capacity() / total_hogging)
+                .abs()
+                < 0.001
+        );
+        // Волновой момент на прогиб меньше момента на тихой воде - прогиба нет.
+        assert!(midship.total_sagging_moment > 0.0);
+        assert_eq!(None, midship.sagging_safety_factor);
+        assert_eq!(midship.hogging_safety_factor, check.min_safety_factor());
+        assert!(check.passed());
+    }
+
+    #[test]
+    fn outside_ship_test() {
+        call_once();
+        let cross_sections = cross_sections("outside_ship.json").unwrap();
+        let ship_dimensions = ShipDimensions::new(100.0, 2, 0.7).with_breadth(10.0);
+        assert_eq!(
+            Err(
+                "Сечение с абсциссой 80 м находится вне длины судна между перпендикулярами."
+                    .to_string()
+            ),
+            UltimateStrengthCheck::new(
+                &SpatiumFunctions::filled_zeros(2, 100.0),
+                &WaveBendingMoment::new(ship_dimensions).unwrap(),
+                &cross_sections
+            )
+        );
+    }
+
+    #[test]
+    fn full_ship_ultimate_strength_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "input_data/input_data.json".to_string(),
+            "input_data/frames.json".to_string(),
+            "input_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let cross_sections =
+            CrossSections::from_file("input_data/cross_sections.json".to_string()).unwrap();
+        let shiploads = Shiploads::from_file("input_data/full_ship.json".to_string()).unwrap();
+        let strength = Strength::from_ship(&ship, &shiploads).unwrap();
+        let check = strength.ultimate_strength_check(&cross_sections).unwrap();
+        assert_eq!(4, check.sections().len());
+        for section in check.sections() {
+            assert!(section.sagging_capacity < section.hogging_capacity);
+        }
+        assert!(check.min_safety_factor().unwrap() > 1.0);
+        assert!(check.passed());
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::strength::{
+        bonjean_scale::frames::Frames, ship::ship_dimensions::ShipDimensions,
+        validation::bonjean_validation::BonjeanValidation,
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn frames() -> Frames {
+        Frames::from_json_file(
+            "src/tests/unit/strength/test_data/frames_inconsistent.json".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn consistency_warnings_test() {
+        call_once();
+        let validation = BonjeanValidation::new(&frames(), ShipDimensions::new(20.0, 2, 0.8));
+        assert!(!validation.findings().has_errors());
+        assert!(validation.findings().check().is_ok());
+        let findings = serde_json::to_value(validation.findings()).unwrap();
+        let messages: Vec<&str> = findings
+            .as_array()
+            .unwrap()
+            .iter()
+            .inspect(|finding| assert_eq!("warning", finding["severity"]))
+            .map(|finding| finding["message"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            vec![
+                "Шпангоут 1, индекс 0: объем 4 м^3 не согласуется с площадью 4 м^2 (ожидается 40 м^3).",
+                "Шпангоут 2, длина вектора объемов 1 не совпадает с длиной вектора осадок 2.",
+                "Шпангоут 2, индекс 1: масса 1 т не согласуется с площадью 2 м^2 (ожидается 20 т).",
+            ],
+            messages
+        );
+    }
+
+    #[test]
+    fn span_error_test() {
+        call_once();
+        let validation = BonjeanValidation::new(&frames(), ShipDimensions::new(30.0, 3, 0.8));
+        assert!(validation.findings().has_errors());
+        let err = validation.findings().check().unwrap_err();
+        assert!(err.contains("Шпангоут 0, индекс 0: абсцисса кормового шпангоута -10 м не совпадает с координатой кормового перпендикуляра -15 м."));
+        assert!(err.contains("Шпангоут 2, индекс 2: абсцисса носового шпангоута 10 м не совпадает с координатой носового перпендикуляра 15 м."));
+    }
+
+    #[test]
+    fn input_data_test() {
+        call_once();
+        let frames = Frames::from_json_file("input_data/frames.json".to_string()).unwrap();
+        let ship_dimensions =
+            ShipDimensions::from_file("input_data/input_data.json".to_string()).unwrap();
+        assert!(BonjeanValidation::new(&frames, ship_dimensions)
+            .findings()
+            .as_ref()
+            .is_empty());
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::strength::{
+        hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
+        validation::hydrostatic_validation::HydrostaticValidation,
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    #[test]
+    fn outlier_warning_test() {
+        call_once();
+        // Выпадающая точка не препятствует загрузке кривых и выводится предупреждением.
+        let curves = HydrostaticCurves::from_json_file(
+            "src/tests/unit/strength/test_data/hydrostatic_curves_outlier.json".to_string(),
+        )
+        .unwrap();
+        let validation = HydrostaticValidation::new(&curves);
+        assert!(!validation.findings().has_errors());
+        assert!(validation.findings().check().is_ok());
+        let findings = serde_json::to_value(validation.findings()).unwrap();
+        assert_eq!(1, findings.as_array().unwrap().len());
+        assert_eq!("warning", findings[0]["severity"]);
+        assert_eq!(
+            "Гидростатическая кривая `x_c`, осадка 9 м: значение 10.47 выпадает из кривой, по соседним точкам ожидается около -10.41.",
+            findings[0]["message"]
+        );
+    }
+
+    #[test]
+    fn input_data_test() {
+        call_once();
+        let curves =
+            HydrostaticCurves::from_json_file("input_data/hydrostatic_curves.json".to_string())
+                .unwrap();
+        assert!(HydrostaticValidation::new(&curves)
+            .findings()
+            .as_ref()
+            .is_empty());
+    }
+}
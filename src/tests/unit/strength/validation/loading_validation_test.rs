@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::{data_file::DataFile, point::Point, water_density::WaterDensity},
+        strength::{
+            bonjean_scale::frames::Frames,
+            compartment::compartments::Compartments,
+            hydrostatic_curves::hydrostatic_curves::HydrostaticCurves,
+            lightweight::{
+                lightweight::Lightweight, lightweight_distribution::LightweightDistribution,
+            },
+            load::{shipload::Shipload, shiploads::Shiploads},
+            ship::{ship_dimensions::ShipDimensions, ship_model::Ship},
+            strength::Strength,
+            validation::loading_validation::LoadingValidation,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn ship_with(compartments: Compartments) -> Ship {
+        let input_path = "src/tests/unit/strength/test_data/input_data.json".to_string();
+        Ship::new(
+            Lightweight::from_file(input_path.clone()).unwrap(),
+            LightweightDistribution::Trapezoidal,
+            ShipDimensions::from_file(input_path.clone()).unwrap(),
+            WaterDensity::from_file(input_path).unwrap(),
+            Frames::from_json_file("src/tests/unit/strength/test_data/frames.json".to_string())
+                .unwrap(),
+            HydrostaticCurves::from_json_file(
+                "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+            )
+            .unwrap(),
+        )
+        .with_compartments(compartments)
+    }
+
+    fn ship() -> Ship {
+        ship_with(
+            Compartments::from_file(
+                "src/tests/unit/strength/test_data/validation/compartments.json".to_string(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn shipload(value: f64, x: f64, length: f64) -> Shipload {
+        Shipload::new(value, Point::new(x, 0.0, 0.0), length)
+    }
+
+    #[test]
+    fn valid_loading_test() {
+        call_once();
+        let shiploads =
+            Shiploads::from_file("src/tests/unit/strength/test_data/full_ship.json".to_string())
+                .unwrap();
+        let validation = LoadingValidation::new(&ship_with(Compartments::default()), &shiploads);
+        assert!(
+            !validation.findings().has_errors(),
+            "{:?}",
+            validation.findings()
+        );
+        assert!(validation.findings().check().is_ok());
+    }
+
+    #[test]
+    fn shipload_findings_test() {
+        call_once();
+        // Нагрузки не проходят валидацию Shiploads::from_file, поэтому файл читается без неё.
+        let shiploads: Shiploads = DataFile::new(
+            "src/tests/unit/strength/test_data/validation/shipload_findings.json".to_string(),
+        )
+        .deserialize()
+        .unwrap();
+        let validation = LoadingValidation::new(&ship(), &shiploads);
+        let findings = serde_json::to_value(validation.findings()).unwrap();
+        let findings: Vec<(Option<u64>, &str)> = findings
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|finding| {
+                (
+                    finding["index"].as_u64(),
+                    finding["severity"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (Some(0), "error"),
+                (Some(1), "error"),
+                (Some(2), "warning"),
+                (Some(3), "error"),
+            ],
+            findings
+        );
+    }
+
+    #[test]
+    fn compartment_overload_test() {
+        call_once();
+        let shiploads = Shiploads::new(vec![
+            shipload(150.0, -102.0, 5.0),
+            shipload(100.0, -98.0, 5.0),
+        ]);
+        let validation = LoadingValidation::new(&ship(), &shiploads);
+        assert_eq!(1, validation.findings().as_ref().len());
+        assert!(validation.findings().as_ref()[0].is_error());
+        assert!(validation.findings().as_ref()[0]
+            .to_string()
+            .contains("0, 1"));
+    }
+
+    #[test]
+    fn max_displacement_test() {
+        call_once();
+        let shiploads = Shiploads::new(vec![shipload(80000.0, 0.0, 100.0)]);
+        let ship = ship();
+        let validation = LoadingValidation::new(&ship, &shiploads);
+        assert!(validation.findings().has_errors());
+        let err = Strength::from_ship(&ship, &shiploads).err().unwrap();
+        assert!(err.contains("максимальное водоизмещение"));
+    }
+    #[test]
+    fn max_displacement_water_density_test() {
+        call_once();
+        // Предельное водоизмещение уменьшается пропорционально плотности воды.
+        let ship = ship();
+        let max_displacement_tonnage = ship.hydrostatic_curves().max_displacement_tonnage();
+        let deadweight = 0.99 * max_displacement_tonnage - ship.lightweight().lightweight().value();
+        let shiploads = Shiploads::new(vec![shipload(deadweight, 0.0, 100.0)]);
+        assert!(!LoadingValidation::new(&ship, &shiploads)
+            .findings()
+            .has_errors());
+        let validation = LoadingValidation::new(&ship, &shiploads.with_water_density(1.0));
+        assert!(validation.findings().has_errors());
+        assert!(validation.findings().as_ref()[0]
+            .to_string()
+            .contains("максимальное водоизмещение"));
+    }
+}
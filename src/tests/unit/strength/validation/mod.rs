@@ -0,0 +1,3 @@
+mod bonjean_validation_test;
+mod hydrostatic_validation_test;
+mod loading_validation_test;
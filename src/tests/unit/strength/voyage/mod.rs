@@ -0,0 +1 @@
+mod voyage_plan_test;
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, sync::Once};
+
+    use crate::{
+        core::system_of_units::Tons,
+        strength::{
+            load::{load_category::LoadCategory, shiploads::Shiploads},
+            ship::ship_model::Ship,
+            voyage::voyage_plan::Voyage,
+        },
+    };
+
+    static INIT: Once = Once::new();
+
+    fn call_once() {
+        INIT.call_once(|| {
+            env::set_var("RUST_LOG", "debug"); // off / error / warn / info / debug / trace
+                                               // env::set_var("RUST_BACKTRACE", "1");
+            env::set_var("RUST_BACKTRACE", "full");
+            let _ = tracing_subscriber::fmt().compact().try_init();
+        })
+    }
+
+    fn voyage(file: &str) -> Result<Voyage, String> {
+        Voyage::from_file(format!("src/tests/unit/strength/test_data/voyage/{file}"))
+    }
+
+    #[test]
+    fn consumable_tank_ok_test() {
+        call_once();
+        let conditions = voyage("voyage.json")
+            .unwrap()
+            .conditions(&Shiploads::new(vec![]));
+        let shipload = &conditions[1].1.as_ref()[0];
+        assert_eq!(Tons(600.0), shipload.value());
+        assert_eq!(-82.0, shipload.longitudinal_center_gravity());
+        assert_eq!(Some("Цистерна топлива"), shipload.name());
+        assert_eq!(Some(LoadCategory::FuelOil), shipload.category());
+    }
+
+    #[test]
+    fn consumable_tank_err_test() {
+        call_once();
+        assert!(voyage("tank_density_err.json").is_err());
+        assert!(voyage("tank_filling_err.json").is_err());
+        assert!(voyage("tank_consumption_err.json").is_err());
+    }
+
+    #[test]
+    fn conditions_ok_test() {
+        call_once();
+        let voyage = voyage("voyage.json").unwrap();
+        assert_eq!(vec![0.0, 10.0, 20.0], voyage.days());
+        let conditions = voyage.conditions(&Shiploads::new(vec![]));
+        let masses: Vec<f64> = conditions
+            .iter()
+            .map(|(_, shiploads)| shiploads.sum())
+            .collect();
+        assert_eq!(vec![900.0, 600.0, 300.0], masses);
+        assert_eq!("Отход", conditions[0].0);
+        assert_eq!("Приход (20 сут)", conditions[2].0);
+    }
+
+    #[test]
+    fn water_density_conditions_test() {
+        call_once();
+        let densities: Vec<Option<f64>> = voyage("water_density.json")
+            .unwrap()
+            .conditions(&Shiploads::new(vec![]).with_water_density(1.015))
+            .iter()
+            .map(|(_, shiploads)| shiploads.water_density())
+            .collect();
+        // В промежуточном состоянии используется плотность исходной схемы загрузки.
+        assert_eq!(vec![Some(1.0), Some(1.015), Some(1.025)], densities);
+        assert!(voyage("water_density_err.json").is_err());
+    }
+
+    #[test]
+    fn from_file_err_test() {
+        call_once();
+        assert!(voyage("without_tanks.json").is_err());
+        assert!(voyage("duration_err.json").is_err());
+        // Запасов топлива хватает на 30 сут.
+        assert!(voyage("endurance_err.json").is_err());
+        assert!(voyage("newer_version.json")
+            .unwrap_err()
+            .contains("Версия формата файла рейса 2 не поддерживается"));
+    }
+
+    #[test]
+    fn run_ok_test() {
+        call_once();
+        let ship = Ship::from_files(
+            "src/tests/unit/strength/test_data/input_data.json".to_string(),
+            "src/tests/unit/strength/test_data/frames.json".to_string(),
+            "src/tests/unit/strength/test_data/hydrostatic_curves.json".to_string(),
+        )
+        .unwrap();
+        let base =
+            Shiploads::from_file("src/tests/unit/strength/test_data/empty_ship.json".to_string())
+                .unwrap();
+        let voyage = Voyage::from_file("input_data/voyage.json".to_string()).unwrap();
+        let report = voyage.run(&ship, &base);
+        assert_eq!(4, report.steps().len());
+        for step in report.steps() {
+            assert!(step.error().is_none());
+        }
+        // Расход запасов из кормовых цистерн увеличивает дифферент на нос.
+        let trim = |step: usize| {
+            let (aft_draft, nose_draft) = report.steps()[step].drafts().unwrap();
+            nose_draft - aft_draft
+        };
+        assert!(trim(3) > trim(0));
+        assert!(report.passed());
+        assert_eq!(1.0, report.steps()[0].water_density());
+        assert_eq!(1.025, report.steps()[3].water_density());
+    }
+}